@@ -0,0 +1,346 @@
+use core::fmt;
+
+use crate::{candle_stream::SERIES_SIZE, CandleStick, CandleStream, Direction, Pattern, Rule};
+
+/// An alert raised by an [`AlertEngine`], describing the rule that fired on the latest candle.
+///
+/// Displays as a one-line message, and serializes to JSON with [`Alert::write_json`] for
+/// webhooks or message queues.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alert<'a> {
+    /// Symbol of the watched instrument
+    pub symbol: &'a str,
+    /// Name of the rule that fired
+    pub name: &'static str,
+    /// The built-in pattern that fired, `None` for custom rules
+    pub pattern: Option<Pattern>,
+    /// Direction of the anticipated move
+    pub direction: Direction,
+    /// Close of the latest candle
+    pub price: f64,
+    /// Volume of the latest candle relative to the average volume of the previous candles,
+    /// `1.0` when the volume is unknown
+    pub score: f64,
+    /// Suggested stop: the lowest low of the rule candles for bullish alerts, the highest high
+    /// for bearish ones
+    pub stop: f64,
+}
+
+impl Alert<'_> {
+    /// Writes the alert as a JSON object
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Alert, Direction, Pattern};
+    /// let alert = Alert {
+    ///     symbol: "ACME",
+    ///     name: "Bullish Engulfing",
+    ///     pattern: Some(Pattern::BullishEngulfing),
+    ///     direction: Direction::Bullish,
+    ///     price: 102.5,
+    ///     score: 2.0,
+    ///     stop: 98.5,
+    /// };
+    /// let mut json = String::new();
+    /// alert.write_json(&mut json).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"symbol":"ACME","name":"Bullish Engulfing","direction":"bullish","price":102.5,"score":2,"stop":98.5}"#
+    /// );
+    /// ```
+    pub fn write_json<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str("{\"symbol\":")?;
+        write_json_str(writer, self.symbol)?;
+        writer.write_str(",\"name\":")?;
+        write_json_str(writer, self.name)?;
+        let direction = match self.direction {
+            Direction::Bullish => "bullish",
+            Direction::Bearish => "bearish",
+        };
+        write!(writer, ",\"direction\":\"{direction}\"")?;
+        for (key, value) in [
+            ("price", self.price),
+            ("score", self.score),
+            ("stop", self.stop),
+        ] {
+            match value.is_finite() {
+                true => write!(writer, ",\"{key}\":{value}")?,
+                false => write!(writer, ",\"{key}\":null")?,
+            }
+        }
+        writer.write_char('}')
+    }
+}
+
+impl fmt::Display for Alert<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} at {} (stop {}, score {:.2})",
+            self.symbol, self.name, self.price, self.stop, self.score
+        )
+    }
+}
+
+// Writes a JSON string literal, escaping quotes, backslashes and control characters
+fn write_json_str<W: fmt::Write>(writer: &mut W, s: &str) -> fmt::Result {
+    writer.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => writer.write_str("\\\"")?,
+            '\\' => writer.write_str("\\\\")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => writer.write_char(c)?,
+        }
+    }
+    writer.write_char('"')
+}
+
+// What fires an alert rule
+enum Trigger<'r, T, const S: usize> {
+    Pattern(Pattern),
+    Rule(&'r dyn Rule<T, S>),
+}
+
+/// A rule watched by an [`AlertEngine`]
+pub struct AlertRule<'r, T, const S: usize = SERIES_SIZE> {
+    name: &'static str,
+    direction: Direction,
+    lookback: usize,
+    trigger: Trigger<'r, T, S>,
+}
+
+impl<'r, T, const S: usize> AlertRule<'r, T, S> {
+    /// Returns an alert rule firing when the given [`Rule`] holds.
+    ///
+    /// The suggested stop is placed beyond the latest candle, see
+    /// [`AlertRule::with_lookback`] to include more candles.
+    pub fn new(name: &'static str, direction: Direction, rule: &'r dyn Rule<T, S>) -> Self {
+        Self {
+            name,
+            direction,
+            lookback: 1,
+            trigger: Trigger::Rule(rule),
+        }
+    }
+
+    /// Returns an alert rule firing when the given built-in [`Pattern`] completes, with the
+    /// suggested stop beyond the pattern candles
+    pub fn pattern(pattern: Pattern) -> Self {
+        Self {
+            name: pattern.name(),
+            direction: pattern.direction(),
+            lookback: pattern.candle_count(),
+            trigger: Trigger::Pattern(pattern),
+        }
+    }
+
+    /// Returns a copy of the rule placing the suggested stop beyond the latest `n` candles
+    pub fn with_lookback(self, n: usize) -> Self {
+        Self {
+            lookback: n.max(1),
+            ..self
+        }
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for AlertRule<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlertRule")
+            .field("name", &self.name)
+            .field("direction", &self.direction)
+            .field("lookback", &self.lookback)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `AlertEngine` evaluates a fixed-capacity set of alert rules on every push and raises
+/// structured [`Alert`]s for the ones that fire.
+///
+/// The engine watches a single symbol and holds up to `N` rules, built-in patterns and custom
+/// [`Rule`]s alike, without any allocation.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{AlertEngine, AlertRule, CandleStream, Pattern};
+/// let mut engine = AlertEngine::<_, 4>::new("ACME");
+/// assert!(engine.register(AlertRule::pattern(Pattern::BullishEngulfing)));
+///
+/// let prev = (101.0, 102.0, 99.5, 100.5, 1000.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 2000.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+///
+/// let alert = engine.evaluate(&series).next().unwrap();
+/// assert_eq!((alert.price, alert.stop, alert.score), (102.5, 98.5, 2.0));
+/// assert_eq!(alert.to_string(), "ACME Bullish Engulfing at 102.5 (stop 98.5, score 2.00)");
+/// ```
+pub struct AlertEngine<'a, 'r, T, const N: usize, const S: usize = SERIES_SIZE> {
+    symbol: &'a str,
+    rules: [Option<AlertRule<'r, T, S>>; N],
+    len: usize,
+}
+
+impl<'a, 'r, T: CandleStick, const N: usize, const S: usize> AlertEngine<'a, 'r, T, N, S> {
+    /// Returns an engine without rules, watching the given symbol
+    pub fn new(symbol: &'a str) -> Self {
+        Self {
+            symbol,
+            rules: [const { None }; N],
+            len: 0,
+        }
+    }
+
+    /// Registers an alert rule, replacing any rule with the same name.
+    ///
+    /// Returns false if the engine is full.
+    pub fn register(&mut self, rule: AlertRule<'r, T, S>) -> bool {
+        let existing = self.rules().position(|r| r.name == rule.name);
+        let slot = match existing {
+            Some(idx) => idx,
+            None if self.len < N => {
+                self.len += 1;
+                self.len - 1
+            }
+            None => return false,
+        };
+
+        self.rules[slot] = Some(rule);
+        true
+    }
+
+    /// Returns the number of registered rules
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no rule is registered
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Evaluates the registered rules on the latest candles of the stream, returning an alert
+    /// for each rule that fires, in registration order
+    pub fn evaluate<'e>(
+        &'e self,
+        stream: &'e CandleStream<'_, T, S>,
+    ) -> impl Iterator<Item = Alert<'a>> + 'e {
+        self.rules()
+            .filter(move |rule| match rule.trigger {
+                Trigger::Pattern(pattern) => stream.is_pattern(pattern),
+                Trigger::Rule(r) => r.holds(stream),
+            })
+            .filter_map(move |rule| self.alert(rule, stream))
+    }
+
+    // Builds the alert raised by the rule on the latest candle
+    fn alert(
+        &self,
+        rule: &AlertRule<'r, T, S>,
+        stream: &CandleStream<'_, T, S>,
+    ) -> Option<Alert<'a>> {
+        let c = stream.get()?;
+        let candles = || (0..rule.lookback).filter_map(|i| stream.prev(i));
+        let stop = match rule.direction {
+            Direction::Bullish => candles().map(|c| c.low()).fold(f64::INFINITY, f64::min),
+            Direction::Bearish => candles()
+                .map(|c| c.high())
+                .fold(f64::NEG_INFINITY, f64::max),
+        };
+
+        let previous = stream.len() - 1;
+        let score = match stream.average_volume_range(1, previous) {
+            Some((volume, _)) if volume > 0.0 => c.volume() / volume,
+            _ => 1.0,
+        };
+
+        Some(Alert {
+            symbol: self.symbol,
+            name: rule.name,
+            pattern: match rule.trigger {
+                Trigger::Pattern(pattern) => Some(pattern),
+                Trigger::Rule(_) => None,
+            },
+            direction: rule.direction,
+            price: c.close(),
+            score,
+            stop,
+        })
+    }
+
+    // Iterates over the registered rules
+    fn rules(&self) -> impl Iterator<Item = &AlertRule<'r, T, S>> + '_ {
+        self.rules[..self.len].iter().flatten()
+    }
+}
+
+impl<T, const N: usize, const S: usize> fmt::Debug for AlertEngine<'_, '_, T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlertEngine")
+            .field("symbol", &self.symbol)
+            .field("rules", &&self.rules[..self.len])
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Not};
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    #[test]
+    fn test_alerts_in_registration_order() {
+        let bullish = |s: &CandleStream<'_, Ohlcv>| s.get().is_some_and(|c| c.is_bullish());
+        let bearish = Not(bullish);
+
+        let mut engine = AlertEngine::<Ohlcv, 3>::new("ACME");
+        assert!(engine.register(AlertRule::new("Up Close", Direction::Bullish, &bullish)));
+        assert!(engine.register(AlertRule::pattern(Pattern::MorningStar)));
+        assert!(engine.register(AlertRule::new("Down Close", Direction::Bearish, &bearish)));
+        assert!(!engine.register(AlertRule::pattern(Pattern::EveningStar)));
+        assert!(engine
+            .register(AlertRule::new("Up Close", Direction::Bullish, &bullish).with_lookback(2)));
+        assert_eq!(engine.len(), 3);
+
+        let mut series = CandleStream::new();
+        for candle in &fixtures::MORNING_STAR {
+            series.push(candle);
+        }
+        let mut alerts = engine.evaluate(&series);
+
+        let up_close = alerts.next();
+        assert_eq!(up_close.map(|a| a.name), Some("Up Close"));
+        assert_eq!(up_close.map(|a| a.stop), Some(fixtures::MORNING_STAR[1].2));
+        assert_eq!(up_close.and_then(|a| a.pattern), None);
+
+        let star = alerts.next();
+        assert_eq!(star.and_then(|a| a.pattern), Some(Pattern::MorningStar));
+        assert_eq!(star.map(|a| a.stop), Some(fixtures::MORNING_STAR[1].2));
+        assert_eq!(star.map(|a| a.score), Some(1.0));
+        assert_eq!(alerts.next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_json_escapes_strings() {
+        use std::string::String;
+
+        let alert = Alert {
+            symbol: "A\"B\\C\n",
+            name: "Custom",
+            pattern: None,
+            direction: Direction::Bearish,
+            price: 1.5,
+            score: f64::NAN,
+            stop: 2.0,
+        };
+        let mut json = String::new();
+        assert!(alert.write_json(&mut json).is_ok());
+        assert_eq!(
+            json,
+            r#"{"symbol":"A\"B\\C\u000a","name":"Custom","direction":"bearish","price":1.5,"score":null,"stop":2}"#
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{CandleStick, Tuned};
+
+/// The `BidAskCandle` trait extends [`CandleStick`] with the bid/ask spread of the bar, for
+/// markets like FX or thin crypto pairs where the spread dwarfs the candle bodies.
+///
+/// A candle whose whole range fits within the spread carries no information: its open and
+/// close are mostly quoting noise. [`BidAskCandle::spread_gated`] ignores such doji in every
+/// single and multi-candle detection.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{BidAsk, BidAskCandle, CandleStick};
+/// let bid = (99.0, 99.2, 98.8, 99.0, 0.0);
+/// let ask = (99.5, 99.7, 99.3, 99.5, 0.0);
+/// let quoted = BidAsk::new(bid, ask);
+/// assert!(quoted.is_doji());
+/// assert!(quoted.is_within_spread());
+/// assert!(!quoted.spread_gated().is_doji());
+/// ```
+pub trait BidAskCandle: CandleStick {
+    /// Returns the average bid/ask spread over the bar
+    fn spread(&self) -> f64;
+
+    /// Returns true if the range of the candle is within its spread
+    fn is_within_spread(&self) -> bool {
+        self.high() - self.low() <= self.spread()
+    }
+
+    /// Wraps the candle so that doji whose range is within the spread are ignored
+    fn spread_gated(self) -> SpreadGated<Self>
+    where
+        Self: Sized,
+    {
+        SpreadGated(self)
+    }
+}
+
+impl<T: BidAskCandle + ?Sized> BidAskCandle for &T {
+    fn spread(&self) -> f64 {
+        (*self).spread()
+    }
+}
+
+impl<T: BidAskCandle> BidAskCandle for Tuned<T> {
+    fn spread(&self) -> f64 {
+        self.candle.spread()
+    }
+}
+
+/// A bar built from its bid and ask candles.
+///
+/// Prices are the mid prices and the spread is averaged over the four OHLC quotes. Volume
+/// and thresholds are taken from the bid side.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BidAsk<T> {
+    /// The bid candle
+    pub bid: T,
+    /// The ask candle
+    pub ask: T,
+}
+
+impl<T> BidAsk<T> {
+    /// Returns a new bar from its bid and ask candles
+    pub fn new(bid: T, ask: T) -> Self {
+        Self { bid, ask }
+    }
+}
+
+impl<T: CandleStick> BidAsk<T> {
+    fn mid(&self, price: impl Fn(&T) -> f64) -> f64 {
+        (price(&self.bid) + price(&self.ask)) / 2.0
+    }
+}
+
+impl<T: CandleStick> CandleStick for BidAsk<T> {
+    forward_candlestick!(thresholds, log_scale => |c| c.bid);
+
+    fn open(&self) -> f64 {
+        self.mid(T::open)
+    }
+
+    fn high(&self) -> f64 {
+        self.mid(T::high)
+    }
+
+    fn low(&self) -> f64 {
+        self.mid(T::low)
+    }
+
+    fn close(&self) -> f64 {
+        self.mid(T::close)
+    }
+
+    fn volume(&self) -> f64 {
+        self.bid.volume()
+    }
+}
+
+impl<T: CandleStick> BidAskCandle for BidAsk<T> {
+    fn spread(&self) -> f64 {
+        let (bo, bh, bl, bc) = self.bid.ohlc();
+        let (ao, ah, al, ac) = self.ask.ohlc();
+        ((ao - bo) + (ah - bh) + (al - bl) + (ac - bc)) / 4.0
+    }
+}
+
+/// A candle whose doji detections, including the doji variants and the multi-candle
+/// patterns built on them, are ignored when its range is within the spread.
+///
+/// Returned by [`BidAskCandle::spread_gated`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SpreadGated<T>(pub T);
+
+impl<T: BidAskCandle> CandleStick for SpreadGated<T> {
+    forward_candlestick!(thresholds, log_scale, prices => |c| c.0);
+
+    fn is_doji(&self) -> bool {
+        !self.0.is_within_spread() && self.0.is_doji()
+    }
+}
+
+impl<T: BidAskCandle> BidAskCandle for SpreadGated<T> {
+    fn spread(&self) -> f64 {
+        self.0.spread()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CandleStream;
+
+    #[test]
+    fn test_mid_prices_and_spread() {
+        let quoted = BidAsk::new((1.0, 1.5, 0.5, 1.25, 10.0), (1.5, 2.0, 1.0, 1.75, 10.0));
+
+        assert_eq!(quoted.ohlc(), (1.25, 1.75, 0.75, 1.5));
+        assert_eq!(quoted.spread(), 0.5);
+        assert!(!quoted.is_within_spread());
+    }
+
+    #[test]
+    fn test_spread_gated_doji_star() {
+        let prev = BidAsk::new(
+            (110.0, 111.0, 100.0, 101.0, 0.0),
+            (110.5, 111.5, 100.5, 101.5, 0.0),
+        );
+        let narrow = BidAsk::new((99.0, 99.2, 98.8, 99.0, 0.0), (99.5, 99.7, 99.3, 99.5, 0.0));
+        let wide = BidAsk::new(
+            (98.0, 99.0, 97.0, 98.05, 0.0),
+            (98.5, 99.5, 97.5, 98.55, 0.0),
+        );
+
+        let (prev, narrow, wide) = (
+            prev.spread_gated(),
+            narrow.spread_gated(),
+            wide.spread_gated(),
+        );
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&narrow);
+
+        assert!(narrow.0.is_doji());
+        assert!(!series.is_bullish_doji_star());
+
+        series.push(&prev).push(&wide);
+
+        assert!(series.is_bullish_doji_star());
+    }
+
+    #[test]
+    fn test_spread_gated_through_references() {
+        let prev = BidAsk::new(
+            (110.0, 111.0, 100.0, 101.0, 0.0),
+            (110.5, 111.5, 100.5, 101.5, 0.0),
+        )
+        .spread_gated();
+        let narrow = BidAsk::new((99.0, 99.2, 98.8, 99.0, 0.0), (99.5, 99.7, 99.3, 99.5, 0.0))
+            .spread_gated();
+
+        let (prev, narrow) = (&prev, &narrow);
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&narrow);
+
+        assert!(!(&narrow).is_doji());
+        assert!(!series.is_bullish_doji_star());
+    }
+}
@@ -0,0 +1,157 @@
+use std::io::{self, Read};
+
+use crate::{scanner::WindowScan, PatternSet};
+
+/// Size in bytes of one binary candle record
+const RECORD_SIZE: usize = 40;
+
+type Ohlcv = (f64, f64, f64, f64, f64);
+
+/// The `BinaryScanner` runs pattern detection over fixed-layout binary candle files, without
+/// loading them in memory.
+///
+/// Each record is 40 bytes long: the open, high, low, close and volume as little-endian
+/// `f64`. Only the last few candles are kept, so a buffered file of any size is scanned in
+/// constant memory. A memory-mapped file can be scanned as well, by passing its bytes as a
+/// slice.
+///
+/// The scanner yields the index of every bar completing at least one pattern of the mask,
+/// together with the detected [`PatternSet`], and stops after the first read error. A
+/// trailing partial record is reported as an [`io::ErrorKind::UnexpectedEof`] error.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{BinaryScanner, Pattern};
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0_f64),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let bytes: Vec<u8> = candles
+///     .iter()
+///     .flat_map(|&(o, h, l, c, v)| [o, h, l, c, v])
+///     .flat_map(f64::to_le_bytes)
+///     .collect();
+///
+/// let (idx, detected) = BinaryScanner::new(bytes.as_slice()).next().unwrap().unwrap();
+/// assert_eq!(idx, 1);
+/// assert!(detected.contains(Pattern::BullishEngulfing));
+/// ```
+#[derive(Debug)]
+pub struct BinaryScanner<R> {
+    reader: R,
+    mask: PatternSet,
+    scan: WindowScan<Ohlcv>,
+    done: bool,
+}
+
+impl<R: Read> BinaryScanner<R> {
+    /// Returns a scanner evaluating every pattern
+    pub fn new(reader: R) -> Self {
+        Self::with_mask(reader, PatternSet::all())
+    }
+
+    /// Returns a scanner evaluating only the patterns enabled in the mask
+    pub fn with_mask(reader: R, mask: PatternSet) -> Self {
+        Self {
+            reader,
+            mask,
+            scan: WindowScan::new(),
+            done: false,
+        }
+    }
+}
+
+/// Reads the next record, returning `None` at the end of the input
+fn read<R: Read>(reader: &mut R) -> io::Result<Option<Ohlcv>> {
+    let mut buf = [0; RECORD_SIZE];
+    let mut filled = 0;
+    while filled < RECORD_SIZE {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let field = |i: usize| f64::from_le_bytes(core::array::from_fn(|j| buf[i * 8 + j]));
+    Ok(Some((field(0), field(1), field(2), field(3), field(4))))
+}
+
+impl<R: Read> Iterator for BinaryScanner<R> {
+    type Item = io::Result<(usize, PatternSet)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut error = None;
+        let reader = &mut self.reader;
+        let candles = core::iter::from_fn(|| {
+            read(reader).unwrap_or_else(|e| {
+                error = Some(e);
+                None
+            })
+        });
+        if let Some(found) = self.scan.next_match(candles, self.mask) {
+            return Some(Ok(found));
+        }
+
+        self.done = true;
+        error.map(Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scanner;
+    use std::vec::Vec;
+
+    fn encode(candles: &[Ohlcv]) -> Vec<u8> {
+        candles
+            .iter()
+            .flat_map(|&(o, h, l, c, v)| [o, h, l, c, v])
+            .flat_map(f64::to_le_bytes)
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_slice_scanner() {
+        let candles = [
+            (100.0, 101.0, 98.0, 99.0, 0.0),
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (99.0, 103.0, 98.5, 102.5, 0.0),
+            (52.0, 52.5, 48.0, 48.5, 0.0),
+            (48.3, 48.9, 47.5, 48.4, 0.0),
+            (48.7, 51.5, 48.5, 51.2, 0.0),
+            (51.0, 52.0, 50.0, 51.5, 0.0),
+        ];
+        let bytes = encode(&candles);
+
+        let scanned: Vec<_> = BinaryScanner::new(bytes.as_slice())
+            .collect::<io::Result<_>>()
+            .unwrap_or_default();
+
+        assert!(!scanned.is_empty());
+        assert!(scanned.into_iter().eq(Scanner::new(&candles)));
+    }
+
+    #[test]
+    fn test_partial_record() {
+        let candles = [
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (99.0, 103.0, 98.5, 102.5, 0.0),
+        ];
+        let bytes = encode(&candles);
+
+        let mut scanner = BinaryScanner::new(&bytes[..bytes.len() - 1]);
+
+        assert!(scanner
+            .next()
+            .is_some_and(|r| r.is_err_and(|e| e.kind() == io::ErrorKind::UnexpectedEof)));
+        assert!(scanner.next().is_none());
+    }
+}
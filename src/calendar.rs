@@ -0,0 +1,223 @@
+use crate::{CandleStick, CandleStream, Timestamped};
+
+/// The `Calendar` trait tells whether a bar is expected at a given time, so that market
+/// closures (nights, weekends, holidays) are not reported as missing bars.
+///
+/// It is implemented for [`AlwaysOpen`] markets and for any `Fn(i64) -> bool` closure.
+/// Calendars knowing their session boundaries can provide them with
+/// [`Calendar::next_boundary`], so that holes are counted a session at a time.
+pub trait Calendar {
+    /// Returns true if a bar opening at the given time is expected
+    fn is_trading(&self, timestamp: i64) -> bool;
+
+    /// Returns the first time after the given one at which [`Calendar::is_trading`] may change,
+    /// like the end of the session or of the closure, or `None` if it never changes.
+    ///
+    /// Default: the next second, checking every expected bar
+    fn next_boundary(&self, timestamp: i64) -> Option<i64> {
+        timestamp.checked_add(1)
+    }
+}
+
+/// Calendar of markets trading around the clock, like crypto
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AlwaysOpen;
+
+impl Calendar for AlwaysOpen {
+    fn is_trading(&self, _: i64) -> bool {
+        true
+    }
+
+    fn next_boundary(&self, _: i64) -> Option<i64> {
+        None
+    }
+}
+
+impl<F: Fn(i64) -> bool> Calendar for F {
+    fn is_trading(&self, timestamp: i64) -> bool {
+        self(timestamp)
+    }
+}
+
+/// A hole in a candle series: expected bars missing between two consecutive candles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hole {
+    /// Open time of the candle before the hole
+    pub after: i64,
+    /// Open time of the candle after the hole
+    pub before: i64,
+    /// Number of expected bars missing
+    pub missing: usize,
+}
+
+impl Hole {
+    /// Returns the hole between two consecutive candles, if expected bars are missing.
+    ///
+    /// Bars are expected every `interval` from the first candle, at the times the calendar
+    /// is trading. They are counted arithmetically between the calendar boundaries, so a
+    /// hole of any size is measured in as many steps as it spans sessions. Out of order or
+    /// duplicated timestamps don't form holes.
+    pub fn between<C: Calendar>(
+        after: i64,
+        before: i64,
+        interval: i64,
+        calendar: &C,
+    ) -> Option<Self> {
+        if interval <= 0 {
+            return None;
+        }
+
+        let mut missing = 0_usize;
+        let mut next = after.checked_add(interval);
+        while let Some(ts) = next.filter(|ts| *ts < before) {
+            // bars from `ts` up to the boundary are all expected or all skipped
+            let end = calendar
+                .next_boundary(ts)
+                .map_or(before, |boundary| boundary.clamp(ts + 1, before));
+            let bars = (i128::from(end) - i128::from(ts) - 1) / i128::from(interval) + 1;
+
+            if calendar.is_trading(ts) {
+                missing = missing.saturating_add(usize::try_from(bars).unwrap_or(usize::MAX));
+            }
+            next = i64::try_from(bars)
+                .ok()
+                .and_then(|bars| bars.checked_mul(interval))
+                .and_then(|step| ts.checked_add(step));
+        }
+
+        match missing > 0 {
+            true => Some(Self {
+                after,
+                before,
+                missing,
+            }),
+            false => None,
+        }
+    }
+}
+
+impl<T: CandleStick + Timestamped, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the hole between the latest and the previous candle, if bars expected every
+    /// `interval` by the calendar are missing. Patterns spanning a hole are often meaningless.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{AlwaysOpen, Candle, CandleStream};
+    /// let prev = Candle::new(0, 100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let curr = Candle::new(180, 104.0, 106.0, 103.0, 105.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let hole = series.push(&prev).push(&curr).missing_bars(60, &AlwaysOpen);
+    /// assert_eq!(hole.map(|h| h.missing), Some(2));
+    /// ```
+    pub fn missing_bars<C: Calendar>(&self, interval: i64, calendar: &C) -> Option<Hole> {
+        let (c, p) = self.get().zip(self.prev(1))?;
+
+        Hole::between(
+            p.open_time(interval),
+            c.open_time(interval),
+            interval,
+            calendar,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+
+    #[test]
+    fn test_hole_between() {
+        assert_eq!(Hole::between(0, 60, 60, &AlwaysOpen), None);
+        assert_eq!(Hole::between(60, 0, 60, &AlwaysOpen), None);
+        assert_eq!(Hole::between(0, 0, 60, &AlwaysOpen), None);
+        assert_eq!(Hole::between(0, 600, 0, &AlwaysOpen), None);
+        assert_eq!(
+            Hole::between(0, 300, 60, &AlwaysOpen),
+            Some(Hole {
+                after: 0,
+                before: 300,
+                missing: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_hole_between_with_calendar() {
+        let day = 86_400;
+        let weekdays = |ts: i64| (ts / day + 3) % 7 < 5; // the unix epoch is a Thursday
+
+        let friday = day;
+        let monday = 4 * day;
+
+        assert_eq!(Hole::between(friday, monday, day, &weekdays), None);
+        assert_eq!(
+            Hole::between(friday, monday + day, day, &weekdays).map(|h| h.missing),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_hole_between_large_gaps() {
+        assert_eq!(
+            Hole::between(0, i64::MAX, 1, &AlwaysOpen).map(|h| h.missing),
+            usize::try_from(i64::MAX - 1).ok()
+        );
+        assert_eq!(
+            Hole::between(i64::MIN, i64::MAX, i64::MAX, &AlwaysOpen).map(|h| h.missing),
+            Some(2)
+        );
+        assert_eq!(
+            Hole::between(i64::MAX - 10, i64::MAX, 3, &AlwaysOpen).map(|h| h.missing),
+            Some(3)
+        );
+        assert_eq!(Hole::between(i64::MAX - 1, i64::MAX, 3, &AlwaysOpen), None);
+    }
+
+    #[test]
+    fn test_hole_between_by_session() {
+        struct Weekdays;
+
+        impl Calendar for Weekdays {
+            fn is_trading(&self, ts: i64) -> bool {
+                (ts.div_euclid(86_400) + 3) % 7 < 5
+            }
+
+            fn next_boundary(&self, ts: i64) -> Option<i64> {
+                ts.div_euclid(86_400).checked_add(1)?.checked_mul(86_400)
+            }
+        }
+
+        let day = 86_400;
+        let weekdays = |ts: i64| Weekdays.is_trading(ts);
+        for (after, before) in [(day, 4 * day), (day, 5 * day), (-3 * day, 40 * day + 7)] {
+            assert_eq!(
+                Hole::between(after, before, 60, &Weekdays),
+                Hole::between(after, before, 60, &weekdays)
+            );
+        }
+
+        let century = 36_525 * day;
+        let missing = Hole::between(0, century, 60, &Weekdays).map_or(0, |h| h.missing);
+        assert_eq!(missing, 26_089 * 1_440 - 1);
+    }
+
+    #[test]
+    fn test_missing_bars() {
+        let candle1 = Candle::new(0, 100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = Candle::new(60, 104.0, 106.0, 103.0, 105.0, 0.0);
+        let candle3 = Candle::new(240, 105.0, 107.0, 104.0, 106.0, 0.0);
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.push(&candle1).missing_bars(60, &AlwaysOpen), None);
+        assert_eq!(series.push(&candle2).missing_bars(60, &AlwaysOpen), None);
+        assert_eq!(
+            series.push(&candle3).missing_bars(60, &AlwaysOpen),
+            Some(Hole {
+                after: 60,
+                before: 240,
+                missing: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::CandleStick;
+
+/// The `Timestamped` trait exposes the time a candle is keyed by, used by the time-aware
+/// features like missing-bar detection.
+///
+/// Candles are keyed by their open time, unless their source only provides the close time,
+/// like barter-data: such candles override [`Timestamped::open_time`], so that calendars,
+/// sessions and filled holes still judge each bar by the time it opened.
+///
+/// Timestamps are plain integers: any unit works (unix seconds, milliseconds, ...) as long as
+/// durations given to the time-aware APIs use the same unit.
+pub trait Timestamped {
+    /// Returns the time the candle is keyed by, its open time unless stated otherwise
+    fn timestamp(&self) -> i64;
+
+    /// Returns the open time of the candle, bars lasting `interval`.
+    ///
+    /// Default: the timestamp
+    fn open_time(&self, interval: i64) -> i64 {
+        let _ = interval;
+        self.timestamp()
+    }
+}
+
+/// A ready-to-use timestamped OHLCV candle
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Candle, CandleStick, Timestamped};
+/// let candle = Candle::new(1_700_000_000, 100.0, 110.0, 99.0, 109.0, 1250.0);
+/// assert!(candle.is_bullish_marubozu());
+/// assert_eq!(candle.timestamp(), 1_700_000_000);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// Open time of the candle
+    pub timestamp: i64,
+    /// Open price
+    pub open: f64,
+    /// High price
+    pub high: f64,
+    /// Low price
+    pub low: f64,
+    /// Close price
+    pub close: f64,
+    /// Traded volume
+    pub volume: f64,
+}
+
+impl Candle {
+    /// Returns a new candle
+    pub fn new(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Self {
+        Self {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+}
+
+impl<T: CandleStick + Timestamped> From<&T> for Candle {
+    fn from(candle: &T) -> Self {
+        Self::new(
+            candle.timestamp(),
+            candle.open(),
+            candle.high(),
+            candle.low(),
+            candle.close(),
+            candle.volume(),
+        )
+    }
+}
+
+impl CandleStick for Candle {
+    fn open(&self) -> f64 {
+        self.open
+    }
+
+    fn high(&self) -> f64 {
+        self.high
+    }
+
+    fn low(&self) -> f64 {
+        self.low
+    }
+
+    fn close(&self) -> f64 {
+        self.close
+    }
+
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+impl Timestamped for Candle {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+impl<T: Timestamped> Timestamped for &T {
+    fn timestamp(&self) -> i64 {
+        (*self).timestamp()
+    }
+
+    fn open_time(&self, interval: i64) -> i64 {
+        (*self).open_time(interval)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{CandleType, Inverted};
+
 /// The `CandleStick` trait provides analytical capabilities to detect key single-candle
 /// formations that signal potential market reversals, continuations, or indecision.
 ///
@@ -92,6 +94,41 @@ pub trait CandleStick {
         0.2
     }
 
+    /// Tick size of the instrument, the smallest price increment. Prices within one tick are
+    /// considered equal and gaps are only meaningful from [`CandleStick::min_gap_ticks`] ticks.
+    /// Can be overridden for exchange-traded instruments where one tick of difference is noise.
+    ///
+    /// Default: __0__ (exact comparisons)
+    fn tick_size(&self) -> f64 {
+        0.0
+    }
+
+    /// Minimum size of a gap, in ticks, for it to be meaningful. Can be overridden.
+    ///
+    /// Default: __1__
+    fn min_gap_ticks(&self) -> u32 {
+        1
+    }
+
+    /// Maximum difference between two prices considered matching, like the highs of a tweezer
+    /// top, as a fraction of the candle range. Never tighter than one tick. Can be overridden.
+    ///
+    /// Default: __5%__
+    fn price_match_ratio(&self) -> f64 {
+        0.05
+    }
+
+    /// Computes the candle geometry (body, shadows, range and gaps) in log-price space.
+    /// Can be overridden to keep pattern sensitivity consistent for assets that moved
+    /// orders of magnitude within a dataset. Price matches and gaps are compared in log-price
+    /// space too, while the tick size still applies to prices.
+    ///
+    /// Default: __false__
+    #[cfg(feature = "std")]
+    fn log_scale(&self) -> bool {
+        false
+    }
+
     /// Returns the open price
     fn open(&self) -> f64;
 
@@ -113,28 +150,66 @@ pub trait CandleStick {
         (self.open(), self.high(), self.low(), self.close())
     }
 
-    /// Helper function to return the candle length with small epsilon
+    /// Helper function to map a price to the space the candle geometry is computed in
+    #[doc(hidden)]
+    fn scaled(&self, price: f64) -> f64 {
+        #[cfg(feature = "std")]
+        if self.log_scale() {
+            return price.ln();
+        }
+
+        price
+    }
+
+    /// Helper function to return the candle length with small epsilon, applied to prices so it
+    /// doesn't depend on the scale
     #[doc(hidden)]
     fn range(&self) -> f64 {
-        (self.high() - self.low()).max(0.001)
+        let high = self.high().max(self.low() + 0.001);
+        self.scaled(high) - self.scaled(self.low())
     }
 
     /// Helper function to return the candle wick length of the candle
     #[doc(hidden)]
     fn wick(&self) -> f64 {
-        self.high() - self.open().max(self.close())
+        self.scaled(self.high()) - self.scaled(self.open().max(self.close()))
     }
 
-    /// Helper function to return the candle body as the absolute difference between the open and close prices with small epsilon
+    /// Helper function to return the candle body as the absolute difference between the open and close prices with small epsilon,
+    /// applied to prices so it doesn't depend on the scale
     #[doc(hidden)]
     fn body(&self) -> f64 {
-        ((self.open() - self.close()).abs()).max(0.0001)
+        let bottom = self.open().min(self.close());
+        let top = self.open().max(self.close()).max(bottom + 0.0001);
+        self.scaled(top) - self.scaled(bottom)
     }
 
     /// Helper function to return the candle tail length
     #[doc(hidden)]
     fn tail(&self) -> f64 {
-        self.open().min(self.close()) - self.low()
+        self.scaled(self.open().min(self.close())) - self.scaled(self.low())
+    }
+
+    /// Helper function to compare two prices within one tick
+    #[doc(hidden)]
+    fn prices_equal(&self, lhs: f64, rhs: f64) -> bool {
+        (lhs - rhs).abs() <= self.tick_size()
+    }
+
+    /// Helper function to compare two prices within the price match tolerance of the candle
+    #[doc(hidden)]
+    fn prices_match(&self, lhs: f64, rhs: f64) -> bool {
+        let tolerance =
+            self.price_match_ratio() * (self.scaled(self.high()) - self.scaled(self.low()));
+        (self.scaled(lhs) - self.scaled(rhs)).abs() <= tolerance || self.prices_equal(lhs, rhs)
+    }
+
+    /// Helper function to check for a meaningful gap from the lower price up to the upper price
+    #[doc(hidden)]
+    fn is_gap(&self, lower: f64, upper: f64) -> bool {
+        let min_gap = self.min_gap_ticks() as f64 * self.tick_size();
+        let gap = self.scaled(upper) - self.scaled(lower);
+        gap > 0.0 && gap >= self.scaled(lower + min_gap) - self.scaled(lower)
     }
 
     /// Helper function to return the candle's wick to range ratio
@@ -490,6 +565,84 @@ pub trait CandleStick {
             && self.tail_range_ratio() < self.doji_min_ratio()
     }
 
+    /// Classifies the candle into the single best-fitting [`CandleType`].
+    ///
+    /// Unlike the individual `is_*` checks, which overlap, this returns exactly one label per
+    /// candle following the priority order documented on [`CandleType`].
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStick, CandleType};
+    /// let candle = (100.0, 100.5, 90.0, 100.1, 0.0);
+    /// assert!(candle.is_doji() && candle.is_dragonfly_doji());
+    /// assert_eq!(candle.classify(), CandleType::DragonflyDoji);
+    /// ```
+    fn classify(&self) -> CandleType {
+        if self.is_dragonfly_doji() {
+            CandleType::DragonflyDoji
+        } else if self.is_gravestone_doji() {
+            CandleType::GravestoneDoji
+        } else if self.is_long_legged_doji() {
+            CandleType::LongLeggedDoji
+        } else if self.is_doji() {
+            CandleType::Doji
+        } else if self.is_bullish_marubozu() {
+            CandleType::BullishMarubozu
+        } else if self.is_bearish_marubozu() {
+            CandleType::BearishMarubozu
+        } else if self.is_hammer() {
+            CandleType::Hammer
+        } else if self.is_inverted_hammer() {
+            CandleType::InvertedHammer
+        } else if self.is_spinning_top() {
+            CandleType::SpinningTop
+        } else if self.is_bullish() {
+            CandleType::Bullish
+        } else if self.is_bearish() {
+            CandleType::Bearish
+        } else {
+            CandleType::Neutral
+        }
+    }
+
+    /// Returns the change from open to close as a fraction of the open price
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStick;
+    /// let candle = (100.0, 110.0, 99.0, 105.0, 0.0);
+    /// assert_eq!(candle.pct_change(), 0.05);
+    /// ```
+    fn pct_change(&self) -> f64 {
+        (self.close() - self.open()) / self.open()
+    }
+
+    /// Returns the change from the previous candle's close to this candle's close, as a
+    /// fraction of the previous close
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStick;
+    /// let prev = (100.0, 110.0, 99.0, 100.0, 0.0);
+    /// let candle = (101.0, 103.0, 98.0, 98.0, 0.0);
+    /// assert_eq!(candle.return_from(&prev), -0.02);
+    /// ```
+    fn return_from(&self, prev: &dyn CandleStick) -> f64 {
+        (self.close() - prev.close()) / prev.close()
+    }
+
+    /// Returns the logarithmic return from open to close
+    #[cfg(feature = "std")]
+    fn log_return(&self) -> f64 {
+        (self.close() / self.open()).ln()
+    }
+
+    /// Returns the logarithmic return from the previous candle's close to this candle's close
+    #[cfg(feature = "std")]
+    fn log_return_from(&self, prev: &dyn CandleStick) -> f64 {
+        (self.close() / prev.close()).ln()
+    }
+
     /// Summarizes the price action for the candle
     fn typical_price(&self) -> f64 {
         (self.high() + self.low() + self.close()) / 3.0
@@ -499,6 +652,27 @@ pub trait CandleStick {
     fn raw_money_flow(&self) -> f64 {
         self.typical_price() * self.volume()
     }
+
+    /// Returns the candle reflected around the `pivot` price, a bullish candle becoming a
+    /// bearish one of the same shape. Candles in log scale are reflected geometrically.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStick;
+    /// let candle = (100.0, 110.0, 99.0, 109.0, 0.0);
+    /// let inverted = candle.invert(100.0);
+    /// assert_eq!(inverted.ohlc(), (100.0, 101.0, 90.0, 91.0));
+    /// assert!(candle.is_bullish() && inverted.is_bearish());
+    /// ```
+    fn invert(&self, pivot: f64) -> Inverted<&Self>
+    where
+        Self: Sized,
+    {
+        Inverted {
+            candle: self,
+            pivot,
+        }
+    }
 }
 
 impl CandleStick for (f64, f64, f64, f64, f64) {
@@ -527,28 +701,85 @@ impl CandleStick for (f64, f64, f64, f64, f64) {
     }
 }
 
-impl CandleStick for &(f64, f64, f64, f64, f64) {
-    fn open(&self) -> f64 {
-        self.0
-    }
+impl<T: CandleStick + ?Sized> CandleStick for &T {
+    forward_candlestick!(thresholds, log_scale, prices, is_doji => |c| *c);
+}
 
-    /// Returns the high price
-    fn high(&self) -> f64 {
-        self.1
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    struct LogCandle(f64, f64, f64, f64);
+
+    impl CandleStick for LogCandle {
+        fn log_scale(&self) -> bool {
+            true
+        }
+
+        fn tick_size(&self) -> f64 {
+            self.0 * 1e-4
+        }
+
+        fn open(&self) -> f64 {
+            self.0
+        }
+
+        fn high(&self) -> f64 {
+            self.1
+        }
+
+        fn low(&self) -> f64 {
+            self.2
+        }
+
+        fn close(&self) -> f64 {
+            self.3
+        }
+
+        fn volume(&self) -> f64 {
+            0.0
+        }
     }
 
-    /// Returns the low price
-    fn low(&self) -> f64 {
-        self.2
+    #[test]
+    fn test_log_scale_is_price_scale_invariant() {
+        let cheap = LogCandle(1.000, 1.010, 0.950, 1.008);
+        let expensive = LogCandle(1000.0, 1010.0, 950.0, 1008.0);
+
+        assert!((cheap.body_range_ratio() - expensive.body_range_ratio()).abs() < 1e-9);
+        assert_eq!(cheap.is_hammer(), expensive.is_hammer());
+        assert!(expensive.is_hammer());
+
+        let linear = (0.0100, 0.0101, 0.0095, 0.01008, 0.0);
+        assert!(!linear.is_hammer());
     }
 
-    /// Returns the close price
-    fn close(&self) -> f64 {
-        self.3
+    #[test]
+    fn test_log_scale_geometry() {
+        let candle = LogCandle(100.0, 200.0, 50.0, 100.0);
+
+        assert!((candle.wick() - 2f64.ln()).abs() < 1e-12);
+        assert!((candle.tail() - 2f64.ln()).abs() < 1e-12);
+        assert!((candle.range() - 4f64.ln()).abs() < 1e-12);
+        assert!(candle.is_long_legged_doji());
     }
 
-    /// Returns the volume
-    fn volume(&self) -> f64 {
-        self.4
+    #[test]
+    fn test_log_scale_epsilons_are_price_level_invariant() {
+        for level in [1.0, 100.0, 10_000.0] {
+            let doji = LogCandle(
+                100.02 * level,
+                100.05 * level,
+                100.0 * level,
+                100.021 * level,
+            );
+            assert!(doji.is_doji(), "doji at level {level}");
+
+            let candle = LogCandle(100.0 * level, 101.0 * level, 99.0 * level, 100.5 * level);
+            assert!(candle.is_gap(100.0 * level, 100.02 * level));
+            assert!(!candle.is_gap(100.0 * level, 100.005 * level));
+            assert!(candle.prices_match(101.0 * level, 101.09 * level));
+            assert!(!candle.prices_match(101.0 * level, 101.2 * level));
+        }
     }
 }
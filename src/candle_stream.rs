@@ -1,6 +1,21 @@
-use crate::{utils::midpoint, CandleStick};
+use crate::{
+    rolling::MonotonicDeque, shape::Shape, utils::midpoint, CandleStick, Pattern, PatternSet,
+    Suppression,
+};
 
-const SERIES_SIZE: usize = 5;
+pub(crate) const SERIES_SIZE: usize = 5;
+
+// Maximum body of a star, as a fraction of the body of the first candle
+pub(crate) const STAR_BODY_RATIO: f64 = 0.3;
+
+// Maximum overlap of a near star's body with the first body, as a fraction of the latter
+pub(crate) const NEAR_STAR_OVERLAP: f64 = 0.25;
+
+// Maximum body of a corrective candle of the three methods, as a fraction of the first body
+pub(crate) const METHODS_BODY_RATIO: f64 = 0.5;
+
+// Minimum body of a long candle, as a fraction of its range
+pub(crate) const LONG_BODY_RATIO: f64 = 0.6;
 
 /// The `CandleStream` provides detection capabilities for powerful multi-candle patterns
 ///
@@ -32,53 +47,265 @@ const SERIES_SIZE: usize = 5;
 /// ```
 
 #[derive(Debug)]
-pub struct CandleStream<'s, T> {
-    series: [Option<&'s T>; SERIES_SIZE],
+pub struct CandleStream<'s, T, const N: usize = SERIES_SIZE> {
+    series: [Option<&'s T>; N],
+    shapes: [Shape; N],
     idx: usize,
+    count: usize,
+    highs: MonotonicDeque<N>,
+    lows: MonotonicDeque<N>,
 }
 
-impl<'s, T> CandleStream<'s, T> {
+impl<T> CandleStream<'_, T> {
     /// Returns a new candle series
     pub fn new() -> Self {
         Self::default()
     }
+}
+
+impl<'s, T, const N: usize> CandleStream<'s, T, N> {
+    /// Returns a new candle series holding up to `N` candles, for lookbacks longer than
+    /// the default 5 candles
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// series.push(&candle);
+    /// assert_eq!(series.capacity(), 20);
+    /// ```
+    pub fn with_capacity() -> Self {
+        Self::default()
+    }
+
+    // Returns an empty series numbering the next candle as if `pushed` candles preceded it
+    pub(crate) fn resumed_after(pushed: usize) -> Self {
+        Self {
+            idx: pushed % N,
+            count: pushed,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the maximum number of candles held by the series
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of candles currently held by the series
+    pub fn len(&self) -> usize {
+        self.count.min(N)
+    }
+
+    /// Returns true if no candle has been pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the total number of candles pushed since the series was created, which keeps
+    /// growing once the series is full
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let mut series = CandleStream::<_, 2>::with_capacity();
+    /// series.push(&candle).push(&candle).push(&candle);
+    /// assert_eq!((series.len(), series.pushed()), (2, 3));
+    /// ```
+    pub fn pushed(&self) -> usize {
+        self.count
+    }
 
     // Returns the index of the nth last candle
     fn nth_index(&self, n: usize) -> Option<usize> {
-        if n > SERIES_SIZE {
+        if n > N {
             return None;
         }
 
-        Some((self.idx + SERIES_SIZE - n) % SERIES_SIZE)
+        Some((self.idx + N - n) % N)
     }
 
     // Returns the candle at the given index
     fn at(&self, idx: usize) -> Option<&T> {
-        match idx < SERIES_SIZE {
+        match idx < N {
             true => self.series[idx],
             false => None,
         }
     }
 
-    // Fetches reference to the current candle
-    fn get(&self) -> Option<&T> {
+    /// Returns the latest candle pushed to the series
+    pub fn get(&self) -> Option<&T> {
         self.at(self.nth_index(1)?)
     }
 
-    // Returns the previous candle
-    fn prev(&self, n: usize) -> Option<&T> {
+    /// Returns the nth candle before the latest one, `prev(1)` being the previous candle
+    pub fn prev(&self, n: usize) -> Option<&T> {
         self.at(self.nth_index(n + 1)?)
     }
 
+    // Returns the candle at the given position along with its shape evaluated on push
+    pub(crate) fn shaped(&self, bars_ago: usize) -> Option<(&T, Shape)> {
+        let idx = self.nth_index(bars_ago + 1)?;
+        Some((self.at(idx)?, self.shapes[idx]))
+    }
+
+    /// Iterates over the candles held by the series, from the latest to the oldest
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&candle1).push(&candle2);
+    /// assert!(series.iter().eq([&candle2, &candle1]));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len()).filter_map(|n| self.prev(n))
+    }
+}
+
+impl<'s, T: CandleStick, const N: usize> CandleStream<'s, T, N> {
     /// Pushes a candle to the series
+    ///
+    /// The direction, doji and gap checks shared by the multi-candle patterns are evaluated
+    /// once here, so detecting many patterns on every bar doesn't repeat them.
     pub fn push(&mut self, candle: &'s T) -> &mut Self {
-        self.series[self.idx % SERIES_SIZE] = Some(candle);
-        self.idx = (self.idx + 1) % SERIES_SIZE;
+        let shape = Shape::of(candle, self.get());
+        self.push_shaped(candle, shape)
+    }
+
+    // Pushes a candle whose shape is already known, like a restored one
+    pub(crate) fn push_shaped(&mut self, candle: &'s T, shape: Shape) -> &mut Self {
+        self.shapes[self.idx % N] = shape;
+        self.series[self.idx % N] = Some(candle);
+        self.idx = (self.idx + 1) % N;
+        self.highs.push(self.count, candle.high());
+        self.lows.push(self.count, -candle.low());
+        self.count += 1;
         self
     }
+
+    /// Returns the highest high of the last `n` candles, or `None` if fewer than `n` candles
+    /// are available or `n` exceeds the capacity of the series.
+    ///
+    /// Extremes are tracked incrementally on push, so the lookup doesn't rescan the window.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 112.0, 99.0, 104.0, 0.0);
+    /// let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&candle1).push(&candle2);
+    /// assert_eq!(series.highest_high(1), Some(110.0));
+    /// assert_eq!(series.highest_high(2), Some(112.0));
+    /// assert_eq!(series.highest_high(3), None);
+    /// ```
+    pub fn highest_high(&self, n: usize) -> Option<f64> {
+        self.highs.max_since(self.window_start(n)?)
+    }
+
+    /// Returns the lowest low of the last `n` candles, or `None` if fewer than `n` candles
+    /// are available or `n` exceeds the capacity of the series.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 112.0, 99.0, 104.0, 0.0);
+    /// let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&candle1).push(&candle2);
+    /// assert_eq!(series.lowest_low(1), Some(104.0));
+    /// assert_eq!(series.lowest_low(2), Some(99.0));
+    /// ```
+    pub fn lowest_low(&self, n: usize) -> Option<f64> {
+        self.lows.max_since(self.window_start(n)?).map(|low| -low)
+    }
+
+    /// Returns the signed gap between the latest and the previous candle ranges.
+    ///
+    /// Positive when the latest low is above the previous high (gap up), negative when the
+    /// latest high is below the previous low (gap down), and zero when the ranges overlap or
+    /// the gap is smaller than the minimum gap size in ticks of the latest candle.
+    /// The gap is measured in log-price when the latest candle uses log scale.
+    /// Returns `None` with fewer than two candles.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let curr = (106.0, 110.0, 105.5, 109.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert_eq!(series.push(&prev).push(&curr).gap_to_prev(), Some(0.5));
+    /// ```
+    pub fn gap_to_prev(&self) -> Option<f64> {
+        let (c, p) = self.get().zip(self.prev(1))?;
+
+        Some(
+            match (c.is_gap(p.high(), c.low()), c.is_gap(c.high(), p.low())) {
+                (true, _) => c.scaled(c.low()) - c.scaled(p.high()),
+                (_, true) => c.scaled(c.high()) - c.scaled(p.low()),
+                _ => 0.0,
+            },
+        )
+    }
+
+    /// Returns the length of the price range shared by the latest and the previous candle,
+    /// zero when they don't overlap. Returns `None` with fewer than two candles.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let curr = (104.0, 108.0, 103.0, 107.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert_eq!(series.push(&prev).push(&curr).overlap_with_prev(), Some(2.0));
+    /// ```
+    pub fn overlap_with_prev(&self) -> Option<f64> {
+        let (c, p) = self.get().zip(self.prev(1))?;
+
+        Some((c.high().min(p.high()) - c.low().max(p.low())).max(0.0))
+    }
+
+    /// Returns the close to close return of the latest candle, as a fraction of the previous
+    /// close. Returns `None` with fewer than two candles.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.0, 99.0, 100.0, 0.0);
+    /// let curr = (100.0, 103.0, 99.0, 102.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert_eq!(series.push(&prev).push(&curr).return_from_prev(), Some(0.02));
+    /// ```
+    pub fn return_from_prev(&self) -> Option<f64> {
+        let (c, p) = self.get().zip(self.prev(1))?;
+
+        Some(c.return_from(p))
+    }
+
+    /// Returns the close to close logarithmic return of the latest candle.
+    /// Returns `None` with fewer than two candles.
+    #[cfg(feature = "std")]
+    pub fn log_return_from_prev(&self) -> Option<f64> {
+        let (c, p) = self.get().zip(self.prev(1))?;
+
+        Some(c.log_return_from(p))
+    }
+
+    // Returns the sequence number of the oldest candle of the last n candles
+    fn window_start(&self, n: usize) -> Option<usize> {
+        match n > 0 && n <= self.len() {
+            true => Some(self.count - n),
+            false => None,
+        }
+    }
 }
 
-impl<T: CandleStick> CandleStream<'_, T> {
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
     /// Identifies a Bullish Doji Star pattern, a potential reversal signal in downtrends.
     ///
     /// This two-candle pattern occurs when a bearish candle is followed by a Doji that gaps below
@@ -99,9 +326,9 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bullish_doji_star());
     /// ```
     pub fn is_bullish_doji_star(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .is_some_and(|(c, p)| p.is_bearish() && c.is_doji() && c.high() < p.low())
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((_, cs), (_, ps))| ps.is_bearish() && cs.is_doji() && cs.is_gap_down())
     }
 
     /// Identifies a Bearish Doji Star pattern, a potential reversal signal in uptrends.
@@ -124,9 +351,137 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bearish_doji_star());
     /// ```
     pub fn is_bearish_doji_star(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .is_some_and(|(c, p)| p.is_bullish() && c.is_doji() && c.low() > p.high())
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((_, cs), (_, ps))| ps.is_bullish() && cs.is_doji() && cs.is_gap_up())
+    }
+
+    /// Identifies a Bullish Star pattern, a potential reversal signal in downtrends.
+    ///
+    /// This two-candle pattern occurs when a bearish candle is followed by any small-bodied candle
+    /// whose body gaps below the prior close. Unlike the Doji Star, the star may have a real body,
+    /// as long as it is small relative to the first one.
+    ///
+    /// **Trading Significance**:
+    /// - Shows the selling momentum stalling after a gap in its direction
+    /// - The first two candles of a Morning Star, before the confirming candle
+    /// - Traders typically wait for a bullish candle closing into the first body before buying
+    /// - Weaker than the Doji Star, since the star still leans to one side
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let curr = (47.6, 48.0, 46.9, 47.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_bullish_star());
+    /// ```
+    pub fn is_bullish_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, _), (p, ps))| {
+                ps.is_bearish()
+                    && is_star_body(c, p)
+                    && c.is_gap(c.open().max(c.close()), p.close())
+            })
+    }
+
+    /// Identifies a Bearish Star pattern, a potential reversal signal in uptrends.
+    ///
+    /// This two-candle pattern occurs when a bullish candle is followed by any small-bodied candle
+    /// whose body gaps above the prior close. Unlike the Doji Star, the star may have a real body,
+    /// as long as it is small relative to the first one.
+    ///
+    /// **Trading Significance**:
+    /// - Shows the buying momentum stalling after a gap in its direction
+    /// - The first two candles of an Evening Star, before the confirming candle
+    /// - Traders typically wait for a bearish candle closing into the first body before selling
+    /// - Weaker than the Doji Star, since the star still leans to one side
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 106.0, 99.5, 105.5, 0.0);
+    /// let curr = (106.2, 107.0, 105.8, 106.8, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_bearish_star());
+    /// ```
+    pub fn is_bearish_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, _), (p, ps))| {
+                ps.is_bullish()
+                    && is_star_body(c, p)
+                    && c.is_gap(p.close(), c.open().min(c.close()))
+            })
+    }
+
+    /// Identifies a Bullish Near Star pattern, a softer variant of the Bullish Star.
+    ///
+    /// This two-candle pattern occurs when a bearish candle is followed by a small-bodied candle
+    /// that does not gap, but whose body rises at most a quarter of the first body above the
+    /// prior close. Markets trading around the clock rarely gap, making this the common form of
+    /// the star outside of equities.
+    ///
+    /// **Trading Significance**:
+    /// - Shows the selling momentum stalling near the lows of the first candle
+    /// - Less reliable than the gapped star, as sellers kept some control of the open
+    /// - Traders typically require a strong bullish confirmation candle before buying
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let curr = (48.8, 49.0, 47.9, 48.4, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev).push(&curr);
+    /// assert!(series.is_bullish_near_star());
+    /// assert!(!series.is_bullish_star());
+    /// ```
+    pub fn is_bullish_near_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, _), (p, ps))| {
+                let top = c.open().max(c.close());
+                ps.is_bearish()
+                    && is_star_body(c, p)
+                    && !c.is_gap(top, p.close())
+                    && top <= p.close() + (p.open() - p.close()) * NEAR_STAR_OVERLAP
+            })
+    }
+
+    /// Identifies a Bearish Near Star pattern, a softer variant of the Bearish Star.
+    ///
+    /// This two-candle pattern occurs when a bullish candle is followed by a small-bodied candle
+    /// that does not gap, but whose body falls at most a quarter of the first body below the
+    /// prior close. Markets trading around the clock rarely gap, making this the common form of
+    /// the star outside of equities.
+    ///
+    /// **Trading Significance**:
+    /// - Shows the buying momentum stalling near the highs of the first candle
+    /// - Less reliable than the gapped star, as buyers kept some control of the open
+    /// - Traders typically require a strong bearish confirmation candle before selling
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 106.0, 99.5, 105.5, 0.0);
+    /// let curr = (105.2, 106.3, 105.0, 105.7, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev).push(&curr);
+    /// assert!(series.is_bearish_near_star());
+    /// assert!(!series.is_bearish_star());
+    /// ```
+    pub fn is_bearish_near_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, _), (p, ps))| {
+                let bottom = c.open().min(c.close());
+                ps.is_bullish()
+                    && is_star_body(c, p)
+                    && !c.is_gap(p.close(), bottom)
+                    && bottom >= p.close() - (p.close() - p.open()) * NEAR_STAR_OVERLAP
+            })
     }
 
     ///
@@ -150,9 +505,11 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bullish_engulfing());
     /// ```
     pub fn is_bullish_engulfing(&self) -> bool {
-        self.get().zip(self.prev(1)).is_some_and(|(c, p)| {
-            p.is_bearish() && c.is_bullish() && c.open() < p.close() && c.close() > p.open()
-        })
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bearish() && cs.is_bullish() && c.open() < p.close() && c.close() > p.open()
+            })
     }
 
     /// Identifies a Bearish Engulfing pattern, a strong reversal signal at the end of uptrends.
@@ -175,9 +532,11 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bearish_engulfing());
     /// ```
     pub fn is_bearish_engulfing(&self) -> bool {
-        self.get().zip(self.prev(1)).is_some_and(|(c, p)| {
-            p.is_bullish() && c.is_bearish() && c.open() > p.close() && c.close() < p.open()
-        })
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bullish() && cs.is_bearish() && c.open() > p.close() && c.close() < p.open()
+            })
     }
 
     /// Identifies a Bullish Harami pattern, indicating potential reversal or continuation in downtrends.
@@ -200,9 +559,11 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bullish_harami());
     /// ```
     pub fn is_bullish_harami(&self) -> bool {
-        self.get().zip(self.prev(1)).is_some_and(|(c, p)| {
-            p.is_bearish() && c.is_bullish() && c.open() > p.close() && c.close() < p.open()
-        })
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bearish() && cs.is_bullish() && c.open() > p.close() && c.close() < p.open()
+            })
     }
 
     /// Identifies a Bearish Harami pattern, indicating potential reversal or continuation in uptrends.
@@ -225,9 +586,11 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_bearish_harami());
     /// ```
     pub fn is_bearish_harami(&self) -> bool {
-        self.get().zip(self.prev(1)).is_some_and(|(c, p)| {
-            p.is_bullish() && c.is_bearish() && c.open() < p.close() && c.close() > p.open()
-        })
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bullish() && cs.is_bearish() && c.open() < p.close() && c.close() > p.open()
+            })
     }
 
     /// Identifies a Dark Cloud Cover pattern, a bearish reversal signal in uptrends.
@@ -250,12 +613,203 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev).push(&curr).is_dark_cloud_cover());
     /// ```
     pub fn is_dark_cloud_cover(&self) -> bool {
-        self.get().zip(self.prev(1)).is_some_and(|(c, p)| {
-            c.is_bearish()
-                && p.is_bullish()
-                && c.open() > p.close()
-                && c.close() < midpoint(p.open(), p.close())
-        })
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                cs.is_bearish()
+                    && ps.is_bullish()
+                    && c.open() > p.close()
+                    && c.close() < midpoint(p.open(), p.close())
+            })
+    }
+
+    /// Identifies a Piercing Line pattern, a bullish reversal signal in downtrends.
+    ///
+    /// This two-candle pattern occurs when a bullish candle opens below the prior bearish candle's low
+    /// but closes above the midpoint of the prior candle's body, without engulfing it. It shows
+    /// rejection of lower prices.
+    ///
+    /// **Trading Significance**:
+    /// - Signals strong buying pressure after a downtrend
+    /// - More significant when the bullish candle closes deep into the prior bearish candle
+    /// - Often used by traders to exit short positions or initiate long positions
+    /// - Particularly effective when appearing at historical support levels
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+    /// let curr = (99.0, 103.5, 98.5, 103.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_piercing_line());
+    /// ```
+    pub fn is_piercing_line(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                cs.is_bullish()
+                    && ps.is_bearish()
+                    && c.open() < p.low()
+                    && c.close() > midpoint(p.open(), p.close())
+                    && c.close() < p.open()
+            })
+    }
+
+    /// Identifies an In-Neck line, a bearish continuation pattern in downtrends.
+    ///
+    /// This two-candle pattern occurs when a bullish candle opens below the prior bearish candle's low
+    /// but only closes slightly into its body, just above its close. The close must be within the
+    /// [`CandleStick::price_match_ratio`] of the first candle's range above the prior close.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a weak bounce that stalls at the prior close, sellers remaining in control
+    /// - Looks like a Piercing Line but fails to reach the midpoint, and reads the opposite way
+    /// - Often used to add to short positions once the next candle resumes the decline
+    /// - Less reliable on thin volume, where small bounces carry little information
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+    /// let curr = (99.0, 100.3, 98.5, 100.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_in_neck());
+    /// ```
+    pub fn is_in_neck(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                cs.is_bullish()
+                    && ps.is_bearish()
+                    && c.open() < p.low()
+                    && c.close() > p.close()
+                    && p.prices_match(p.close(), c.close())
+            })
+    }
+
+    /// Identifies Bullish Meeting Lines, also known as a bullish counterattack line.
+    ///
+    /// This two-candle pattern occurs when a long bullish candle opens below the close of a prior
+    /// long bearish candle and rallies back to close at the same level. Closes match within the
+    /// [`CandleStick::price_match_ratio`] of the first candle's range, and long bodies cover at
+    /// least 60% of their range.
+    ///
+    /// **Trading Significance**:
+    /// - The sellers' gap lower is entirely erased, stalling the decline
+    /// - Weaker than the Piercing Line, the close not reaching into the first body
+    /// - Often used to cover short positions rather than to enter long ones
+    /// - More reliable when confirmed by a higher close on the next candle
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (105.0, 105.3, 99.8, 100.0, 0.0);
+    /// let curr = (95.0, 100.1, 94.8, 99.9, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_bullish_meeting_lines());
+    /// ```
+    pub fn is_bullish_meeting_lines(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bearish()
+                    && p.body_range_ratio() >= LONG_BODY_RATIO
+                    && cs.is_bullish()
+                    && c.body_range_ratio() >= LONG_BODY_RATIO
+                    && c.open() < p.close()
+                    && p.prices_match(p.close(), c.close())
+            })
+    }
+
+    /// Identifies Bearish Meeting Lines, also known as a bearish counterattack line.
+    ///
+    /// This two-candle pattern occurs when a long bearish candle gaps up, opening above the high
+    /// of a prior long bullish candle, and falls back to close at the same level. Closes match
+    /// within the [`CandleStick::price_match_ratio`] of the first candle's range, and long bodies
+    /// cover at least 60% of their range.
+    ///
+    /// **Trading Significance**:
+    /// - The buyers' gap higher is entirely erased, stalling the advance
+    /// - Weaker than the Dark Cloud Cover, the close not reaching into the first body
+    /// - Often used to take profits on long positions rather than to enter short ones
+    /// - More reliable when confirmed by a lower close on the next candle
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.2, 99.7, 105.0, 0.0);
+    /// let curr = (110.0, 110.2, 104.9, 105.1, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_bearish_meeting_lines());
+    /// ```
+    pub fn is_bearish_meeting_lines(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bullish()
+                    && p.body_range_ratio() >= LONG_BODY_RATIO
+                    && cs.is_bearish()
+                    && c.body_range_ratio() >= LONG_BODY_RATIO
+                    && c.is_gap(p.high(), c.open())
+                    && p.prices_match(p.close(), c.close())
+            })
+    }
+
+    /// Identifies a Tweezer Top pattern, a bearish reversal signal in uptrends.
+    ///
+    /// This two-candle pattern occurs when a bearish candle follows a bullish candle extending the
+    /// advance, both topping out at the same high. Highs match within the
+    /// [`CandleStick::price_match_ratio`] of the first candle's range, so the tolerance scales
+    /// with the instrument and can be tuned through [`crate::Thresholds`].
+    ///
+    /// **Trading Significance**:
+    /// - Shows the same price level rejected twice in a row, marking short-term resistance
+    /// - More significant when the highs are long upper shadows or reach a known resistance
+    /// - Often used to place stop losses just above the matching highs
+    /// - Usually requires confirmation, as tweezers are frequent in ranging markets
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+    /// let curr = (104.5, 105.1, 101.0, 101.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_tweezer_top());
+    /// ```
+    pub fn is_tweezer_top(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bullish() && cs.is_bearish() && p.prices_match(p.high(), c.high())
+            })
+    }
+
+    /// Identifies a Tweezer Bottom pattern, a bullish reversal signal in downtrends.
+    ///
+    /// This two-candle pattern occurs when a bullish candle follows a bearish candle extending the
+    /// decline, both bottoming out at the same low. Lows match within the
+    /// [`CandleStick::price_match_ratio`] of the first candle's range.
+    ///
+    /// **Trading Significance**:
+    /// - Shows the same price level defended twice in a row, marking short-term support
+    /// - More significant when the lows are long lower shadows or reach a known support
+    /// - Often used to place stop losses just below the matching lows
+    /// - Usually requires confirmation, as tweezers are frequent in ranging markets
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (104.5, 105.0, 100.0, 100.5, 0.0);
+    /// let curr = (100.5, 103.5, 99.9, 103.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_tweezer_bottom());
+    /// ```
+    pub fn is_tweezer_bottom(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((c, cs), (p, ps))| {
+                ps.is_bearish() && cs.is_bullish() && p.prices_match(p.low(), c.low())
+            })
     }
 
     /// Identifies an Evening Star pattern, a bearish reversal formation at market tops.
@@ -281,13 +835,13 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_evening_star());
     /// ```
     pub fn is_evening_star(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bullish()
-                    && (p1.is_doji() || p1.open() < p1.close())
-                    && c.is_bearish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && (s1.is_doji() || p1.open() < p1.close())
+                    && cs.is_bearish()
                     && c.close() < midpoint(p2.open(), p2.close())
             })
     }
@@ -314,12 +868,12 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_evening_star_doji());
     /// ```
     pub fn is_evening_star_doji(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bullish()
-                    && p1.is_doji() & c.is_bearish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (_, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_doji() & cs.is_bearish()
                     && c.close() < midpoint(p2.open(), p2.close())
             })
     }
@@ -347,13 +901,13 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_morning_star());
     /// ```
     pub fn is_morning_star(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bearish()
-                    && (p1.is_doji() || p1.open() < p1.close())
-                    && c.is_bullish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && (s1.is_doji() || p1.open() < p1.close())
+                    && cs.is_bullish()
                     && c.close() > midpoint(p2.open(), p2.close())
             })
     }
@@ -380,86 +934,180 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_morning_star_doji());
     /// ```
     pub fn is_morning_star_doji(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bearish()
-                    && p1.is_doji()
-                    && c.is_bullish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (_, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_doji()
+                    && cs.is_bullish()
                     && c.close() > midpoint(p2.open(), p2.close())
             })
     }
 
-    /// Identifies Three White Soldiers, a powerful bullish reversal or continuation pattern.
+    /// Identifies a Bullish Abandoned Baby, the strongest bullish reversal of the star family.
     ///
-    /// This three-candle pattern consists of consecutive bullish candles, each opening within the previous
-    /// candle's body and closing higher, creating a stair-step appearance. Each candle shows progressively
-    /// stronger buying pressure overtaking sellers.
+    /// This three-candle pattern is a stricter Morning Star Doji where the Doji is isolated from
+    /// both of its neighbors:
+    /// 1. A bearish candle extending the downtrend
+    /// 2. A Doji gapping down, its high below the first candle's low
+    /// 3. A bullish candle gapping up, its low above the Doji's high
     ///
     /// **Trading Significance**:
-    /// - Indicates sustained buying pressure and strong bullish momentum
-    /// - Shows buyers controlling the market over multiple time periods
-    /// - Traders use it to confirm bullish trend reversals or continuations
-    /// - Most reliable when candles have minimal upper shadows (little selling pressure at highs)
+    /// - The abandoned Doji marks the exhaustion of sellers, stranded on an island below the market
+    /// - Rare, but considered one of the most reliable bullish reversal signals
+    /// - The unfilled gap above the Doji often acts as support on later pullbacks
+    /// - Traders often place stop losses below the Doji's low
     ///
     /// # Example
     /// ```
     /// use candlestick_rs::CandleStream;
-    /// let prev2 = (48.0, 50.5, 47.8, 50.2, 0.0);
-    /// let prev1 = (50.3, 52.7, 50.1, 52.4, 0.0);
-    /// let curr =  (52.5, 54.8, 52.3, 54.5, 0.0);
+    /// let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let prev1 = (47.0, 47.5, 46.8, 47.0, 0.0);
+    /// let curr = (48.0, 51.5, 47.8, 51.2, 0.0);
     /// let mut series = CandleStream::new();
-    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_white_soldiers());
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_bullish_abandoned_baby());
     /// ```
-    pub fn is_three_white_soldiers(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bullish()
-                    && p1.is_bullish()
-                    && p1.open() > p2.close()
-                    && p1.close() > p2.close()
-                    && c.is_bullish()
-                    && c.open() > p1.close()
-                    && c.close() > p1.close()
+    pub fn is_bullish_abandoned_baby(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((_, cs), (_, s1)), (_, s2))| {
+                s2.is_bearish()
+                    && s1.is_doji()
+                    && s1.is_gap_down()
+                    && cs.is_bullish()
+                    && cs.is_gap_up()
             })
     }
 
-    /// Identifies Three Black Crows, a powerful bearish reversal or continuation pattern.
+    /// Identifies a Bullish Tri-Star, a rare bullish reversal pattern at market bottoms.
     ///
-    /// This three-candle pattern consists of consecutive bearish candles, each opening within the previous
-    /// candle's body and closing lower, creating a downward stair-step appearance. Each candle shows progressively
-    /// stronger selling pressure overtaking buyers.
+    /// This three-candle pattern consists of three consecutive Doji, the middle one gapping below
+    /// both of the others: its high is below the lows of the first and the last Doji.
     ///
     /// **Trading Significance**:
-    /// - Indicates sustained selling pressure and strong bearish momentum
-    /// - Shows sellers controlling the market over multiple time periods
-    /// - Traders use it to confirm bearish trend reversals or continuations
-    /// - Most reliable when candles have minimal lower shadows (little buying pressure at lows)
+    /// - Three sessions of indecision in a row signal that the decline has run out of sellers
+    /// - The gaps around the middle Doji isolate the low, much like an abandoned baby
+    /// - Rare, but considered a significant reversal warning when it appears after a long decline
+    /// - Traders often wait for a bullish candle to confirm before entering long positions
     ///
     /// # Example
     /// ```
     /// use candlestick_rs::CandleStream;
-    /// let prev2 = (54.0, 54.5, 51.8, 52.2, 0.0);
-    /// let prev1 = (52.0, 52.3, 49.7, 50.4, 0.0);
-    /// let curr =  (50.2, 50.5, 47.9, 48.3, 0.0);
+    /// let prev2 = (100.0, 100.5, 99.5, 100.02, 0.0);
+    /// let prev1 = (99.0, 99.3, 98.7, 99.0, 0.0);
+    /// let curr = (99.8, 100.2, 99.5, 99.82, 0.0);
     /// let mut series = CandleStream::new();
-    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_black_crows());
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_bullish_tri_star());
     /// ```
-    pub fn is_three_black_crows(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bearish()
-                    && p1.is_bearish()
-                    && p1.open() < p2.close()
-                    && p1.close() < p2.close()
-                    && c.is_bearish()
-                    && c.open() < p1.close()
-                    && c.close() < p1.close()
+    pub fn is_bullish_tri_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((_, cs), (_, s1)), (_, s2))| {
+                s2.is_doji() && s1.is_doji() && s1.is_gap_down() && cs.is_doji() && cs.is_gap_up()
+            })
+    }
+
+    /// Identifies a Bearish Tri-Star, a rare bearish reversal pattern at market tops.
+    ///
+    /// This three-candle pattern consists of three consecutive Doji, the middle one gapping above
+    /// both of the others: its low is above the highs of the first and the last Doji.
+    ///
+    /// **Trading Significance**:
+    /// - Three sessions of indecision in a row signal that the advance has run out of buyers
+    /// - The gaps around the middle Doji isolate the high, much like an abandoned baby
+    /// - Rare, but considered a significant reversal warning when it appears after a long advance
+    /// - Traders often tighten stops on long positions or wait for a bearish candle to short
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 100.5, 99.5, 99.98, 0.0);
+    /// let prev1 = (101.0, 101.3, 100.7, 101.0, 0.0);
+    /// let curr = (100.2, 100.5, 99.8, 100.18, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_bearish_tri_star());
+    /// ```
+    pub fn is_bearish_tri_star(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((_, cs), (_, s1)), (_, s2))| {
+                s2.is_doji() && s1.is_doji() && s1.is_gap_up() && cs.is_doji() && cs.is_gap_down()
+            })
+    }
+
+    /// Identifies Three White Soldiers, a powerful bullish reversal or continuation pattern.
+    ///
+    /// This three-candle pattern consists of consecutive bullish candles, each opening within the previous
+    /// candle's body and closing higher, creating a stair-step appearance. Each candle shows progressively
+    /// stronger buying pressure overtaking sellers.
+    ///
+    /// **Trading Significance**:
+    /// - Indicates sustained buying pressure and strong bullish momentum
+    /// - Shows buyers controlling the market over multiple time periods
+    /// - Traders use it to confirm bullish trend reversals or continuations
+    /// - Most reliable when candles have minimal upper shadows (little selling pressure at highs)
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (48.0, 50.5, 47.8, 50.2, 0.0);
+    /// let prev1 = (50.3, 52.7, 50.1, 52.4, 0.0);
+    /// let curr =  (52.5, 54.8, 52.3, 54.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_white_soldiers());
+    /// ```
+    pub fn is_three_white_soldiers(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_bullish()
+                    && p1.open() > p2.close()
+                    && p1.close() > p2.close()
+                    && cs.is_bullish()
+                    && c.open() > p1.close()
+                    && c.close() > p1.close()
+            })
+    }
+
+    /// Identifies Three Black Crows, a powerful bearish reversal or continuation pattern.
+    ///
+    /// This three-candle pattern consists of consecutive bearish candles, each opening within the previous
+    /// candle's body and closing lower, creating a downward stair-step appearance. Each candle shows progressively
+    /// stronger selling pressure overtaking buyers.
+    ///
+    /// **Trading Significance**:
+    /// - Indicates sustained selling pressure and strong bearish momentum
+    /// - Shows sellers controlling the market over multiple time periods
+    /// - Traders use it to confirm bearish trend reversals or continuations
+    /// - Most reliable when candles have minimal lower shadows (little buying pressure at lows)
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (54.0, 54.5, 51.8, 52.2, 0.0);
+    /// let prev1 = (52.0, 52.3, 49.7, 50.4, 0.0);
+    /// let curr =  (50.2, 50.5, 47.9, 48.3, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_black_crows());
+    /// ```
+    pub fn is_three_black_crows(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_bearish()
+                    && p1.open() < p2.close()
+                    && p1.close() < p2.close()
+                    && cs.is_bearish()
+                    && c.open() < p1.close()
+                    && c.close() < p1.close()
             })
     }
 
@@ -487,17 +1135,17 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_inside_up());
     /// ```
     pub fn is_three_inside_up(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bearish()
-                    && p1.is_bullish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_bullish()
                     && p1.open() > p2.close()
                     && p1.close() < p2.open()
-                    && c.is_bullish()
+                    && cs.is_bullish()
                     && c.close() > p1.close()
-                    && !c.is_doji()
+                    && !cs.is_doji()
             })
     }
 
@@ -524,91 +1172,849 @@ impl<T: CandleStick> CandleStream<'_, T> {
     /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_inside_down());
     /// ```
     pub fn is_three_inside_down(&self) -> bool {
-        self.get()
-            .zip(self.prev(1))
-            .zip(self.prev(2))
-            .is_some_and(|((c, p1), p2)| {
-                p2.is_bullish()
-                    && p1.is_bearish()
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_bearish()
                     && p1.open() < p2.close()
                     && p1.close() > p2.open()
-                    && c.is_bearish()
+                    && cs.is_bearish()
                     && c.close() < p1.close()
-                    && !c.is_doji()
+                    && !cs.is_doji()
             })
     }
-}
 
-impl<T> Default for CandleStream<'_, T> {
-    fn default() -> Self {
-        Self {
-            series: [const { None }; SERIES_SIZE],
-            idx: 0,
-        }
+    /// Identifies the Three Outside Down pattern, a bearish reversal.
+    ///
+    /// This three-candle pattern typically appears in an uptrend and confirms a bearish engulfing:
+    /// a bullish candle is engulfed by a bearish candle, and a third bearish candle closes below
+    /// the engulfing candle's close.
+    ///
+    /// **Trading Significance**:
+    /// - Confirms that sellers kept control after overwhelming the buyers
+    /// - More reliable than the bearish engulfing alone, at the cost of a later entry
+    /// - Often used to enter shorts or exit longs once the third candle closes
+    /// - Considerably stronger when the engulfing candle forms at resistance or on high volume
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (99.0, 100.5, 98.5, 100.0, 0.0);
+    /// let prev1 = (101.5, 102.0, 97.0, 98.5, 0.0);
+    /// let curr  = (98.4, 98.6, 96.0, 96.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_three_outside_down());
+    /// ```
+    pub fn is_three_outside_down(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_bearish()
+                    && p1.open() > p2.close()
+                    && p1.close() < p2.open()
+                    && cs.is_bearish()
+                    && c.close() < p1.close()
+                    && !cs.is_doji()
+            })
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_nth_index() {
-        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
-        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
-        let candle3 = (109.5, 112.0, 108.0, 111.0, 0.0);
-        let candle4 = (111.5, 115.0, 110.0, 114.0, 0.0);
-        let candle5 = (114.5, 118.0, 113.0, 117.0, 0.0);
-        let candle6 = (117.5, 120.0, 116.0, 119.0, 0.0);
-
-        let mut stream = CandleStream::new();
 
-        assert_eq!(stream.nth_index(6), None);
-
-        stream.push(&candle1).push(&candle2);
+    /// Identifies an Upside Tasuki Gap, a bullish continuation pattern.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A bullish candle extending the uptrend
+    /// 2. A second bullish candle gapping up, its low above the first candle's high
+    /// 3. A bearish candle opening within the second body and closing into the gap, without
+    ///    filling it
+    ///
+    /// **Trading Significance**:
+    /// - The third candle is profit taking rather than a reversal, as the gap holds as support
+    /// - Often used to enter longs on the close of the third candle
+    /// - The pattern fails if a later candle closes below the first candle's high, filling the gap
+    /// - Rare, and more reliable when the two bullish candles are of similar size
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+    /// let prev1 = (104.0, 107.0, 103.5, 106.5, 0.0);
+    /// let curr = (105.5, 106.0, 103.1, 103.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_upside_tasuki_gap());
+    /// ```
+    pub fn is_upside_tasuki_gap(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_bullish()
+                    && s1.is_gap_up()
+                    && cs.is_bearish()
+                    && c.open() > p1.open()
+                    && c.open() < p1.close()
+                    && c.close() < p1.low()
+                    && c.close() > p2.high()
+            })
+    }
 
-        assert_eq!(stream.nth_index(0), Some(2));
-        assert_eq!(stream.nth_index(1), Some(1));
-        assert_eq!(stream.nth_index(2), Some(0));
+    /// Identifies a Downside Tasuki Gap, a bearish continuation pattern.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A bearish candle extending the downtrend
+    /// 2. A second bearish candle gapping down, its high below the first candle's low
+    /// 3. A bullish candle opening within the second body and closing into the gap, without
+    ///    filling it
+    ///
+    /// **Trading Significance**:
+    /// - The third candle is short covering rather than a reversal, as the gap holds as resistance
+    /// - Often used to enter shorts on the close of the third candle
+    /// - The pattern fails if a later candle closes above the first candle's low, filling the gap
+    /// - Rare, and more reliable when the two bearish candles are of similar size
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+    /// let prev1 = (102.0, 102.5, 99.0, 99.5, 0.0);
+    /// let curr = (100.5, 102.9, 100.0, 102.8, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_downside_tasuki_gap());
+    /// ```
+    pub fn is_downside_tasuki_gap(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_bearish()
+                    && s1.is_gap_down()
+                    && cs.is_bullish()
+                    && c.open() < p1.open()
+                    && c.open() > p1.close()
+                    && c.close() > p1.high()
+                    && c.close() < p2.low()
+            })
+    }
 
-        stream.push(&candle3).push(&candle4).push(&candle5);
+    /// Identifies Upside-Gap Side-by-Side White Lines, a bullish continuation pattern.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A bullish candle extending the uptrend
+    /// 2. A second bullish candle gapping up, its low above the first candle's high
+    /// 3. A third bullish candle of similar size, opening at about the same level as the second
+    ///    and staying above the gap
+    ///
+    /// Opens and body sizes match within the [`CandleStick::price_match_ratio`] of the second
+    /// candle's range.
+    ///
+    /// **Trading Significance**:
+    /// - Shows buyers defending the new level twice after the gap, which holds as support
+    /// - Rare, and more meaningful when the gap breaks out of a consolidation
+    /// - Often used to add to long positions, with stops below the gap
+    /// - The pattern fails if a later candle closes back into the first candle's range
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+    /// let prev1 = (104.0, 106.2, 103.6, 106.0, 0.0);
+    /// let curr = (104.05, 106.3, 103.8, 106.1, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev2).push(&prev1).push(&curr);
+    /// assert!(series.is_upgap_side_by_side_white_lines());
+    /// ```
+    pub fn is_upgap_side_by_side_white_lines(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bullish()
+                    && s1.is_bullish()
+                    && s1.is_gap_up()
+                    && cs.is_bullish()
+                    && c.is_gap(p2.high(), c.low())
+                    && p1.prices_match(p1.open(), c.open())
+                    && bodies_match(p1, c)
+            })
+    }
 
-        assert_eq!(stream.nth_index(0), Some(0));
-        assert_eq!(stream.nth_index(1), Some(4));
-        assert_eq!(stream.nth_index(2), Some(3));
-        assert_eq!(stream.nth_index(3), Some(2));
-        assert_eq!(stream.nth_index(4), Some(1));
-        assert_eq!(stream.nth_index(5), Some(0));
+    /// Identifies Downside-Gap Side-by-Side White Lines, a bearish continuation pattern.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A bearish candle extending the downtrend
+    /// 2. A bullish candle gapping down, its high below the first candle's low
+    /// 3. A second bullish candle of similar size, opening at about the same level as the first
+    ///    one and staying below the gap
+    ///
+    /// Opens and body sizes match within the [`CandleStick::price_match_ratio`] of the second
+    /// candle's range.
+    ///
+    /// **Trading Significance**:
+    /// - The bullish candles are short covering, too weak to close the gap which holds as resistance
+    /// - Rare, and often read as a pause before the decline resumes
+    /// - Often used to add to short positions, with stops above the gap
+    /// - The pattern fails if a later candle closes back into the first candle's range
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+    /// let prev1 = (100.0, 102.4, 99.8, 102.0, 0.0);
+    /// let curr = (100.05, 102.5, 99.9, 102.1, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev2).push(&prev1).push(&curr);
+    /// assert!(series.is_downgap_side_by_side_white_lines());
+    /// ```
+    pub fn is_downgap_side_by_side_white_lines(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_bullish()
+                    && s1.is_gap_down()
+                    && cs.is_bullish()
+                    && c.is_gap(c.high(), p2.low())
+                    && p1.prices_match(p1.open(), c.open())
+                    && bodies_match(p1, c)
+            })
+    }
 
-        stream.push(&candle6);
+    /// Identifies a Stick Sandwich, a bullish reversal pattern in downtrends.
+    ///
+    /// This three-candle pattern consists of a bullish candle closing above the close of a prior
+    /// bearish candle, sandwiched by a second bearish candle closing back at the same level. Closes
+    /// match within the [`CandleStick::price_match_ratio`] of the first candle's range.
+    ///
+    /// **Trading Significance**:
+    /// - Two closes at the same level reveal a support that sellers could not break
+    /// - The failed rally in between makes it a subtle pattern, often mistaken for weakness
+    /// - Often used to enter longs with stops just below the matching closes
+    /// - More reliable when the matching closes sit on a known support level
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (104.0, 104.5, 99.5, 100.0, 0.0);
+    /// let prev1 = (100.5, 103.5, 100.2, 103.0, 0.0);
+    /// let curr = (103.2, 103.6, 99.8, 100.1, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_stick_sandwich());
+    /// ```
+    pub fn is_stick_sandwich(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .zip(self.shaped(2))
+            .is_some_and(|(((c, cs), (p1, s1)), (p2, s2))| {
+                s2.is_bearish()
+                    && s1.is_bullish()
+                    && p1.close() > p2.close()
+                    && cs.is_bearish()
+                    && p2.prices_match(p2.close(), c.close())
+            })
+    }
 
-        assert_eq!(stream.nth_index(0), Some(1));
-        assert_eq!(stream.nth_index(1), Some(0));
-        assert_eq!(stream.nth_index(2), Some(4));
-        assert_eq!(stream.nth_index(3), Some(3));
-        assert_eq!(stream.nth_index(4), Some(2));
-        assert_eq!(stream.nth_index(5), Some(1));
+    /// Identifies a Bullish Island Reversal, a bullish reversal pattern at market bottoms.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A candle extending the downtrend
+    /// 2. A candle gapping down, its high below the first candle's low
+    /// 3. A candle gapping up, its low above the second candle's high
+    ///
+    /// The second candle is left as an island of price at the lows, whatever the colors. Islands
+    /// spanning more candles are found by [`CandleStream::bullish_island`].
+    ///
+    /// **Trading Significance**:
+    /// - An exhaustion gap followed by a breakaway gap traps the traders who sold at the lows
+    /// - One of the strongest reversal signals, though rare on liquid markets trading around
+    ///   the clock
+    /// - Often used to enter long positions, with stops below the island low
+    /// - More reliable when the gap up comes on higher volume than the island
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+    /// let prev1 = (101.0, 102.0, 100.0, 101.5, 0.0);
+    /// let curr = (103.5, 104.8, 103.2, 104.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_bullish_island_reversal());
+    /// ```
+    pub fn is_bullish_island_reversal(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((_, cs), (_, s1))| s1.is_gap_down() && cs.is_gap_up())
     }
 
-    #[test]
-    fn test_at() {
-        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
-        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
-        let candle3 = (109.5, 112.0, 108.0, 111.0, 0.0);
-        let candle4 = (111.5, 115.0, 110.0, 114.0, 0.0);
-        let candle5 = (114.5, 118.0, 113.0, 117.0, 0.0);
-        let candle6 = (117.5, 120.0, 116.0, 119.0, 0.0);
+    /// Returns the number of candles of the island left by the latest candle gapping up, or
+    /// `None` if there is no such island within the series.
+    ///
+    /// The island is made of the candles between a gap down and the gap up of the latest
+    /// candle, both gaps clearing the highest high of the island. The shortest island is
+    /// returned, a single candle being the [`CandleStream::is_bullish_island_reversal`].
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (106.0, 106.5, 103.0, 103.5, 0.0),
+    ///     (102.0, 102.5, 100.5, 101.0, 0.0),
+    ///     (101.0, 102.8, 100.0, 102.5, 0.0),
+    ///     (103.5, 104.8, 103.2, 104.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert_eq!(series.bullish_island(), Some(2));
+    /// assert!(!series.is_bullish_island_reversal());
+    /// ```
+    pub fn bullish_island(&self) -> Option<usize> {
+        let c = self.get()?;
+        let mut island_high = f64::MIN;
+
+        for n in 1..self.len().saturating_sub(1) {
+            let (island, before) = self.prev(n).zip(self.prev(n + 1))?;
+            island_high = island_high.max(island.high());
+            if !c.is_gap(island_high, c.low()) {
+                return None;
+            }
+            if island.is_gap(island_high, before.low()) {
+                return Some(n);
+            }
+        }
+        None
+    }
 
-        let mut stream = CandleStream::new();
-        stream.push(&candle1).push(&candle2);
+    /// Identifies a Bearish Island Reversal, a bearish reversal pattern at market tops.
+    ///
+    /// This three-candle pattern consists of:
+    /// 1. A candle extending the uptrend
+    /// 2. A candle gapping up, its low above the first candle's high
+    /// 3. A candle gapping down, its high below the second candle's low
+    ///
+    /// The second candle is left as an island of price at the highs, whatever the colors. Islands
+    /// spanning more candles are found by [`CandleStream::bearish_island`].
+    ///
+    /// **Trading Significance**:
+    /// - An exhaustion gap followed by a breakaway gap traps the traders who bought the highs
+    /// - One of the strongest reversal signals, though rare on liquid markets trading around
+    ///   the clock
+    /// - Often used to exit longs or enter short positions, with stops above the island high
+    /// - More reliable when the gap down comes on higher volume than the island
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+    /// let prev1 = (104.5, 106.0, 104.0, 105.0, 0.0);
+    /// let curr = (102.5, 103.8, 101.2, 101.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_bearish_island_reversal());
+    /// ```
+    pub fn is_bearish_island_reversal(&self) -> bool {
+        self.shaped(0)
+            .zip(self.shaped(1))
+            .is_some_and(|((_, cs), (_, s1))| s1.is_gap_up() && cs.is_gap_down())
+    }
 
-        assert_eq!(stream.at(0), Some(&candle1));
-        assert_eq!(stream.at(1), Some(&candle2));
-        assert_eq!(stream.at(2), None);
+    /// Returns the number of candles of the island left by the latest candle gapping down, or
+    /// `None` if there is no such island within the series.
+    ///
+    /// The island is made of the candles between a gap up and the gap down of the latest
+    /// candle, both gaps clearing the lowest low of the island. The shortest island is
+    /// returned, a single candle being the [`CandleStream::is_bearish_island_reversal`].
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 103.0, 99.5, 102.5, 0.0),
+    ///     (104.5, 106.0, 104.0, 105.0, 0.0),
+    ///     (105.0, 105.5, 103.6, 104.0, 0.0),
+    ///     (102.5, 103.4, 101.2, 101.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert_eq!(series.bearish_island(), Some(2));
+    /// assert!(!series.is_bearish_island_reversal());
+    /// ```
+    pub fn bearish_island(&self) -> Option<usize> {
+        let c = self.get()?;
+        let mut island_low = f64::MAX;
+
+        for n in 1..self.len().saturating_sub(1) {
+            let (island, before) = self.prev(n).zip(self.prev(n + 1))?;
+            island_low = island_low.min(island.low());
+            if !c.is_gap(c.high(), island_low) {
+                return None;
+            }
+            if island.is_gap(before.high(), island_low) {
+                return Some(n);
+            }
+        }
+        None
+    }
 
-        stream.push(&candle3).push(&candle4).push(&candle5);
+    /// Identifies the Rising Three Methods, a bullish continuation pattern.
+    ///
+    /// This five-candle pattern consists of:
+    /// 1. A long bullish candle extending the uptrend
+    /// 2. Three small bearish candles held within the first candle's range, a pause rather than
+    ///    a reversal
+    /// 3. A bullish candle closing at a new high, above the first candle's high
+    ///
+    /// The first body must be at least 60% of its range, and corrective bodies smaller than half
+    /// of the first body.
+    ///
+    /// **Trading Significance**:
+    /// - Shows sellers unable to push prices out of the range of the initial advance
+    /// - The final candle confirms that buyers have resumed control
+    /// - Often used to add to long positions, with stops below the first candle's low
+    /// - More reliable when the corrective candles trade on lighter volume than the outer ones
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 106.0, 99.5, 105.5, 0.0),
+    ///     (105.0, 105.5, 103.5, 104.0, 0.0),
+    ///     (104.0, 104.5, 102.5, 103.0, 0.0),
+    ///     (103.0, 103.5, 101.5, 102.0, 0.0),
+    ///     (102.0, 108.0, 101.8, 107.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_rising_three_methods());
+    /// ```
+    pub fn is_rising_three_methods(&self) -> bool {
+        let (Some((c, cs)), Some((first, fs))) = (self.shaped(0), self.shaped(4)) else {
+            return false;
+        };
+
+        fs.is_bullish()
+            && first.body_range_ratio() >= LONG_BODY_RATIO
+            && (1..4).all(|n| {
+                self.shaped(n).is_some_and(|(m, ms)| {
+                    ms.is_bearish()
+                        && m.high() <= first.high()
+                        && m.low() >= first.low()
+                        && m.body() < first.body() * METHODS_BODY_RATIO
+                })
+            })
+            && cs.is_bullish()
+            && c.close() > first.high()
+    }
 
-        assert_eq!(stream.at(0), Some(&candle1));
+    /// Identifies the Falling Three Methods, a bearish continuation pattern.
+    ///
+    /// This five-candle pattern consists of:
+    /// 1. A long bearish candle extending the downtrend
+    /// 2. Three small bullish candles held within the first candle's range, a pause rather than
+    ///    a reversal
+    /// 3. A bearish candle closing at a new low, below the first candle's low
+    ///
+    /// The first body must be at least 60% of its range, and corrective bodies smaller than half
+    /// of the first body.
+    ///
+    /// **Trading Significance**:
+    /// - Shows buyers unable to lift prices out of the range of the initial decline
+    /// - The final candle confirms that sellers have resumed control
+    /// - Often used to add to short positions, with stops above the first candle's high
+    /// - More reliable when the corrective candles trade on lighter volume than the outer ones
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (106.0, 106.5, 100.0, 100.5, 0.0),
+    ///     (101.0, 102.5, 100.5, 102.0, 0.0),
+    ///     (102.0, 103.5, 101.5, 103.0, 0.0),
+    ///     (103.0, 104.5, 102.5, 104.0, 0.0),
+    ///     (104.0, 104.2, 98.0, 98.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_falling_three_methods());
+    /// ```
+    pub fn is_falling_three_methods(&self) -> bool {
+        let (Some((c, cs)), Some((first, fs))) = (self.shaped(0), self.shaped(4)) else {
+            return false;
+        };
+
+        fs.is_bearish()
+            && first.body_range_ratio() >= LONG_BODY_RATIO
+            && (1..4).all(|n| {
+                self.shaped(n).is_some_and(|(m, ms)| {
+                    ms.is_bullish()
+                        && m.high() <= first.high()
+                        && m.low() >= first.low()
+                        && m.body() < first.body() * METHODS_BODY_RATIO
+                })
+            })
+            && cs.is_bearish()
+            && c.close() < first.low()
+    }
+
+    /// Identifies a Mat Hold, a bullish continuation pattern.
+    ///
+    /// This five-candle pattern consists of:
+    /// 1. A strong bullish candle extending the uptrend
+    /// 2. A small candle gapping up, its low above the first candle's high
+    /// 3. Two more small candles pulling back, the three bodies holding above the first close
+    /// 4. A bullish candle breaking out, closing above the highs of the pullback
+    ///
+    /// The first body must be at least 60% of its range, and pullback bodies smaller than half of
+    /// the first body, whatever their color.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a shallow pause that never gives back the gains of the first candle
+    /// - Considered more reliable than the Rising Three Methods, the pullback holding higher
+    /// - Often used to add to long positions on the breakout, with stops below the first close
+    /// - More reliable when the pullback trades on lighter volume than the breakout
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 105.5, 99.5, 105.0, 0.0),
+    ///     (106.5, 107.5, 106.0, 107.0, 0.0),
+    ///     (107.0, 107.2, 105.8, 106.0, 0.0),
+    ///     (106.0, 106.5, 105.2, 105.5, 0.0),
+    ///     (105.6, 109.0, 105.4, 108.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_mat_hold());
+    /// ```
+    pub fn is_mat_hold(&self) -> bool {
+        let (Some((c, cs)), Some((first, fs))) = (self.shaped(0), self.shaped(4)) else {
+            return false;
+        };
+
+        fs.is_bullish()
+            && first.body_range_ratio() >= LONG_BODY_RATIO
+            && self.shaped(3).is_some_and(|(_, s3)| s3.is_gap_up())
+            && (1..4).all(|n| {
+                self.shaped(n).is_some_and(|(m, _)| {
+                    m.open().min(m.close()) > first.close()
+                        && m.body() < first.body() * METHODS_BODY_RATIO
+                        && c.close() > m.high()
+                })
+            })
+            && cs.is_bullish()
+    }
+
+    /// Identifies a Bullish Breakaway, a bullish reversal pattern.
+    ///
+    /// This five-candle pattern consists of:
+    /// 1. A long bearish candle extending the downtrend
+    /// 2. A small candle gapping down, its high below the first candle's low
+    /// 3. Two more small candles drifting lower, each closing below the previous one
+    /// 4. A long bullish candle closing inside the gap left by the second candle
+    ///
+    /// Long candles have a body of at least 60% of their range. Drifting bodies must be smaller
+    /// than half of the first body, and the last body larger. Only this fixed five-candle form
+    /// is detected: a drift of two or four candles doesn't match.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a decline losing momentum after an exhaustion gap, the sellers being unable to
+    ///   extend it further
+    /// - The last candle erases the whole drift, though the gap is not closed yet
+    /// - Often used to enter long positions, with stops below the lowest low of the drift
+    /// - More reliable when confirmed by a close above the first candle's low
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (110.0, 110.5, 104.5, 105.0, 0.0),
+    ///     (103.0, 103.5, 102.0, 102.5, 0.0),
+    ///     (102.5, 102.8, 101.2, 101.5, 0.0),
+    ///     (101.5, 101.8, 100.2, 100.5, 0.0),
+    ///     (100.6, 104.2, 100.4, 104.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_bullish_breakaway());
+    /// ```
+    pub fn is_bullish_breakaway(&self) -> bool {
+        let (Some((c, cs)), Some((first, fs)), Some((second, ss))) =
+            (self.shaped(0), self.shaped(4), self.shaped(3))
+        else {
+            return false;
+        };
+
+        fs.is_bearish()
+            && first.body_range_ratio() >= LONG_BODY_RATIO
+            && ss.is_gap_down()
+            && (1..4).all(|n| {
+                self.shaped(n)
+                    .is_some_and(|(m, _)| m.body() < first.body() * METHODS_BODY_RATIO)
+            })
+            && (1..3).all(|n| {
+                self.prev(n)
+                    .zip(self.prev(n + 1))
+                    .is_some_and(|(m, p)| m.close() < p.close())
+            })
+            && cs.is_bullish()
+            && c.body_range_ratio() >= LONG_BODY_RATIO
+            && c.body() > first.body() * METHODS_BODY_RATIO
+            && c.close() > second.high()
+            && c.close() < first.low()
+    }
+
+    /// Evaluates the given [`Pattern`] against the latest candles of the stream.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+    /// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_pattern(Pattern::BullishEngulfing));
+    /// ```
+    pub fn is_pattern(&self, pattern: Pattern) -> bool {
+        match pattern {
+            Pattern::MorningStarDoji => self.is_morning_star_doji(),
+            Pattern::BullishAbandonedBaby => self.is_bullish_abandoned_baby(),
+            Pattern::BullishTriStar => self.is_bullish_tri_star(),
+            Pattern::BearishTriStar => self.is_bearish_tri_star(),
+            Pattern::RisingThreeMethods => self.is_rising_three_methods(),
+            Pattern::FallingThreeMethods => self.is_falling_three_methods(),
+            Pattern::MatHold => self.is_mat_hold(),
+            Pattern::BullishBreakaway => self.is_bullish_breakaway(),
+            Pattern::EveningStarDoji => self.is_evening_star_doji(),
+            Pattern::MorningStar => self.is_morning_star(),
+            Pattern::EveningStar => self.is_evening_star(),
+            Pattern::ThreeWhiteSoldiers => self.is_three_white_soldiers(),
+            Pattern::ThreeBlackCrows => self.is_three_black_crows(),
+            Pattern::ThreeInsideUp => self.is_three_inside_up(),
+            Pattern::ThreeInsideDown => self.is_three_inside_down(),
+            Pattern::ThreeOutsideDown => self.is_three_outside_down(),
+            Pattern::UpsideTasukiGap => self.is_upside_tasuki_gap(),
+            Pattern::DownsideTasukiGap => self.is_downside_tasuki_gap(),
+            Pattern::UpgapSideBySideWhiteLines => self.is_upgap_side_by_side_white_lines(),
+            Pattern::DowngapSideBySideWhiteLines => self.is_downgap_side_by_side_white_lines(),
+            Pattern::StickSandwich => self.is_stick_sandwich(),
+            Pattern::BullishIslandReversal => self.is_bullish_island_reversal(),
+            Pattern::BearishIslandReversal => self.is_bearish_island_reversal(),
+            Pattern::BullishEngulfing => self.is_bullish_engulfing(),
+            Pattern::BearishEngulfing => self.is_bearish_engulfing(),
+            Pattern::DarkCloudCover => self.is_dark_cloud_cover(),
+            Pattern::PiercingLine => self.is_piercing_line(),
+            Pattern::InNeck => self.is_in_neck(),
+            Pattern::BullishMeetingLines => self.is_bullish_meeting_lines(),
+            Pattern::BearishMeetingLines => self.is_bearish_meeting_lines(),
+            Pattern::TweezerTop => self.is_tweezer_top(),
+            Pattern::TweezerBottom => self.is_tweezer_bottom(),
+            Pattern::BullishHarami => self.is_bullish_harami(),
+            Pattern::BearishHarami => self.is_bearish_harami(),
+            Pattern::BullishDojiStar => self.is_bullish_doji_star(),
+            Pattern::BearishDojiStar => self.is_bearish_doji_star(),
+            Pattern::BullishStar => self.is_bullish_star(),
+            Pattern::BearishStar => self.is_bearish_star(),
+            Pattern::BullishNearStar => self.is_bullish_near_star(),
+            Pattern::BearishNearStar => self.is_bearish_near_star(),
+            Pattern::Upthrust => self.is_upthrust(),
+            Pattern::Spring => self.is_spring(),
+            Pattern::NoDemand => self.is_no_demand(),
+            Pattern::NoSupply => self.is_no_supply(),
+        }
+    }
+
+    /// Returns the highest-priority pattern currently formed by the latest candles, if any.
+    ///
+    /// Several patterns can match on the same bar (a Morning Star Doji is also a Morning Star),
+    /// this resolves them to the single label ranked first by [`Pattern::ALL`].
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+    /// let curr =  (48.7, 51.5, 48.5, 51.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev2).push(&prev1).push(&curr);
+    /// assert!(series.is_morning_star());
+    /// assert_eq!(series.strongest(), Some(Pattern::MorningStarDoji));
+    /// ```
+    pub fn strongest(&self) -> Option<Pattern> {
+        Pattern::ALL.iter().copied().find(|p| self.is_pattern(*p))
+    }
+
+    /// Returns every pattern currently formed by the latest candles.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+    /// let curr =  (48.7, 51.5, 48.5, 51.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let detected = series.push(&prev2).push(&prev1).push(&curr).detect_all();
+    /// assert!(detected.contains(Pattern::MorningStar));
+    /// assert!(detected.contains(Pattern::MorningStarDoji));
+    /// ```
+    pub fn detect_all(&self) -> PatternSet {
+        self.detect(PatternSet::all())
+    }
+
+    /// Returns the patterns currently formed by the latest candles, evaluating only the
+    /// patterns enabled in the given mask.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern, PatternSet};
+    /// let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+    /// let curr =  (48.7, 51.5, 48.5, 51.2, 0.0);
+    /// let mask = PatternSet::new().with(Pattern::MorningStar);
+    /// let mut series = CandleStream::new();
+    /// let detected = series.push(&prev2).push(&prev1).push(&curr).detect(mask);
+    /// assert_eq!(detected, mask);
+    /// ```
+    pub fn detect(&self, mask: PatternSet) -> PatternSet {
+        mask.iter().filter(|p| self.is_pattern(*p)).collect()
+    }
+
+    /// Returns the patterns currently formed by the latest candles, without the ones made
+    /// redundant by a dominant pattern.
+    ///
+    /// Rules are applied in order, and only patterns that survived the previous rules can
+    /// suppress others. [`Suppression::DEFAULTS`] provides a sensible default table.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern, Suppression};
+    /// let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+    /// let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+    /// let curr =  (48.7, 51.5, 48.5, 51.2, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let detected = series
+    ///     .push(&prev2)
+    ///     .push(&prev1)
+    ///     .push(&curr)
+    ///     .detect_dominant(&Suppression::DEFAULTS);
+    /// assert!(!detected.contains(Pattern::MorningStar));
+    /// assert!(detected.contains(Pattern::MorningStarDoji));
+    /// ```
+    pub fn detect_dominant(&self, suppressions: &[Suppression]) -> PatternSet {
+        suppressions
+            .iter()
+            .fold(self.detect_all(), |detected, rule| {
+                match detected.contains(rule.dominant) {
+                    true => detected.difference(rule.suppressed),
+                    false => detected,
+                }
+            })
+    }
+}
+
+impl<T, const N: usize> Default for CandleStream<'_, T, N> {
+    fn default() -> Self {
+        const { assert!(N > 0, "CandleStream capacity must be greater than zero") };
+
+        Self {
+            series: [const { None }; N],
+            shapes: [Shape::default(); N],
+            idx: 0,
+            count: 0,
+            highs: MonotonicDeque::new(),
+            lows: MonotonicDeque::new(),
+        }
+    }
+}
+
+// Returns true if the body lengths of the candles match within the price match tolerance of
+// the first one, measured in prices whatever the scale of the candles
+fn bodies_match<T: CandleStick>(first: &T, candle: &T) -> bool {
+    let body = |c: &T| (c.close() - c.open()).abs();
+    let tolerance = first.price_match_ratio() * (first.high() - first.low());
+    (body(first) - body(candle)).abs() <= tolerance.max(first.tick_size())
+}
+
+// Returns true if the candle's body is small enough to be a star after the first candle
+fn is_star_body<T: CandleStick>(candle: &T, first: &T) -> bool {
+    candle.body() < first.body() * STAR_BODY_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Thresholds;
+
+    #[test]
+    fn test_nth_index() {
+        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+        let candle3 = (109.5, 112.0, 108.0, 111.0, 0.0);
+        let candle4 = (111.5, 115.0, 110.0, 114.0, 0.0);
+        let candle5 = (114.5, 118.0, 113.0, 117.0, 0.0);
+        let candle6 = (117.5, 120.0, 116.0, 119.0, 0.0);
+
+        let mut stream = CandleStream::new();
+
+        assert_eq!(stream.nth_index(6), None);
+
+        stream.push(&candle1).push(&candle2);
+
+        assert_eq!(stream.nth_index(0), Some(2));
+        assert_eq!(stream.nth_index(1), Some(1));
+        assert_eq!(stream.nth_index(2), Some(0));
+
+        stream.push(&candle3).push(&candle4).push(&candle5);
+
+        assert_eq!(stream.nth_index(0), Some(0));
+        assert_eq!(stream.nth_index(1), Some(4));
+        assert_eq!(stream.nth_index(2), Some(3));
+        assert_eq!(stream.nth_index(3), Some(2));
+        assert_eq!(stream.nth_index(4), Some(1));
+        assert_eq!(stream.nth_index(5), Some(0));
+
+        stream.push(&candle6);
+
+        assert_eq!(stream.nth_index(0), Some(1));
+        assert_eq!(stream.nth_index(1), Some(0));
+        assert_eq!(stream.nth_index(2), Some(4));
+        assert_eq!(stream.nth_index(3), Some(3));
+        assert_eq!(stream.nth_index(4), Some(2));
+        assert_eq!(stream.nth_index(5), Some(1));
+    }
+
+    #[test]
+    fn test_at() {
+        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+        let candle3 = (109.5, 112.0, 108.0, 111.0, 0.0);
+        let candle4 = (111.5, 115.0, 110.0, 114.0, 0.0);
+        let candle5 = (114.5, 118.0, 113.0, 117.0, 0.0);
+        let candle6 = (117.5, 120.0, 116.0, 119.0, 0.0);
+
+        let mut stream = CandleStream::new();
+        stream.push(&candle1).push(&candle2);
+
+        assert_eq!(stream.at(0), Some(&candle1));
+        assert_eq!(stream.at(1), Some(&candle2));
+        assert_eq!(stream.at(2), None);
+
+        stream.push(&candle3).push(&candle4).push(&candle5);
+
+        assert_eq!(stream.at(0), Some(&candle1));
         assert_eq!(stream.at(1), Some(&candle2));
         assert_eq!(stream.at(2), Some(&candle3));
         assert_eq!(stream.at(3), Some(&candle4));
@@ -665,6 +2071,32 @@ mod tests {
         assert_eq!(stream.prev(2), Some(&candle1));
     }
 
+    #[test]
+    fn test_shapes_follow_the_ring_buffer() {
+        let candles = [
+            (52.0, 52.5, 48.0, 48.5, 0.0),
+            (47.0, 47.5, 46.8, 47.0, 0.0),
+            (48.7, 51.5, 48.5, 51.2, 0.0),
+            (53.0, 54.0, 52.5, 52.6, 0.0),
+            (52.6, 53.0, 50.0, 50.5, 0.0),
+            (50.5, 51.0, 49.0, 50.9, 0.0),
+            (44.0, 45.0, 43.0, 44.0, 0.0),
+        ];
+
+        let mut stream = CandleStream::<_, 3>::with_capacity();
+        for (i, candle) in candles.iter().enumerate() {
+            stream.push(candle);
+            for bars_ago in 0..3.min(i + 1) {
+                let expected = Shape::of(
+                    &candles[i - bars_ago],
+                    i.checked_sub(bars_ago + 1).map(|j| &candles[j]),
+                );
+                assert_eq!(stream.shaped(bars_ago).map(|(_, s)| s), Some(expected));
+            }
+            assert!(stream.shaped(3).is_none());
+        }
+    }
+
     #[test]
     fn test_is_three_inside_up() {
         let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
@@ -677,373 +2109,1530 @@ mod tests {
             .push(&prev2)
             .push(&prev1)
             .push(&curr)
-            .is_three_inside_up());
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_three_inside_up_if_curr_engulfs_prev1() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let curr_engulf_prev1 = (52.0, 55.0, 51.9, 53.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr_engulf_prev1)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_curr_is_doji() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let doji = (53.4, 55.0, 52.7, 53.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&doji)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev2_not_bearish() {
+        let not_bearish_prev2 = (52.0, 54.5, 51.8, 54.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let curr = (52.9, 55.0, 52.7, 54.5, 0.0); // valid curr
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&not_bearish_prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev2_is_doji() {
+        let doji_prev2 = (53.0, 54.5, 51.8, 53.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&doji_prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev1_not_bullish() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let not_bullish_prev1 = (52.8, 53.0, 52.0, 52.2, 0.0); // open > close
+        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&not_bullish_prev1)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev1_opens_below_prev2_close() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1_open_below_prev2 = (51.9, 53.0, 51.8, 52.5, 0.0);
+        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_open_below_prev2)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev1_closes_above_prev2_open() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1_close_above_prev2 = (52.2, 55.0, 52.0, 54.5, 0.0);
+        let curr = (54.6, 56.0, 52.7, 55.0, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_close_above_prev2)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev1_engulfs_prev2() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1_engulf_prev2 = (51.5, 55.0, 51.0, 54.5, 0.0);
+        let curr = (54.6, 56.0, 53.5, 55.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_engulf_prev2)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_prev1_is_doji() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let doji_prev1 = (52.8, 53.0, 52.0, 52.8, 0.0);
+        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&doji_prev1)
+            .push(&curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_curr_is_inside_prev1() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let curr_inside_prev1 = (52.3, 53.1, 52.1, 52.7, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr_inside_prev1)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_if_curr_not_bullish() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+        let not_bullish_curr = (55.0, 55.5, 52.7, 53.0, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&not_bullish_curr)
+            .is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_up_with_insufficient_candles() {
+        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series.push(&prev2).is_three_inside_up());
+        assert!(!series.push(&prev1).is_three_inside_up());
+    }
+
+    #[test]
+    fn test_is_three_inside_down() {
+        let prev2: (f64, f64, f64, f64, f64) = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1: (f64, f64, f64, f64, f64) = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let curr: (f64, f64, f64, f64, f64) = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series: CandleStream<'_, (f64, f64, f64, f64, f64)> = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_three_inside_down_if_curr_engulfs_prev1() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let curr_engulf_prev1 = (49.8, 50.0, 47.5, 48.8, 0.0); // open > prev1.open, close < prev1.close
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr_engulf_prev1)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_curr_is_doji() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let doji = (48.5, 50.0, 47.5, 48.5, 0.0); // open == close
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&doji)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev2_not_bullish() {
+        let not_bullish_prev2 = (50.0, 50.5, 47.8, 48.0, 0.0); // bearish instead of bullish
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0); // valid curr
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&not_bullish_prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev2_is_doji() {
+        let doji_prev2 = (49.0, 50.5, 47.8, 49.0, 0.0); // open == close
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&doji_prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev1_not_bearish() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let not_bearish_prev1 = (48.5, 49.5, 48.0, 49.2, 0.0); // open < close
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&not_bearish_prev1)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev1_opens_above_prev2_close() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1_open_above_prev2 = (50.2, 50.5, 48.5, 49.5, 0.0);
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_open_above_prev2)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev1_closes_below_prev2_open() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1_close_below_prev2 = (49.5, 49.8, 47.5, 47.9, 0.0); // close < 48.0
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_close_below_prev2)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev1_engulfs_prev2() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0); // body [48.0, 50.0]
+        let prev1_engulf_prev2 = (50.5, 51.0, 47.0, 47.5, 0.0); // open > 50.0, close < 48.0
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1_engulf_prev2)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_prev1_is_doji() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let doji_prev1 = (49.0, 49.5, 48.5, 49.0, 0.0); // open == close
+        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&doji_prev1)
+            .push(&curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_curr_is_inside_prev1() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0); // body [49.0, 49.5]
+        let curr_inside_prev1 = (49.4, 49.6, 48.8, 49.1, 0.0); // close 49.1 > 49.0
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr_inside_prev1)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_if_curr_not_bearish() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+        let not_bearish_curr = (47.8, 48.5, 47.5, 48.6, 0.0); // bullish
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&not_bearish_curr)
+            .is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_inside_down_with_insufficient_candles() {
+        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
+        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series.push(&prev2).is_three_inside_down());
+        assert!(!series.push(&prev1).is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_three_outside_down() {
+        let prev2 = (99.0, 100.5, 98.5, 100.0, 0.0);
+        let prev1 = (101.5, 102.0, 97.0, 98.5, 0.0);
+        let curr = (98.4, 98.6, 96.0, 96.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_outside_down());
+        assert!(!series.is_three_inside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_outside_down_if_prev1_does_not_engulf_prev2() {
+        let prev2 = (99.0, 100.5, 98.5, 100.0, 0.0);
+        let prev1 = (99.8, 100.2, 97.0, 98.5, 0.0);
+        let curr = (98.4, 98.6, 96.0, 96.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_outside_down());
+    }
+
+    #[test]
+    fn test_is_not_three_outside_down_if_curr_closes_above_prev1_close() {
+        let prev2 = (99.0, 100.5, 98.5, 100.0, 0.0);
+        let prev1 = (101.5, 102.0, 97.0, 98.5, 0.0);
+        let curr = (99.5, 99.8, 98.0, 98.7, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_three_outside_down());
+    }
+
+    #[test]
+    fn test_is_mat_hold() {
+        let first = (100.0, 105.5, 99.5, 105.0, 0.0);
+        let pullback = [
+            (106.5, 107.5, 106.0, 107.0, 0.0),
+            (107.0, 107.2, 105.8, 106.0, 0.0),
+            (106.0, 106.5, 105.2, 105.5, 0.0),
+        ];
+        let breakout = (105.6, 109.0, 105.4, 108.5, 0.0);
+        let short = (105.6, 107.4, 105.4, 107.2, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(series.push(&breakout).is_mat_hold());
+        assert!(!series.is_rising_three_methods());
+        assert!(!series.push(&short).is_mat_hold());
+    }
+
+    #[test]
+    fn test_is_not_mat_hold_if_pullback_closes_below_first_close() {
+        let first = (100.0, 105.5, 99.5, 105.0, 0.0);
+        let pullback = [
+            (106.5, 107.5, 106.0, 107.0, 0.0),
+            (107.0, 107.2, 105.8, 106.0, 0.0),
+            (106.0, 106.5, 104.5, 104.8, 0.0),
+        ];
+        let breakout = (105.6, 109.0, 105.4, 108.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&breakout).is_mat_hold());
+    }
+
+    #[test]
+    fn test_is_not_mat_hold_if_first_body_is_short() {
+        let first = (100.0, 105.5, 96.0, 105.0, 0.0);
+        let pullback = [
+            (106.5, 107.5, 106.0, 107.0, 0.0),
+            (107.0, 107.2, 105.8, 106.0, 0.0),
+            (106.0, 106.5, 105.2, 105.5, 0.0),
+        ];
+        let breakout = (105.6, 109.0, 105.4, 108.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&breakout).is_mat_hold());
+    }
+
+    #[test]
+    fn test_is_not_mat_hold_without_gap() {
+        let first = (100.0, 105.5, 99.5, 105.0, 0.0);
+        let pullback = [
+            (105.4, 107.5, 105.3, 107.0, 0.0),
+            (107.0, 107.2, 105.8, 106.0, 0.0),
+            (106.0, 106.5, 105.2, 105.5, 0.0),
+        ];
+        let breakout = (105.6, 109.0, 105.4, 108.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&breakout).is_mat_hold());
+    }
+
+    #[test]
+    fn test_is_upside_tasuki_gap() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (104.0, 107.0, 103.5, 106.5, 0.0);
+        let curr = (105.5, 106.0, 103.1, 103.2, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_upside_tasuki_gap());
+    }
+
+    #[test]
+    fn test_is_not_upside_tasuki_gap_if_curr_fills_the_gap() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (104.0, 107.0, 103.5, 106.5, 0.0);
+        let filling = (105.5, 106.0, 102.5, 102.8, 0.0);
+        let shallow = (105.5, 106.0, 103.6, 103.8, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&filling);
+        assert!(!series.is_upside_tasuki_gap());
+
+        series.push(&prev2).push(&prev1).push(&shallow);
+        assert!(!series.is_upside_tasuki_gap());
+    }
+
+    #[test]
+    fn test_is_not_upside_tasuki_gap_without_gap() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (103.2, 107.0, 102.8, 106.5, 0.0);
+        let curr = (105.5, 106.0, 102.9, 103.0, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_upside_tasuki_gap());
+    }
+
+    #[test]
+    fn test_is_downside_tasuki_gap() {
+        let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+        let prev1 = (102.0, 102.5, 99.0, 99.5, 0.0);
+        let curr = (100.5, 102.9, 100.0, 102.8, 0.0);
+        let filling = (100.5, 103.5, 100.0, 103.2, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_downside_tasuki_gap() && !series.is_upside_tasuki_gap());
+
+        series.push(&prev2).push(&prev1).push(&filling);
+        assert!(!series.is_downside_tasuki_gap());
+    }
+
+    #[test]
+    fn test_is_not_downside_tasuki_gap_if_curr_opens_outside_prev1_body() {
+        let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+        let prev1 = (102.0, 102.5, 99.0, 99.5, 0.0);
+        let curr = (99.2, 102.9, 99.0, 102.8, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_downside_tasuki_gap());
+    }
+
+    #[test]
+    fn test_is_upgap_side_by_side_white_lines() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (104.0, 106.2, 103.6, 106.0, 0.0);
+        let curr = (104.05, 106.3, 103.8, 106.1, 0.0);
+        let larger = (104.05, 107.3, 103.8, 107.1, 0.0);
+        let into_gap = (104.05, 106.3, 102.8, 106.1, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_upgap_side_by_side_white_lines());
+
+        series.push(&prev2).push(&prev1).push(&larger);
+        assert!(!series.is_upgap_side_by_side_white_lines());
+
+        series.push(&prev2).push(&prev1).push(&into_gap);
+        assert!(!series.is_upgap_side_by_side_white_lines());
+    }
+
+    #[cfg(feature = "std")]
+    struct LogScale((f64, f64, f64, f64, f64));
+
+    #[cfg(feature = "std")]
+    impl CandleStick for LogScale {
+        fn log_scale(&self) -> bool {
+            true
+        }
+
+        fn open(&self) -> f64 {
+            self.0 .0
+        }
+
+        fn high(&self) -> f64 {
+            self.0 .1
+        }
+
+        fn low(&self) -> f64 {
+            self.0 .2
+        }
+
+        fn close(&self) -> f64 {
+            self.0 .3
+        }
+
+        fn volume(&self) -> f64 {
+            self.0 .4
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_upgap_side_by_side_white_lines_in_log_scale() {
+        let prev2 = LogScale((100.0, 103.0, 99.5, 102.5, 0.0));
+        let prev1 = LogScale((104.0, 106.2, 103.6, 106.0, 0.0));
+        let curr = LogScale((104.05, 106.3, 103.8, 106.1, 0.0));
+        let larger = LogScale((104.05, 107.3, 103.8, 107.1, 0.0));
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_upgap_side_by_side_white_lines());
+
+        series.push(&prev2).push(&prev1).push(&larger);
+        assert!(!series.is_upgap_side_by_side_white_lines());
+    }
+
+    #[test]
+    fn test_is_not_upgap_side_by_side_white_lines_if_opens_differ() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (104.0, 106.2, 103.6, 106.0, 0.0);
+        let curr = (105.0, 107.3, 104.8, 107.0, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
+
+        assert!(!series.is_upgap_side_by_side_white_lines());
+    }
+
+    #[test]
+    fn test_is_downgap_side_by_side_white_lines() {
+        let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+        let prev1 = (100.0, 102.4, 99.8, 102.0, 0.0);
+        let curr = (100.05, 102.5, 99.9, 102.1, 0.0);
+        let into_gap = (100.05, 103.2, 99.9, 102.1, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_downgap_side_by_side_white_lines());
+        assert!(!series.is_upgap_side_by_side_white_lines());
+
+        series.push(&prev2).push(&prev1).push(&into_gap);
+        assert!(!series.is_downgap_side_by_side_white_lines());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_downgap_side_by_side_white_lines_in_log_scale() {
+        let prev2 = LogScale((106.0, 106.5, 103.0, 103.5, 0.0));
+        let prev1 = LogScale((100.0, 102.4, 99.8, 102.0, 0.0));
+        let curr = LogScale((100.05, 102.5, 99.9, 102.1, 0.0));
+        let larger = LogScale((100.05, 102.9, 99.9, 102.7, 0.0));
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_downgap_side_by_side_white_lines());
+
+        series.push(&prev2).push(&prev1).push(&larger);
+        assert!(!series.is_downgap_side_by_side_white_lines());
+    }
+
+    #[test]
+    fn test_is_stick_sandwich() {
+        let prev2 = (104.0, 104.5, 99.5, 100.0, 0.0);
+        let prev1 = (100.5, 103.5, 100.2, 103.0, 0.0);
+        let curr = (103.2, 103.6, 99.8, 100.1, 0.0);
+        let lower = (103.2, 103.6, 99.2, 99.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_stick_sandwich());
+
+        series.push(&prev2).push(&prev1).push(&lower);
+        assert!(!series.is_stick_sandwich());
+    }
+
+    #[test]
+    fn test_is_bullish_island_reversal() {
+        let prev2 = (106.0, 106.5, 103.0, 103.5, 0.0);
+        let prev1 = (101.0, 102.0, 100.0, 101.5, 0.0);
+        let curr = (103.5, 104.8, 103.2, 104.5, 0.0);
+        let no_gap = (102.0, 104.8, 101.8, 104.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_bullish_island_reversal());
+        assert_eq!(series.bullish_island(), Some(1));
+        assert_eq!(series.strongest(), Some(Pattern::BullishIslandReversal));
+
+        series.push(&prev2).push(&prev1).push(&no_gap);
+        assert!(!series.is_bullish_island_reversal());
+        assert_eq!(series.bullish_island(), None);
+    }
+
+    #[test]
+    fn test_bullish_island() {
+        let before = (106.0, 106.5, 103.0, 103.5, 0.0);
+        let island = [
+            (102.0, 102.5, 100.5, 101.0, 0.0),
+            (101.0, 102.8, 100.0, 102.5, 0.0),
+            (102.5, 102.9, 101.5, 102.0, 0.0),
+        ];
+        let curr = (103.5, 104.8, 103.2, 104.5, 0.0);
+        let into_island = (103.5, 104.8, 102.7, 104.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&before);
+        for candle in &island {
+            series.push(candle);
+        }
+
+        assert_eq!(series.push(&curr).bullish_island(), Some(3));
+        assert!(!series.is_bullish_island_reversal());
+        assert_eq!(series.push(&into_island).bullish_island(), None);
+        assert_eq!(
+            CandleStream::<(f64, f64, f64, f64, f64)>::new().bullish_island(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_bearish_island_reversal() {
+        let prev2 = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let prev1 = (104.5, 106.0, 104.0, 105.0, 0.0);
+        let curr = (102.5, 103.8, 101.2, 101.5, 0.0);
+        let no_gap = (102.5, 104.2, 101.2, 101.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_bearish_island_reversal());
+        assert!(!series.is_bullish_island_reversal());
+        assert_eq!(series.bearish_island(), Some(1));
+        assert_eq!(series.strongest(), Some(Pattern::BearishIslandReversal));
+
+        series.push(&prev2).push(&prev1).push(&no_gap);
+        assert!(!series.is_bearish_island_reversal());
+        assert_eq!(series.bearish_island(), None);
+    }
+
+    #[test]
+    fn test_bearish_island() {
+        let before = (100.0, 103.0, 99.5, 102.5, 0.0);
+        let island = [
+            (104.5, 106.0, 104.0, 105.0, 0.0),
+            (105.0, 105.5, 103.6, 104.0, 0.0),
+            (104.0, 104.8, 103.4, 104.5, 0.0),
+        ];
+        let curr = (102.5, 103.2, 101.2, 101.5, 0.0);
+        let into_island = (102.5, 103.5, 101.2, 101.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&before);
+        for candle in &island {
+            series.push(candle);
+        }
+
+        assert_eq!(series.push(&curr).bearish_island(), Some(3));
+        assert_eq!(series.bullish_island(), None);
+        assert_eq!(series.push(&into_island).bearish_island(), None);
+    }
+
+    #[test]
+    fn test_stick_sandwich_tolerance() {
+        let loose = Thresholds {
+            price_match_ratio: 0.15,
+            ..Thresholds::default()
+        };
+        let prev2 = loose.apply((104.0, 104.5, 99.5, 100.0, 0.0));
+        let prev1 = loose.apply((100.5, 103.5, 100.2, 103.0, 0.0));
+        let curr = loose.apply((103.2, 103.6, 99.2, 99.5, 0.0));
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_stick_sandwich());
+    }
+
+    #[test]
+    fn test_is_bullish_breakaway() {
+        let first = (110.0, 110.5, 104.5, 105.0, 0.0);
+        let drift = [
+            (103.0, 103.5, 102.0, 102.5, 0.0),
+            (102.5, 102.8, 101.2, 101.5, 0.0),
+            (101.5, 101.8, 100.2, 100.5, 0.0),
+        ];
+        let last = (100.6, 104.2, 100.4, 104.0, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &drift {
+            series.push(candle);
+        }
+
+        assert!(!series.is_bullish_breakaway());
+        assert!(series.push(&last).is_bullish_breakaway());
+        assert_eq!(series.strongest(), Some(Pattern::BullishBreakaway));
+    }
+
+    #[test]
+    fn test_is_not_bullish_breakaway_if_gap_is_closed() {
+        let first = (110.0, 110.5, 104.5, 105.0, 0.0);
+        let drift = [
+            (103.0, 103.5, 102.0, 102.5, 0.0),
+            (102.5, 102.8, 101.2, 101.5, 0.0),
+            (101.5, 101.8, 100.2, 100.5, 0.0),
+        ];
+        let last = (100.6, 105.2, 100.4, 105.0, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &drift {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_bullish_breakaway());
+    }
+
+    #[test]
+    fn test_is_not_bullish_breakaway_without_long_candles() {
+        let drift = [
+            (103.0, 103.5, 102.0, 102.5, 0.0),
+            (102.5, 102.8, 101.2, 101.5, 0.0),
+            (101.5, 101.8, 100.2, 100.5, 0.0),
+        ];
+        let long_first = (110.0, 110.5, 104.5, 105.0, 0.0);
+        let short_first = (110.0, 113.0, 104.5, 105.0, 0.0);
+        let long_last = (100.6, 104.2, 100.4, 104.0, 0.0);
+        let short_last = (100.6, 104.2, 98.0, 104.0, 0.0);
+
+        for (first, last) in [(short_first, long_last), (long_first, short_last)] {
+            let mut series = CandleStream::new();
+            series.push(&first);
+            for candle in &drift {
+                series.push(candle);
+            }
+
+            assert!(!series.push(&last).is_bullish_breakaway());
+        }
+    }
+
+    #[test]
+    fn test_is_rising_three_methods() {
+        let first = (100.0, 106.0, 99.5, 105.5, 0.0);
+        let pullback = [
+            (105.0, 105.5, 103.5, 104.0, 0.0),
+            (104.0, 104.5, 102.5, 103.0, 0.0),
+            (103.0, 103.5, 101.5, 102.0, 0.0),
+        ];
+        let last = (102.0, 108.0, 101.8, 107.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.is_rising_three_methods());
+        assert!(series.push(&last).is_rising_three_methods());
+        assert_eq!(series.strongest(), Some(Pattern::RisingThreeMethods));
+    }
+
+    #[test]
+    fn test_is_not_rising_three_methods_if_pullback_breaks_first_range() {
+        let first = (100.0, 106.0, 99.5, 105.5, 0.0);
+        let pullback = [
+            (105.0, 105.5, 103.5, 104.0, 0.0),
+            (104.0, 104.5, 102.5, 103.0, 0.0),
+            (103.0, 103.5, 99.0, 102.0, 0.0),
+        ];
+        let last = (102.0, 108.0, 101.8, 107.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_rising_three_methods());
+    }
+
+    #[test]
+    fn test_is_not_rising_three_methods_if_last_close_below_first_high() {
+        let first = (100.0, 106.0, 99.5, 105.5, 0.0);
+        let pullback = [
+            (105.0, 105.5, 103.5, 104.0, 0.0),
+            (104.0, 104.5, 102.5, 103.0, 0.0),
+            (103.0, 103.5, 101.5, 102.0, 0.0),
+        ];
+        let last = (102.0, 106.5, 101.8, 105.8, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_rising_three_methods());
+        assert!(!CandleStream::<_, 3>::with_capacity()
+            .push(&pullback[2])
+            .push(&last)
+            .is_rising_three_methods());
+    }
+
+    #[test]
+    fn test_is_not_rising_three_methods_if_first_body_is_short() {
+        let first = (100.0, 106.0, 96.0, 105.5, 0.0);
+        let pullback = [
+            (105.0, 105.5, 103.5, 104.0, 0.0),
+            (104.0, 104.5, 102.5, 103.0, 0.0),
+            (103.0, 103.5, 101.5, 102.0, 0.0),
+        ];
+        let last = (102.0, 108.0, 101.8, 107.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_rising_three_methods());
+    }
+
+    #[test]
+    fn test_is_falling_three_methods() {
+        let first = (106.0, 106.5, 100.0, 100.5, 0.0);
+        let pullback = [
+            (101.0, 102.5, 100.5, 102.0, 0.0),
+            (102.0, 103.5, 101.5, 103.0, 0.0),
+            (103.0, 104.5, 102.5, 104.0, 0.0),
+        ];
+        let last = (104.0, 104.2, 98.0, 98.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.is_falling_three_methods());
+        assert!(series.push(&last).is_falling_three_methods());
+        assert!(!series.is_rising_three_methods());
+    }
+
+    #[test]
+    fn test_is_not_falling_three_methods_if_pullback_bodies_are_large() {
+        let first = (106.0, 106.5, 100.0, 100.5, 0.0);
+        let pullback = [
+            (101.0, 102.5, 100.5, 102.0, 0.0),
+            (100.5, 104.0, 100.2, 103.8, 0.0),
+            (103.0, 104.5, 102.5, 104.0, 0.0),
+        ];
+        let last = (104.0, 104.2, 98.0, 98.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_falling_three_methods());
+    }
+
+    #[test]
+    fn test_is_not_falling_three_methods_if_first_body_is_short() {
+        let first = (106.0, 110.0, 100.0, 100.5, 0.0);
+        let pullback = [
+            (101.0, 102.5, 100.5, 102.0, 0.0),
+            (102.0, 103.5, 101.5, 103.0, 0.0),
+            (103.0, 104.5, 102.5, 104.0, 0.0),
+        ];
+        let last = (104.0, 104.2, 98.0, 98.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first);
+        for candle in &pullback {
+            series.push(candle);
+        }
+
+        assert!(!series.push(&last).is_falling_three_methods());
+    }
+
+    #[test]
+    fn test_is_bullish_star_with_real_body() {
+        let prev = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let bullish_star = (47.2, 48.0, 46.9, 47.6, 0.0);
+        let bearish_star = (47.6, 48.0, 46.9, 47.2, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(series.push(&prev).push(&bullish_star).is_bullish_star());
+        assert!(series.push(&prev).push(&bearish_star).is_bullish_star());
+        assert!(!series.is_bullish_doji_star());
+    }
+
+    #[test]
+    fn test_is_not_bullish_star_if_body_is_large() {
+        let prev = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let large_body = (47.9, 48.0, 46.0, 46.5, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(!series.push(&prev).push(&large_body).is_bullish_star());
+        assert!(!series.is_bullish_near_star());
+    }
+
+    #[test]
+    fn test_near_stars_exclude_gapped_stars() {
+        let prev = (100.0, 106.0, 99.5, 105.5, 0.0);
+        let gapped = (106.2, 107.0, 105.8, 106.8, 0.0);
+        let near = (105.2, 106.3, 105.0, 105.7, 0.0);
+        let too_deep = (104.0, 104.8, 103.8, 104.3, 0.0);
+
+        let mut series = CandleStream::new();
+
+        series.push(&prev).push(&gapped);
+        assert!(series.is_bearish_star() && !series.is_bearish_near_star());
+
+        series.push(&prev).push(&near);
+        assert!(!series.is_bearish_star() && series.is_bearish_near_star());
+
+        series.push(&prev).push(&too_deep);
+        assert!(!series.is_bearish_star() && !series.is_bearish_near_star());
+    }
+
+    #[test]
+    fn test_is_bullish_abandoned_baby() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (47.0, 47.5, 46.8, 47.0, 0.0);
+        let curr = (48.0, 51.5, 47.8, 51.2, 0.0);
+
+        let mut series = CandleStream::new();
+
+        assert!(series
+            .push(&prev2)
+            .push(&prev1)
+            .push(&curr)
+            .is_bullish_abandoned_baby());
+        assert!(series.is_morning_star_doji());
+        assert_eq!(series.strongest(), Some(Pattern::BullishAbandonedBaby));
     }
 
     #[test]
-    fn test_is_three_inside_up_if_curr_engulfs_prev1() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let curr_engulf_prev1 = (52.0, 55.0, 51.9, 53.5, 0.0);
+    fn test_is_not_bullish_abandoned_baby_if_shadows_overlap() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (47.0, 48.1, 46.8, 47.0, 0.0);
+        let curr = (48.0, 51.5, 47.8, 51.2, 0.0);
+        let overlapping = (48.0, 51.5, 47.2, 51.2, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&curr_engulf_prev1)
-            .is_three_inside_up());
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(!series.is_bullish_abandoned_baby() && series.is_morning_star_doji());
+
+        let prev1 = (47.0, 47.5, 46.8, 47.0, 0.0);
+        series.push(&prev2).push(&prev1).push(&overlapping);
+        assert!(!series.is_bullish_abandoned_baby() && series.is_morning_star_doji());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_curr_is_doji() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let doji = (53.4, 55.0, 52.7, 53.5, 0.0);
+    fn test_is_bullish_tri_star() {
+        let prev2 = (100.0, 100.5, 99.5, 100.02, 0.0);
+        let prev1 = (99.0, 99.3, 98.7, 99.0, 0.0);
+        let curr = (99.8, 100.2, 99.5, 99.82, 0.0);
+        let bullish = (99.5, 100.8, 99.4, 100.6, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&doji)
-            .is_three_inside_up());
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_bullish_tri_star());
+
+        series.push(&prev2).push(&prev1).push(&bullish);
+        assert!(!series.is_bullish_tri_star());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev2_not_bearish() {
-        let not_bearish_prev2 = (52.0, 54.5, 51.8, 54.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let curr = (52.9, 55.0, 52.7, 54.5, 0.0); // valid curr
+    fn test_is_not_bullish_tri_star_if_middle_overlaps() {
+        let prev2 = (100.0, 100.5, 99.5, 100.02, 0.0);
+        let prev1 = (99.3, 99.6, 99.0, 99.3, 0.0);
+        let curr = (99.8, 100.2, 99.7, 99.82, 0.0);
 
         let mut series = CandleStream::new();
 
         assert!(!series
-            .push(&not_bearish_prev2)
+            .push(&prev2)
             .push(&prev1)
             .push(&curr)
-            .is_three_inside_up());
+            .is_bullish_tri_star());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev2_is_doji() {
-        let doji_prev2 = (53.0, 54.5, 51.8, 53.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+    fn test_is_bearish_tri_star() {
+        let prev2 = (100.0, 100.5, 99.5, 99.98, 0.0);
+        let prev1 = (101.0, 101.3, 100.7, 101.0, 0.0);
+        let curr = (100.2, 100.5, 99.8, 100.18, 0.0);
+        let bearish = (100.5, 100.6, 99.2, 99.4, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&doji_prev2)
-            .push(&prev1)
-            .push(&curr)
-            .is_three_inside_up());
+        series.push(&prev2).push(&prev1).push(&curr);
+        assert!(series.is_bearish_tri_star() && !series.is_bullish_tri_star());
+
+        series.push(&prev2).push(&prev1).push(&bearish);
+        assert!(!series.is_bearish_tri_star());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev1_not_bullish() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let not_bullish_prev1 = (52.8, 53.0, 52.0, 52.2, 0.0); // open > close
-        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+    fn test_is_piercing_line() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let curr = (99.0, 103.5, 98.5, 103.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&not_bullish_prev1)
-            .push(&curr)
-            .is_three_inside_up());
+        assert!(series.push(&prev).push(&curr).is_piercing_line());
+        assert!(!series.is_dark_cloud_cover());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev1_opens_below_prev2_close() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1_open_below_prev2 = (51.9, 53.0, 51.8, 52.5, 0.0);
-        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+    fn test_is_not_piercing_line_if_curr_opens_above_prev_low() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let curr = (99.8, 103.5, 99.6, 103.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1_open_below_prev2)
-            .push(&curr)
-            .is_three_inside_up());
+        assert!(!series.push(&prev).push(&curr).is_piercing_line());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev1_closes_above_prev2_open() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1_close_above_prev2 = (52.2, 55.0, 52.0, 54.5, 0.0);
-        let curr = (54.6, 56.0, 52.7, 55.0, 0.0);
+    fn test_is_not_piercing_line_if_curr_closes_below_prev_midpoint() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let curr = (99.0, 102.5, 98.5, 102.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1_close_above_prev2)
-            .push(&curr)
-            .is_three_inside_up());
+        assert!(!series.push(&prev).push(&curr).is_piercing_line());
+        assert!(!series.push(&curr).is_piercing_line());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev1_engulfs_prev2() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1_engulf_prev2 = (51.5, 55.0, 51.0, 54.5, 0.0);
-        let curr = (54.6, 56.0, 53.5, 55.5, 0.0);
+    fn test_is_not_piercing_line_if_curr_engulfs_prev() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let curr = (99.0, 105.5, 98.5, 105.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1_engulf_prev2)
-            .push(&curr)
-            .is_three_inside_up());
+        assert!(!series.push(&prev).push(&curr).is_piercing_line());
+        assert!(series.is_bullish_engulfing());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_prev1_is_doji() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let doji_prev1 = (52.8, 53.0, 52.0, 52.8, 0.0);
-        let curr = (52.9, 55.0, 52.7, 54.5, 0.0);
+    fn test_is_in_neck() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let curr = (99.0, 100.3, 98.5, 100.2, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&doji_prev1)
-            .push(&curr)
-            .is_three_inside_up());
+        assert!(series.push(&prev).push(&curr).is_in_neck());
+        assert!(!series.is_piercing_line());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_curr_is_inside_prev1() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let curr_inside_prev1 = (52.3, 53.1, 52.1, 52.7, 0.0);
+    fn test_is_not_in_neck_if_curr_closes_too_deep_or_below_prev_close() {
+        let prev = (104.5, 105.0, 99.5, 100.0, 0.0);
+        let deep = (99.0, 101.0, 98.5, 100.8, 0.0);
+        let below = (99.0, 100.0, 98.5, 99.9, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&curr_inside_prev1)
-            .is_three_inside_up());
+        assert!(!series.push(&prev).push(&deep).is_in_neck());
+        assert!(!series.push(&prev).push(&below).is_in_neck());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_if_curr_not_bullish() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
-        let not_bullish_curr = (55.0, 55.5, 52.7, 53.0, 0.0);
+    fn test_is_bullish_meeting_lines() {
+        let prev = (105.0, 105.3, 99.8, 100.0, 0.0);
+        let curr = (95.0, 100.1, 94.8, 99.9, 0.0);
+        let short = (97.0, 100.1, 94.8, 99.9, 0.0);
+        let short_of_close = (95.0, 99.7, 94.8, 99.5, 0.0);
 
         let mut series = CandleStream::new();
 
+        series.push(&prev).push(&curr);
+        assert!(series.is_bullish_meeting_lines());
+        assert_eq!(series.strongest(), Some(Pattern::BullishMeetingLines));
+
+        assert!(!series.push(&prev).push(&short).is_bullish_meeting_lines());
         assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&not_bullish_curr)
-            .is_three_inside_up());
+            .push(&prev)
+            .push(&short_of_close)
+            .is_bullish_meeting_lines());
     }
 
     #[test]
-    fn test_is_not_three_inside_up_with_insufficient_candles() {
-        let prev2 = (54.0, 54.5, 51.8, 52.0, 0.0);
-        let prev1 = (52.2, 53.0, 52.0, 52.8, 0.0);
+    fn test_is_bearish_meeting_lines() {
+        let prev = (100.0, 105.2, 99.7, 105.0, 0.0);
+        let curr = (110.0, 110.2, 104.9, 105.1, 0.0);
+        let short = (108.0, 110.2, 104.9, 105.1, 0.0);
+        let short_of_close = (110.0, 110.2, 105.4, 105.5, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series.push(&prev2).is_three_inside_up());
-        assert!(!series.push(&prev1).is_three_inside_up());
+        series.push(&prev).push(&curr);
+        assert!(series.is_bearish_meeting_lines());
+        assert!(!series.is_bullish_meeting_lines());
+        assert_eq!(series.strongest(), Some(Pattern::BearishMeetingLines));
+
+        assert!(!series.push(&prev).push(&short).is_bearish_meeting_lines());
+        assert!(!series
+            .push(&prev)
+            .push(&short_of_close)
+            .is_bearish_meeting_lines());
     }
 
     #[test]
-    fn test_is_three_inside_down() {
-        let prev2: (f64, f64, f64, f64, f64) = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1: (f64, f64, f64, f64, f64) = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let curr: (f64, f64, f64, f64, f64) = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_is_not_bearish_meeting_lines_without_gap() {
+        let prev = (100.0, 108.0, 99.7, 105.0, 0.0);
+        let curr = (107.5, 107.6, 104.9, 105.1, 0.0);
 
-        let mut series: CandleStream<'_, (f64, f64, f64, f64, f64)> = CandleStream::new();
+        let mut series = CandleStream::new();
 
-        assert!(series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&curr)
-            .is_three_inside_down());
+        assert!(!series.push(&prev).push(&curr).is_bearish_meeting_lines());
     }
 
     #[test]
-    fn test_is_three_inside_down_if_curr_engulfs_prev1() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let curr_engulf_prev1 = (49.8, 50.0, 47.5, 48.8, 0.0); // open > prev1.open, close < prev1.close
+    fn test_is_tweezer_top() {
+        let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+        let curr = (104.5, 105.1, 101.0, 101.5, 0.0);
+        let higher = (104.5, 105.5, 101.0, 101.5, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&curr_engulf_prev1)
-            .is_three_inside_down());
+        assert!(series.push(&prev).push(&curr).is_tweezer_top());
+        assert!(!series.push(&prev).push(&higher).is_tweezer_top());
+        assert!(!series.push(&curr).push(&prev).is_tweezer_top());
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_curr_is_doji() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let doji = (48.5, 50.0, 47.5, 48.5, 0.0); // open == close
+    fn test_tweezer_top_tolerance() {
+        let loose = Thresholds {
+            price_match_ratio: 0.1,
+            ..Thresholds::default()
+        };
+        let prev = loose.apply((100.0, 105.0, 99.5, 104.5, 0.0));
+        let curr = loose.apply((104.5, 105.5, 101.0, 101.5, 0.0));
 
         let mut series = CandleStream::new();
-
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&doji)
-            .is_three_inside_down());
+        assert!(series.push(&prev).push(&curr).is_tweezer_top());
+
+        // one tick of difference always matches
+        let ticked = Thresholds {
+            price_match_ratio: 0.0,
+            tick_size: 0.25,
+            ..Thresholds::default()
+        };
+        let prev = ticked.apply((100.0, 105.0, 99.5, 104.5, 0.0));
+        let curr = ticked.apply((104.5, 105.25, 101.0, 101.5, 0.0));
+        assert!(series.push(&prev).push(&curr).is_tweezer_top());
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev2_not_bullish() {
-        let not_bullish_prev2 = (50.0, 50.5, 47.8, 48.0, 0.0); // bearish instead of bullish
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0); // valid curr
+    fn test_is_tweezer_bottom() {
+        let prev = (104.5, 105.0, 100.0, 100.5, 0.0);
+        let curr = (100.5, 103.5, 99.9, 103.0, 0.0);
+        let lower = (100.5, 103.5, 99.5, 103.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&not_bullish_prev2)
-            .push(&prev1)
-            .push(&curr)
-            .is_three_inside_down());
+        assert!(series.push(&prev).push(&curr).is_tweezer_bottom());
+        assert!(!series.is_tweezer_top());
+        assert!(!series.push(&prev).push(&lower).is_tweezer_bottom());
+        assert!(!series.push(&curr).push(&prev).is_tweezer_bottom());
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev2_is_doji() {
-        let doji_prev2 = (49.0, 50.5, 47.8, 49.0, 0.0); // open == close
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_strongest() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+        let curr = (48.7, 51.5, 48.5, 51.2, 0.0);
 
         let mut series = CandleStream::new();
+        assert_eq!(series.strongest(), None);
 
-        assert!(!series
-            .push(&doji_prev2)
-            .push(&prev1)
-            .push(&curr)
-            .is_three_inside_down());
+        series.push(&prev2).push(&prev1).push(&curr);
+
+        assert!(series.is_morning_star());
+        assert!(series.is_morning_star_doji());
+        assert_eq!(series.strongest(), Some(Pattern::MorningStarDoji));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev1_not_bearish() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let not_bearish_prev1 = (48.5, 49.5, 48.0, 49.2, 0.0); // open < close
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_strongest_is_none_without_pattern() {
+        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
 
         let mut series = CandleStream::new();
 
-        assert!(!series
-            .push(&prev2)
-            .push(&not_bearish_prev1)
-            .push(&curr)
-            .is_three_inside_down());
+        assert_eq!(series.push(&candle1).push(&candle2).strongest(), None);
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev1_opens_above_prev2_close() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1_open_above_prev2 = (50.2, 50.5, 48.5, 49.5, 0.0);
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_detect_all() {
+        let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+        let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
 
         let mut series = CandleStream::new();
+        assert!(series.detect_all().is_empty());
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1_open_above_prev2)
-            .push(&curr)
-            .is_three_inside_down());
+        let detected = series.push(&prev).push(&curr).detect_all();
+
+        assert_eq!(detected, PatternSet::new().with(Pattern::BullishEngulfing));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev1_closes_below_prev2_open() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1_close_below_prev2 = (49.5, 49.8, 47.5, 47.9, 0.0); // close < 48.0
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_detect_dominant() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+        let curr = (48.7, 51.5, 48.5, 51.2, 0.0);
 
         let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
 
+        assert_eq!(series.detect_dominant(&[]), series.detect_all());
+        assert_eq!(
+            series.detect_dominant(&Suppression::DEFAULTS).first(),
+            Some(Pattern::MorningStarDoji)
+        );
         assert!(!series
-            .push(&prev2)
-            .push(&prev1_close_below_prev2)
-            .push(&curr)
-            .is_three_inside_down());
+            .detect_dominant(&Suppression::DEFAULTS)
+            .contains(Pattern::MorningStar));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev1_engulfs_prev2() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0); // body [48.0, 50.0]
-        let prev1_engulf_prev2 = (50.5, 51.0, 47.0, 47.5, 0.0); // open > 50.0, close < 48.0
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_detect_dominant_with_custom_table() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+        let curr = (48.7, 51.5, 48.5, 51.2, 0.0);
 
         let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1_engulf_prev2)
-            .push(&curr)
-            .is_three_inside_down());
+        let table = [Suppression::new(
+            Pattern::MorningStar,
+            PatternSet::new().with(Pattern::MorningStarDoji),
+        )];
+        let detected = series.detect_dominant(&table);
+
+        assert!(detected.contains(Pattern::MorningStar));
+        assert!(!detected.contains(Pattern::MorningStarDoji));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_prev1_is_doji() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let doji_prev1 = (49.0, 49.5, 48.5, 49.0, 0.0); // open == close
-        let curr = (48.8, 49.0, 47.5, 47.9, 0.0);
+    fn test_detect_with_mask() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+        let curr = (48.7, 51.5, 48.5, 51.2, 0.0);
 
         let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
+
+        assert!(series.detect(PatternSet::new()).is_empty());
+        assert_eq!(series.detect(PatternSet::all()), series.detect_all());
+        assert_eq!(
+            series.detect(PatternSet::all().without(Pattern::MorningStarDoji)),
+            series.detect_all().without(Pattern::MorningStarDoji)
+        );
+    }
 
-        assert!(!series
-            .push(&prev2)
-            .push(&doji_prev1)
-            .push(&curr)
-            .is_three_inside_down());
+    #[test]
+    fn test_highest_high_and_lowest_low() {
+        let candles = [
+            (100.0, 105.0, 99.0, 104.0, 0.0),
+            (104.0, 103.0, 97.0, 98.0, 0.0),
+            (98.0, 101.0, 96.0, 100.0, 0.0),
+            (100.0, 108.0, 99.5, 107.0, 0.0),
+            (107.0, 107.5, 101.0, 102.0, 0.0),
+            (102.0, 104.0, 100.0, 103.0, 0.0),
+        ];
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.highest_high(1), None);
+        assert_eq!(series.lowest_low(1), None);
+
+        series.push(&candles[0]).push(&candles[1]).push(&candles[2]);
+
+        assert_eq!(series.highest_high(0), None);
+        assert_eq!(series.highest_high(3), Some(105.0));
+        assert_eq!(series.highest_high(2), Some(103.0));
+        assert_eq!(series.lowest_low(3), Some(96.0));
+        assert_eq!(series.lowest_low(1), Some(96.0));
+        assert_eq!(series.highest_high(4), None);
+
+        series.push(&candles[3]).push(&candles[4]).push(&candles[5]);
+
+        assert_eq!(series.highest_high(5), Some(108.0));
+        assert_eq!(series.highest_high(2), Some(107.5));
+        assert_eq!(series.lowest_low(5), Some(96.0));
+        assert_eq!(series.lowest_low(3), Some(99.5));
+        assert_eq!(series.highest_high(6), None);
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_curr_is_inside_prev1() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0); // body [49.0, 49.5]
-        let curr_inside_prev1 = (49.4, 49.6, 48.8, 49.1, 0.0); // close 49.1 > 49.0
+    fn test_with_capacity() {
+        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+
+        let mut series = CandleStream::<_, 8>::with_capacity();
+        assert!(series.is_empty());
+
+        for _ in 0..4 {
+            series.push(&candle1).push(&candle2);
+        }
+
+        assert_eq!(series.len(), 8);
+        assert_eq!(series.prev(7), Some(&candle1));
+        assert_eq!(series.lowest_low(8), Some(99.0));
+
+        series.push(&candle2);
+
+        assert_eq!(series.len(), 8);
+        assert_eq!(series.prev(7), Some(&candle2));
+    }
+
+    #[test]
+    fn test_gap_to_prev() {
+        let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let gap_up = (106.0, 110.0, 105.5, 109.0, 0.0);
+        let gap_down = (97.0, 98.0, 95.0, 96.0, 0.0);
+        let inside = (101.0, 103.0, 100.0, 102.0, 0.0);
 
         let mut series = CandleStream::new();
+        assert_eq!(series.push(&prev).gap_to_prev(), None);
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&curr_inside_prev1)
-            .is_three_inside_down());
+        assert_eq!(series.push(&gap_up).gap_to_prev(), Some(0.5));
+        assert_eq!(series.push(&prev).push(&gap_down).gap_to_prev(), Some(-1.0));
+        assert_eq!(series.push(&prev).push(&inside).gap_to_prev(), Some(0.0));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_if_curr_not_bearish() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
-        let not_bearish_curr = (47.8, 48.5, 47.5, 48.6, 0.0); // bullish
+    fn test_overlap_with_prev() {
+        let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let overlapping = (104.0, 108.0, 103.0, 107.0, 0.0);
+        let gap_up = (106.0, 110.0, 105.5, 109.0, 0.0);
+        let inside = (101.0, 103.0, 100.0, 102.0, 0.0);
 
         let mut series = CandleStream::new();
+        assert_eq!(series.push(&prev).overlap_with_prev(), None);
+
+        assert_eq!(series.push(&overlapping).overlap_with_prev(), Some(2.0));
+        assert_eq!(
+            series.push(&prev).push(&gap_up).overlap_with_prev(),
+            Some(0.0)
+        );
+        assert_eq!(
+            series.push(&prev).push(&inside).overlap_with_prev(),
+            Some(3.0)
+        );
+    }
 
-        assert!(!series
-            .push(&prev2)
-            .push(&prev1)
-            .push(&not_bearish_curr)
-            .is_three_inside_down());
+    #[test]
+    fn test_return_from_prev() {
+        let prev = (100.0, 105.0, 99.0, 100.0, 0.0);
+        let curr = (100.0, 103.0, 97.0, 98.0, 0.0);
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.push(&prev).return_from_prev(), None);
+
+        series.push(&curr);
+
+        assert_eq!(series.return_from_prev(), Some(-0.02));
+        #[cfg(feature = "std")]
+        assert_eq!(series.log_return_from_prev(), Some((0.98f64).ln()));
     }
 
     #[test]
-    fn test_is_not_three_inside_down_with_insufficient_candles() {
-        let prev2 = (48.0, 50.5, 47.8, 50.0, 0.0);
-        let prev1 = (49.5, 49.8, 48.5, 49.0, 0.0);
+    fn test_gaps_with_tick_size() {
+        use crate::Thresholds;
+
+        let ticks = Thresholds {
+            tick_size: 0.25,
+            min_gap_ticks: 2,
+            ..Thresholds::default()
+        };
+        let prev = ticks.apply((52.0, 52.5, 48.0, 48.5, 0.0));
+        let one_tick_gap = ticks.apply((47.0, 47.75, 46.8, 47.0, 0.0));
+        let two_ticks_gap = ticks.apply((47.0, 47.5, 46.8, 47.0, 0.0));
 
         let mut series = CandleStream::new();
+        series.push(&prev).push(&one_tick_gap);
 
-        assert!(!series.push(&prev2).is_three_inside_down());
-        assert!(!series.push(&prev1).is_three_inside_down());
+        assert!(!series.is_bullish_doji_star());
+        assert_eq!(series.gap_to_prev(), Some(0.0));
+
+        series.push(&prev).push(&two_ticks_gap);
+
+        assert!(series.is_bullish_doji_star());
+        assert_eq!(series.gap_to_prev(), Some(-0.5));
     }
 }
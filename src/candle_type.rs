@@ -0,0 +1,113 @@
+/// The single best-fitting category of a candle, as returned by [`crate::CandleStick::classify`].
+///
+/// The individual `is_*` checks of [`crate::CandleStick`] overlap: every dragonfly doji is also
+/// a doji and frequently satisfies the hammer geometry as well. `CandleType` resolves those
+/// overlaps into exactly one label per candle using a fixed priority order, from the most
+/// specific formation to the most generic one:
+///
+/// 1. Dragonfly Doji, Gravestone Doji, Long-Legged Doji, Doji
+/// 2. Bullish/Bearish Marubozu
+/// 3. Hammer, Inverted Hammer
+/// 4. Spinning Top
+/// 5. Bullish, Bearish, Neutral
+///
+/// Hanging Man and Shooting Star share their geometry with Hammer and Inverted Hammer and only
+/// differ by the trend they appear in, so they are reported as [`CandleType::Hammer`] and
+/// [`CandleType::InvertedHammer`] respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandleType {
+    /// Doji with a long lower shadow and virtually no upper shadow
+    DragonflyDoji,
+    /// Doji with a long upper shadow and virtually no lower shadow
+    GravestoneDoji,
+    /// Doji with long upper and lower shadows
+    LongLeggedDoji,
+    /// Any other doji
+    Doji,
+    /// Bullish candle with virtually no shadows
+    BullishMarubozu,
+    /// Bearish candle with virtually no shadows
+    BearishMarubozu,
+    /// Small body at the top of the range with a long lower shadow
+    Hammer,
+    /// Small body at the bottom of the range with a long upper shadow
+    InvertedHammer,
+    /// Small body centered between long shadows
+    SpinningTop,
+    /// Any other candle closing above its open
+    Bullish,
+    /// Any other candle closing below its open
+    Bearish,
+    /// Candle closing exactly at its open without qualifying as a doji
+    Neutral,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CandleStick;
+
+    #[test]
+    fn test_classify_dragonfly_doji_over_hammer() {
+        let candle = (100.0, 100.5, 90.0, 100.1, 0.0);
+
+        assert!(candle.is_doji() && candle.is_hammer());
+        assert_eq!(candle.classify(), CandleType::DragonflyDoji);
+    }
+
+    #[test]
+    fn test_classify_gravestone_doji_over_inverted_hammer() {
+        let candle = (100.0, 110.0, 99.5, 100.1, 0.0);
+
+        assert!(candle.is_doji() && candle.is_inverted_hammer());
+        assert_eq!(candle.classify(), CandleType::GravestoneDoji);
+    }
+
+    #[test]
+    fn test_classify_long_legged_doji_over_spinning_top() {
+        let candle = (100.0, 110.0, 90.0, 100.2, 0.0);
+
+        assert!(candle.is_spinning_top());
+        assert_eq!(candle.classify(), CandleType::LongLeggedDoji);
+    }
+
+    #[test]
+    fn test_classify_single_formations() {
+        assert_eq!(
+            (100.0, 110.0, 99.0, 109.0, 0.0).classify(),
+            CandleType::BullishMarubozu
+        );
+        assert_eq!(
+            (110.0, 111.0, 99.0, 100.0, 0.0).classify(),
+            CandleType::BearishMarubozu
+        );
+        assert_eq!(
+            (100.0, 101.0, 95.0, 100.8, 0.0).classify(),
+            CandleType::Hammer
+        );
+        assert_eq!(
+            (100.0, 104.0, 99.8, 100.5, 0.0).classify(),
+            CandleType::InvertedHammer
+        );
+        assert_eq!(
+            (100.0, 105.0, 95.0, 101.5, 0.0).classify(),
+            CandleType::SpinningTop
+        );
+    }
+
+    #[test]
+    fn test_classify_fallbacks() {
+        assert_eq!(
+            (100.0, 106.0, 98.0, 104.0, 0.0).classify(),
+            CandleType::Bullish
+        );
+        assert_eq!(
+            (104.0, 106.0, 98.0, 100.0, 0.0).classify(),
+            CandleType::Bearish
+        );
+        assert_eq!(
+            (100.0, 100.0, 100.0, 100.0, 0.0).classify(),
+            CandleType::Neutral
+        );
+    }
+}
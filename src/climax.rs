@@ -0,0 +1,189 @@
+use crate::{CandleStick, CandleStream};
+
+// Minimum volume of a climactic candle, relative to the average volume
+const CLIMAX_VOLUME: f64 = 2.0;
+// Minimum range of a climactic candle, relative to the average range
+const CLIMAX_RANGE: f64 = 1.5;
+// Maximum close to close progress of stopping volume, relative to the average range
+const STOPPING_PROGRESS: f64 = 0.25;
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Buying Climax, a wide-range, high-volume candle making a new high at the
+    /// end of an extended advance.
+    ///
+    /// The latest candle must trade above the highs of the `n` previous candles, which must
+    /// close higher overall, with at least twice their average volume and one and a half
+    /// times their average range. Returns false if `n` is lower than two or fewer than
+    /// `n + 1` candles are available.
+    ///
+    /// **Trading Significance**:
+    /// - Late buyers rush in while professionals sell into the strength
+    /// - Often marks the exhaustion of the advance rather than its acceleration
+    /// - A reversal pattern on the following candles confirms the top
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 101.0, 99.5, 100.8, 100.0),
+    ///     (100.8, 102.0, 100.5, 101.8, 100.0),
+    ///     (101.8, 103.0, 101.5, 102.8, 100.0),
+    ///     (102.8, 106.0, 102.5, 105.0, 300.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_buying_climax(3));
+    /// ```
+    pub fn is_buying_climax(&self, n: usize) -> bool {
+        self.climax(n, |c, prev| c.high() > prev.high(), |net| net > 0.0)
+    }
+
+    /// Identifies a Selling Climax, a wide-range, high-volume candle making a new low at the
+    /// end of an extended decline.
+    ///
+    /// Mirror of [`CandleStream::is_buying_climax`].
+    ///
+    /// **Trading Significance**:
+    /// - Panicked holders capitulate while professionals absorb the supply
+    /// - Often marks the exhaustion of the decline
+    /// - Frequently followed by an automatic rally and a test of the low
+    pub fn is_selling_climax(&self, n: usize) -> bool {
+        self.climax(n, |c, prev| c.low() < prev.low(), |net| net < 0.0)
+    }
+
+    /// Identifies Stopping Volume, a high-volume candle making little progress after a
+    /// decline and closing in the upper half of its range.
+    ///
+    /// The `n` previous candles must close lower overall, and the latest candle must carry at
+    /// least twice their average volume while closing within a quarter of their average range
+    /// of the previous close. Returns false if `n` is lower than two or fewer than `n + 1`
+    /// candles are available.
+    ///
+    /// **Trading Significance**:
+    /// - Heavy selling is met by equally heavy buying, so the price stops falling
+    /// - Shows demand absorbing supply ahead of a potential reversal
+    /// - More significant near support or after a selling climax
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (103.0, 103.5, 101.8, 102.0, 100.0),
+    ///     (102.0, 102.2, 100.8, 101.0, 100.0),
+    ///     (101.0, 101.2, 99.8, 100.0, 100.0),
+    ///     (100.0, 100.4, 99.2, 100.1, 400.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_stopping_volume(3));
+    /// ```
+    pub fn is_stopping_volume(&self, n: usize) -> bool {
+        let Some((c, prev, net)) = self.latest_with_move(n) else {
+            return false;
+        };
+        let Some((volume, range)) = self.average_volume_range(1, n) else {
+            return false;
+        };
+
+        net < 0.0
+            && c.volume() >= CLIMAX_VOLUME * volume
+            && (c.close() - prev.close()).abs() <= STOPPING_PROGRESS * range
+            && c.close() - c.low() >= c.range() / 2.0
+    }
+
+    /// Returns the average volume and range of the `n` candles starting `bars_ago` candles
+    /// back, or `None` if fewer candles are available
+    pub(crate) fn average_volume_range(&self, bars_ago: usize, n: usize) -> Option<(f64, f64)> {
+        self.prev(bars_ago + n.checked_sub(1)?)?;
+
+        let (volume, range) = (bars_ago..bars_ago + n)
+            .filter_map(|i| self.prev(i))
+            .fold((0.0, 0.0), |(volume, range), c| {
+                (volume + c.volume(), range + c.range())
+            });
+        Some((volume / n as f64, range / n as f64))
+    }
+
+    // Returns the latest and previous candles with the net close change of the `n` candles
+    // before the latest one
+    pub(crate) fn latest_with_move(&self, n: usize) -> Option<(&T, &T, f64)> {
+        if n < 2 {
+            return None;
+        }
+        let (c, prev) = self.get().zip(self.prev(1))?;
+        Some((c, prev, prev.close() - self.prev(n)?.close()))
+    }
+
+    // Shared implementation of the buying and selling climaxes
+    fn climax(
+        &self,
+        n: usize,
+        beyond: impl Fn(&T, &T) -> bool,
+        trend: impl Fn(f64) -> bool,
+    ) -> bool {
+        let Some((c, _, net)) = self.latest_with_move(n) else {
+            return false;
+        };
+        let Some((volume, range)) = self.average_volume_range(1, n) else {
+            return false;
+        };
+
+        trend(net)
+            && (1..=n)
+                .filter_map(|i| self.prev(i))
+                .all(|prev| beyond(c, prev))
+            && c.volume() >= CLIMAX_VOLUME * volume
+            && c.range() >= CLIMAX_RANGE * range
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 10> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    const DECLINE: [Ohlcv; 4] = [
+        (103.0, 103.5, 101.8, 102.0, 100.0),
+        (102.0, 102.2, 100.8, 101.0, 100.0),
+        (101.0, 101.2, 99.8, 100.0, 100.0),
+        (100.0, 100.2, 96.0, 96.5, 300.0),
+    ];
+
+    #[test]
+    fn test_selling_climax() {
+        assert!(series(&DECLINE).is_selling_climax(3));
+        assert!(!series(&DECLINE).is_buying_climax(3));
+        assert!(!series(&DECLINE).is_stopping_volume(3));
+        assert!(!series(&DECLINE).is_selling_climax(1));
+        assert!(!series(&DECLINE).is_selling_climax(4));
+
+        let mut quiet = DECLINE;
+        quiet[3].4 = 150.0;
+        assert!(!series(&quiet).is_selling_climax(3));
+    }
+
+    #[test]
+    fn test_stopping_volume() {
+        let mut stopping = DECLINE;
+        stopping[3] = (100.0, 100.4, 99.2, 100.1, 400.0);
+        assert!(series(&stopping).is_stopping_volume(3));
+        assert!(!series(&stopping).is_selling_climax(3));
+
+        // closing on the low shows supply still winning
+        stopping[3] = (100.2, 100.6, 99.2, 99.8, 400.0);
+        assert!(!series(&stopping).is_stopping_volume(3));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{candle_stream::SERIES_SIZE, CandleStick, CandleStream, PatternSet};
+
+/// A series of candles readable by index, whatever its memory layout.
+///
+/// Implemented for slices of candles, and for column-major buffers through [`Columns`]. The
+/// [`ColumnScanner`] reads the prices in place, so data living in separate price columns, like
+/// dataframes or arrow record batches, is scanned without building a tuple per row.
+///
+/// The prices of an index out of bounds are unspecified, prefer [`CandleSeries::candle`] when
+/// the index is not known to be valid.
+pub trait CandleSeries {
+    /// Returns the number of candles of the series
+    fn len(&self) -> usize;
+
+    /// Returns the open price of the candle at the given index
+    fn open(&self, index: usize) -> f64;
+
+    /// Returns the high price of the candle at the given index
+    fn high(&self, index: usize) -> f64;
+
+    /// Returns the low price of the candle at the given index
+    fn low(&self, index: usize) -> f64;
+
+    /// Returns the close price of the candle at the given index
+    fn close(&self, index: usize) -> f64;
+
+    /// Returns the volume of the candle at the given index
+    fn volume(&self, index: usize) -> f64;
+
+    /// Returns true if the series has no candle
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a view of the candle at the given index, or `None` if out of bounds
+    fn candle(&self, index: usize) -> Option<ColumnCandle<'_, Self>> {
+        (index < self.len()).then_some(ColumnCandle {
+            series: self,
+            index,
+        })
+    }
+}
+
+impl<T: CandleStick> CandleSeries for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn open(&self, index: usize) -> f64 {
+        self.get(index).map_or(f64::NAN, T::open)
+    }
+
+    fn high(&self, index: usize) -> f64 {
+        self.get(index).map_or(f64::NAN, T::high)
+    }
+
+    fn low(&self, index: usize) -> f64 {
+        self.get(index).map_or(f64::NAN, T::low)
+    }
+
+    fn close(&self, index: usize) -> f64 {
+        self.get(index).map_or(f64::NAN, T::close)
+    }
+
+    fn volume(&self, index: usize) -> f64 {
+        self.get(index).map_or(f64::NAN, T::volume)
+    }
+}
+
+/// Borrowed price columns forming a [`CandleSeries`].
+///
+/// The candle at index `i` reads the element at `i * stride` of every column, so a stride
+/// above one selects every n-th row, or reads an interleaved buffer through offset slices of
+/// it. The series is as long as its shortest column.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleSeries, CandleStick, Columns};
+/// // two candles stored row after row as open, high, low, close, volume
+/// let rows = [101.0, 102.0, 99.5, 100.5, 0.0, 99.0, 103.0, 98.5, 102.5, 0.0];
+/// let series = Columns::new(&rows, &rows[1..], &rows[2..], &rows[3..], &rows[4..]).with_stride(5);
+/// assert_eq!(series.len(), 2);
+/// assert_eq!(series.candle(1).map(|c| c.ohlc()), Some((99.0, 103.0, 98.5, 102.5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Columns<'a> {
+    open: &'a [f64],
+    high: &'a [f64],
+    low: &'a [f64],
+    close: &'a [f64],
+    volume: &'a [f64],
+    stride: usize,
+}
+
+impl<'a> Columns<'a> {
+    /// Returns the series formed by the given price columns, with a stride of one
+    pub fn new(
+        open: &'a [f64],
+        high: &'a [f64],
+        low: &'a [f64],
+        close: &'a [f64],
+        volume: &'a [f64],
+    ) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            stride: 1,
+        }
+    }
+
+    /// Returns a copy of the series reading every `stride`-th element of the columns. A stride
+    /// of zero is treated as one.
+    pub fn with_stride(self, stride: usize) -> Self {
+        Self {
+            stride: stride.max(1),
+            ..self
+        }
+    }
+
+    // Reads the element of the column for the candle at the given index
+    fn at(&self, column: &[f64], index: usize) -> f64 {
+        index
+            .checked_mul(self.stride)
+            .and_then(|i| column.get(i))
+            .copied()
+            .unwrap_or(f64::NAN)
+    }
+}
+
+impl CandleSeries for Columns<'_> {
+    fn len(&self) -> usize {
+        [self.open, self.high, self.low, self.close, self.volume]
+            .iter()
+            .map(|column| column.len().div_ceil(self.stride))
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn open(&self, index: usize) -> f64 {
+        self.at(self.open, index)
+    }
+
+    fn high(&self, index: usize) -> f64 {
+        self.at(self.high, index)
+    }
+
+    fn low(&self, index: usize) -> f64 {
+        self.at(self.low, index)
+    }
+
+    fn close(&self, index: usize) -> f64 {
+        self.at(self.close, index)
+    }
+
+    fn volume(&self, index: usize) -> f64 {
+        self.at(self.volume, index)
+    }
+}
+
+/// A candle of a [`CandleSeries`], reading its prices from the series on demand.
+///
+/// Returned by [`CandleSeries::candle`]. It uses the default pattern thresholds.
+#[derive(Debug)]
+pub struct ColumnCandle<'s, S: ?Sized> {
+    series: &'s S,
+    index: usize,
+}
+
+impl<S: ?Sized> ColumnCandle<'_, S> {
+    /// Returns the index of the candle in its series
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<S: ?Sized> Clone for ColumnCandle<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized> Copy for ColumnCandle<'_, S> {}
+
+impl<S: CandleSeries + ?Sized> CandleStick for ColumnCandle<'_, S> {
+    fn open(&self) -> f64 {
+        self.series.open(self.index)
+    }
+
+    fn high(&self) -> f64 {
+        self.series.high(self.index)
+    }
+
+    fn low(&self) -> f64 {
+        self.series.low(self.index)
+    }
+
+    fn close(&self) -> f64 {
+        self.series.close(self.index)
+    }
+
+    fn volume(&self) -> f64 {
+        self.series.volume(self.index)
+    }
+}
+
+/// The `ColumnScanner` runs pattern detection over any [`CandleSeries`], bar by bar.
+///
+/// The columnar counterpart of [`crate::Scanner`], yielding the same results: the index of
+/// every bar completing at least one pattern of the mask, together with the detected
+/// [`PatternSet`].
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{ColumnScanner, Columns, Pattern};
+/// let open = [101.0, 99.0];
+/// let high = [102.0, 103.0];
+/// let low = [99.5, 98.5];
+/// let close = [100.5, 102.5];
+/// let volume = [0.0, 0.0];
+/// let series = Columns::new(&open, &high, &low, &close, &volume);
+///
+/// let (idx, detected) = ColumnScanner::new(&series).next().unwrap();
+/// assert_eq!(idx, 1);
+/// assert!(detected.contains(Pattern::BullishEngulfing));
+/// ```
+pub struct ColumnScanner<'s, S: ?Sized> {
+    series: &'s S,
+    mask: PatternSet,
+    idx: usize,
+}
+
+impl<'s, S: ?Sized> ColumnScanner<'s, S> {
+    /// Returns a scanner evaluating every pattern
+    pub fn new(series: &'s S) -> Self {
+        Self::with_mask(series, PatternSet::all())
+    }
+
+    /// Returns a scanner evaluating only the patterns enabled in the mask
+    pub fn with_mask(series: &'s S, mask: PatternSet) -> Self {
+        Self {
+            series,
+            mask,
+            idx: 0,
+        }
+    }
+}
+
+impl<S: CandleSeries + ?Sized> Iterator for ColumnScanner<'_, S> {
+    type Item = (usize, PatternSet);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.series.len() {
+            let idx = self.idx;
+            self.idx += 1;
+
+            let start = (idx + 1).saturating_sub(SERIES_SIZE);
+            let window: [Option<ColumnCandle<'_, S>>; SERIES_SIZE] = core::array::from_fn(|i| {
+                self.series.candle(start + i).filter(|c| c.index() <= idx)
+            });
+            let mut stream = CandleStream::new();
+            for candle in window.iter().flatten() {
+                stream.push(candle);
+            }
+
+            let detected = stream.detect(self.mask);
+            if !detected.is_empty() {
+                return Some((idx, detected));
+            }
+        }
+
+        None
+    }
+}
+
+impl<S: ?Sized> core::fmt::Debug for ColumnScanner<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ColumnScanner")
+            .field("mask", &self.mask)
+            .field("idx", &self.idx)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Pattern, Scanner};
+
+    #[test]
+    fn test_matches_slice_scanner() {
+        let candles: [(f64, f64, f64, f64, f64); 8] = [
+            fixtures::BULLISH_ENGULFING[0],
+            fixtures::BULLISH_ENGULFING[1],
+            fixtures::MORNING_STAR[0],
+            fixtures::MORNING_STAR[1],
+            fixtures::MORNING_STAR[2],
+            fixtures::NO_SUPPLY[0],
+            fixtures::NO_SUPPLY[1],
+            fixtures::NO_SUPPLY[2],
+        ];
+        let open = candles.map(|c| c.0);
+        let high = candles.map(|c| c.1);
+        let low = candles.map(|c| c.2);
+        let close = candles.map(|c| c.3);
+        let volume = candles.map(|c| c.4);
+        let series = Columns::new(&open, &high, &low, &close, &volume);
+
+        assert_eq!(series.len(), candles.len());
+        assert!(ColumnScanner::new(&series).count() >= 3);
+        assert!(ColumnScanner::new(&series).eq(Scanner::new(&candles)));
+        assert!(ColumnScanner::new(&candles[..]).eq(Scanner::new(&candles)));
+
+        let mask = PatternSet::new().with(Pattern::MorningStar);
+        assert!(ColumnScanner::with_mask(&series, mask).eq(Scanner::with_mask(&candles, mask)));
+    }
+
+    #[test]
+    fn test_strided_columns() {
+        let close = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let series = Columns::new(&close, &close, &close, &close, &close[1..]).with_stride(2);
+
+        assert_eq!(series.len(), 2);
+        assert_eq!(series.close(1), 3.0);
+        assert_eq!(series.volume(1), 4.0);
+        assert!(series.candle(2).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use std::vec::Vec;
+
+use crate::{scanner::WindowScan, CandleStick, PatternSet};
+
+type Ohlcv = (f64, f64, f64, f64, f64);
+
+/// A delta-encoded candle history, for caching long histories in little memory.
+///
+/// Prices are stored in ticks: the open as an offset from the previous close, and the high,
+/// low and close as offsets from the open. Volumes are stored in multiples of a volume step.
+/// All values are variable-length integers, so a minute bar usually takes 5 to 8 bytes
+/// instead of 40. Prices and volumes are rounded to the nearest tick and step on push.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CompactCandles, Pattern, PatternSet};
+/// let mut history = CompactCandles::new(0.25, 1.0);
+/// history.push(&(101.0, 102.0, 99.5, 100.5, 1200.0));
+/// history.push(&(99.0, 103.0, 98.5, 102.5, 1500.0));
+///
+/// assert_eq!(history.len(), 2);
+/// assert_eq!(history.iter().last(), Some((99.0, 103.0, 98.5, 102.5, 1500.0)));
+///
+/// let mask = PatternSet::new().with(Pattern::BullishEngulfing);
+/// assert_eq!(history.scan(mask).next(), Some((1, mask)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactCandles {
+    tick_size: f64,
+    volume_step: f64,
+    bytes: Vec<u8>,
+    len: usize,
+    close: i64,
+}
+
+impl CompactCandles {
+    /// Returns an empty history storing prices in multiples of `tick_size` and volumes in
+    /// multiples of `volume_step`
+    pub fn new(tick_size: f64, volume_step: f64) -> Self {
+        Self {
+            tick_size,
+            volume_step,
+            bytes: Vec::new(),
+            len: 0,
+            close: 0,
+        }
+    }
+
+    /// Appends a candle to the history
+    pub fn push<T: CandleStick>(&mut self, candle: &T) {
+        let ticks = |price: f64| (price / self.tick_size).round() as i64;
+        let open = ticks(candle.open());
+        let close = ticks(candle.close());
+
+        for delta in [
+            open - self.close,
+            ticks(candle.high()) - open,
+            ticks(candle.low()) - open,
+            close - open,
+        ] {
+            write_varint(&mut self.bytes, zigzag(delta));
+        }
+        let volume = (candle.volume() / self.volume_step).round().max(0.0) as u64;
+        write_varint(&mut self.bytes, volume);
+
+        self.close = close;
+        self.len += 1;
+    }
+
+    /// Returns the number of candles in the history
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the history holds no candle
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the size of the encoded history in bytes
+    pub fn encoded_len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Decodes the candles of the history, oldest first
+    pub fn iter(&self) -> CompactIter<'_> {
+        CompactIter {
+            history: self,
+            pos: 0,
+            close: 0,
+        }
+    }
+
+    /// Returns a scanner decoding the history on the fly and yielding the index of every bar
+    /// completing at least one pattern of the mask, together with the detected [`PatternSet`]
+    pub fn scan(&self, mask: PatternSet) -> CompactScanner<'_> {
+        CompactScanner {
+            candles: self.iter(),
+            mask,
+            scan: WindowScan::new(),
+        }
+    }
+}
+
+/// Iterator decoding the candles of a [`CompactCandles`] history, returned by
+/// [`CompactCandles::iter`]
+#[derive(Debug, Clone)]
+pub struct CompactIter<'a> {
+    history: &'a CompactCandles,
+    pos: usize,
+    close: i64,
+}
+
+impl CompactIter<'_> {
+    // Reads the next variable-length integer
+    fn read(&mut self) -> Option<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.history.bytes.get(self.pos)?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for CompactIter<'_> {
+    type Item = Ohlcv;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let open = self.close + unzigzag(self.read()?);
+        let high = open + unzigzag(self.read()?);
+        let low = open + unzigzag(self.read()?);
+        let close = open + unzigzag(self.read()?);
+        let volume = self.read()?;
+        self.close = close;
+
+        let price = |ticks: i64| ticks as f64 * self.history.tick_size;
+        Some((
+            price(open),
+            price(high),
+            price(low),
+            price(close),
+            volume as f64 * self.history.volume_step,
+        ))
+    }
+}
+
+/// The `CompactScanner` runs pattern detection over a [`CompactCandles`] history, decoding
+/// one candle at a time and keeping only the last few in memory.
+///
+/// Returned by [`CompactCandles::scan`].
+#[derive(Debug, Clone)]
+pub struct CompactScanner<'a> {
+    candles: CompactIter<'a>,
+    mask: PatternSet,
+    scan: WindowScan<Ohlcv>,
+}
+
+impl Iterator for CompactScanner<'_> {
+    type Item = (usize, PatternSet);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scan.next_match(self.candles.by_ref(), self.mask)
+    }
+}
+
+// Maps signed integers to unsigned ones, keeping small magnitudes small
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+// Appends an unsigned LEB128 variable-length integer
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Scanner};
+
+    #[test]
+    fn test_round_trip() {
+        let candles = [
+            (100.25, 101.0, 99.5, 100.75, 1_250.0),
+            (100.75, 100.75, 97.0, 97.5, 0.0),
+            (3_000.0, 3_010.5, 2_990.0, 3_005.25, 1e9),
+            (0.25, 0.5, 0.0, 0.25, 3.0),
+        ];
+        let mut history = CompactCandles::new(0.25, 1.0);
+        for candle in &candles {
+            history.push(candle);
+        }
+
+        assert_eq!(history.len(), candles.len());
+        assert!(history.encoded_len() < candles.len() * 40 / 2);
+        assert!(history.iter().eq(candles));
+    }
+
+    #[test]
+    fn test_matches_slice_scanner() {
+        let candles: Vec<Ohlcv> = [
+            &fixtures::BULLISH_ENGULFING[..],
+            &fixtures::MORNING_STAR,
+            &fixtures::UPTHRUST,
+        ]
+        .concat();
+        let mut history = CompactCandles::new(0.1, 1.0);
+        for candle in &candles {
+            history.push(candle);
+        }
+        let decoded: Vec<_> = history.iter().collect();
+
+        assert!(history.scan(PatternSet::all()).count() >= 3);
+        assert!(history.scan(PatternSet::all()).eq(Scanner::new(&decoded)));
+    }
+
+    #[test]
+    fn test_zigzag() {
+        for value in [0, 1, -1, 63, -64, i64::MAX, i64::MIN] {
+            assert_eq!(unzigzag(zigzag(value)), value);
+        }
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+    }
+}
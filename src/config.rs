@@ -0,0 +1,127 @@
+use std::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{
+    CandleStick, CandleStream, InRegime, Pattern, PatternSet, Rule, Thresholds, VolumeAbove,
+};
+
+/// A composite [`Rule`] described as data, for loading strategies from configuration files.
+///
+/// Serialized in snake case, externally tagged: `{ pattern = "MorningStar" }`,
+/// `{ all = [...] }` or `{ not = { ... } }` in TOML.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleConfig {
+    /// Holds when the pattern is formed by the latest candles
+    Pattern(Pattern),
+    /// Holds when the market is in the regime
+    Regime(InRegime),
+    /// Holds when the latest volume passes the filter
+    VolumeAbove(VolumeAbove),
+    /// Holds when every rule holds
+    All(Vec<RuleConfig>),
+    /// Holds when at least one rule holds
+    Any(Vec<RuleConfig>),
+    /// Holds when the rule doesn't
+    Not(Box<RuleConfig>),
+}
+
+impl<T: CandleStick, const N: usize> Rule<T, N> for RuleConfig {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        match self {
+            RuleConfig::Pattern(pattern) => pattern.holds(stream),
+            RuleConfig::Regime(regime) => regime.holds(stream),
+            RuleConfig::VolumeAbove(volume) => volume.holds(stream),
+            RuleConfig::All(rules) => rules.iter().all(|rule| rule.holds(stream)),
+            RuleConfig::Any(rules) => rules.iter().any(|rule| rule.holds(stream)),
+            RuleConfig::Not(rule) => !rule.holds(stream),
+        }
+    }
+}
+
+/// The tunable settings of a deployment, loadable from any serde format like TOML or JSON so
+/// strategies can be retuned without recompiling.
+///
+/// Every field is optional in the file: missing thresholds keep their defaults, a missing
+/// pattern list enables every pattern and a missing rule table defines no rule.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStream, Config, Pattern, Rule};
+/// let config: Config = toml::from_str(r#"
+///     patterns = ["BullishEngulfing", "MorningStar"]
+///
+///     [thresholds]
+///     doji_body_ratio = 0.05
+///
+///     [rules.entry]
+///     all = [
+///         { pattern = "BullishEngulfing" },
+///         { volume_above = { factor = 1.5, period = 1 } },
+///     ]
+/// "#).unwrap();
+///
+/// assert_eq!(config.thresholds.doji_body_ratio, 0.05);
+/// assert!(config.patterns.contains(Pattern::MorningStar));
+/// assert!(!config.patterns.contains(Pattern::EveningStar));
+///
+/// let prev = (101.0, 102.0, 99.5, 100.5, 1000.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 2000.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+/// assert!(config.rules["entry"].holds(&series));
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Thresholds of the candle detectors, see [`Thresholds::apply`]
+    pub thresholds: Thresholds,
+    /// Patterns enabled for detection, see [`CandleStream::detect`]
+    pub patterns: PatternSet,
+    /// Composite rules by name
+    pub rules: BTreeMap<String, RuleConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            thresholds: Thresholds::default(),
+            patterns: PatternSet::all(),
+            rules: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Regime, RegimeRules};
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut config = Config {
+            patterns: PatternSet::new().with(Pattern::Spring),
+            ..Config::default()
+        };
+        config.thresholds.tick_size = 0.25;
+        config.rules.insert(
+            "trend_pullback".into(),
+            RuleConfig::All(Vec::from([
+                RuleConfig::Regime(InRegime {
+                    regime: Regime::TrendingUp,
+                    rules: RegimeRules::default(),
+                }),
+                RuleConfig::Not(Box::new(RuleConfig::Pattern(Pattern::Upthrust))),
+            ])),
+        );
+
+        let json = serde_json::to_string(&config).unwrap_or_default();
+        assert!(json.contains(r#""patterns":["Spring"]"#));
+        assert_eq!(serde_json::from_str::<Config>(&json).ok(), Some(config));
+    }
+
+    #[test]
+    fn test_missing_fields_keep_defaults() {
+        assert_eq!(toml::from_str::<Config>("").ok(), Some(Config::default()));
+        assert!(serde_json::from_str::<Config>(r#"{"patterns":["Nope"]}"#).is_err());
+    }
+}
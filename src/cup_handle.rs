@@ -0,0 +1,221 @@
+use crate::{CandleStick, CandleStream, Swing};
+
+/// Shape constraints of the cup and handle pattern, used by [`CandleStream::cup_and_handle`].
+///
+/// The defaults follow the classic daily chart definition, shorter timeframes usually need
+/// shallower cups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CupRules {
+    /// Minimum cup depth, as a fraction of the lower rim
+    pub min_depth: f64,
+    /// Maximum cup depth, as a fraction of the lower rim
+    pub max_depth: f64,
+    /// Minimum number of candles between the rims
+    pub min_bars: usize,
+    /// Maximum difference between the rims, as a fraction of the left rim
+    pub rim_tolerance: f64,
+    /// Maximum handle pullback, as a fraction of the cup depth
+    pub max_handle_retracement: f64,
+}
+
+impl Default for CupRules {
+    fn default() -> Self {
+        Self {
+            min_depth: 0.12,
+            max_depth: 0.35,
+            min_bars: 7,
+            rim_tolerance: 0.05,
+            max_handle_retracement: 0.5,
+        }
+    }
+}
+
+/// A cup and handle chart pattern, as returned by [`CandleStream::cup_and_handle`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CupAndHandle {
+    /// The swing high opening the cup
+    pub left_rim: Swing,
+    /// The swing high closing the cup, where the handle starts
+    pub right_rim: Swing,
+    /// The lowest low of the cup
+    pub bottom: f64,
+    /// The lowest low of the handle
+    pub handle_low: f64,
+    /// The breakout trigger level, the high of the right rim
+    pub trigger: f64,
+    /// True if the latest candle closed above the trigger
+    pub broken_out: bool,
+}
+
+impl CupAndHandle {
+    /// Returns the measured move target: the cup depth projected above the trigger
+    pub fn target(&self) -> f64 {
+        2.0 * self.trigger - self.bottom
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Cup and Handle, a bullish continuation chart pattern over the buffered
+    /// history, from the two latest swing highs of the given `strength`.
+    ///
+    /// The cup must satisfy the depth, duration and rim constraints of the rules and have a
+    /// rounded base: at least a third of its candles reach the lowest quarter of its depth,
+    /// which rules out sharp V-shaped bottoms. The handle is the pullback following the right
+    /// rim, limited by the rules and without any candle trading above the rim before the
+    /// latest one.
+    ///
+    /// **Trading Significance**:
+    /// - The rounded base shows a gradual transfer from weak to strong hands
+    /// - The handle shakes out the last sellers before the breakout
+    /// - A close above the trigger level completes the pattern
+    pub fn cup_and_handle(&self, strength: usize, rules: CupRules) -> Option<CupAndHandle> {
+        let mut rims = self.swing_highs(strength);
+        let right_rim = rims.next()?;
+        let left_rim = rims.next()?;
+
+        let duration = left_rim.bars_ago - right_rim.bars_ago;
+        let rim_difference = (left_rim.price - right_rim.price).abs();
+        if duration < rules.min_bars || rim_difference > rules.rim_tolerance * left_rim.price {
+            return None;
+        }
+
+        let cup = || (right_rim.bars_ago + 1..left_rim.bars_ago).filter_map(|n| self.prev(n));
+        let bottom = cup().map(T::low).reduce(f64::min)?;
+        let rim = right_rim.price.min(left_rim.price);
+        let depth = (rim - bottom) / rim;
+        if depth < rules.min_depth || depth > rules.max_depth {
+            return None;
+        }
+
+        let base = bottom + (rim - bottom) / 4.0;
+        let rounded = cup().filter(|c| c.low() <= base).count() * 3 >= duration - 1;
+        if !rounded {
+            return None;
+        }
+
+        let mut handle = (1..right_rim.bars_ago).filter_map(|n| self.prev(n));
+        if handle.any(|c| c.high() > right_rim.price) {
+            return None;
+        }
+
+        let latest = self.get()?;
+        let handle_low = (0..right_rim.bars_ago)
+            .filter_map(|n| self.prev(n))
+            .map(T::low)
+            .reduce(f64::min)?;
+        if right_rim.price - handle_low > rules.max_handle_retracement * (right_rim.price - bottom)
+        {
+            return None;
+        }
+
+        Some(CupAndHandle {
+            left_rim,
+            right_rim,
+            bottom,
+            handle_low,
+            trigger: right_rim.price,
+            broken_out: latest.close() > right_rim.price,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const LEFT: [(f64, f64); 3] = [(96.0, 94.0), (98.0, 95.0), (100.0, 97.0)];
+    const HANDLE: [(f64, f64); 4] = [(99.5, 96.0), (98.0, 95.0), (97.0, 94.0), (98.0, 95.0)];
+
+    fn series<'s>(candles: &'s [Ohlcv]) -> CandleStream<'s, Ohlcv, 40> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    fn build(cup: [(f64, f64); 12], last: (f64, f64)) -> [Ohlcv; 20] {
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 20];
+        let shape = LEFT.iter().chain(cup.iter()).chain(HANDLE.iter());
+        for (slot, bar) in candles.iter_mut().zip(shape.chain([&last])) {
+            *slot = candle(*bar);
+        }
+        candles
+    }
+
+    const ROUNDED: [(f64, f64); 12] = [
+        (98.0, 94.0),
+        (95.0, 90.0),
+        (91.0, 86.0),
+        (88.0, 83.0),
+        (86.0, 81.0),
+        (85.0, 80.0),
+        (85.0, 80.5),
+        (86.0, 81.0),
+        (88.0, 83.0),
+        (91.0, 86.0),
+        (95.0, 90.0),
+        (98.0, 94.0),
+    ];
+
+    #[test]
+    fn test_cup_and_handle_breakout() {
+        let candles = build(ROUNDED, (101.0, 97.0));
+        let pattern = series(&candles).cup_and_handle(2, CupRules::default());
+
+        assert!(pattern.is_some_and(|p| p.broken_out));
+        assert!(pattern.is_some_and(|p| (p.bottom, p.handle_low, p.trigger) == (80.0, 94.0, 99.5)));
+        assert!(pattern.is_some_and(|p| p.left_rim.price == 100.0));
+        assert!(pattern.is_some_and(|p| p.target() == 119.0));
+    }
+
+    #[test]
+    fn test_cup_and_handle_pending() {
+        let candles = build(ROUNDED, (98.5, 95.5));
+        let pattern = series(&candles).cup_and_handle(2, CupRules::default());
+
+        assert!(pattern.is_some_and(|p| !p.broken_out));
+    }
+
+    #[test]
+    fn test_cup_and_handle_constraints() {
+        let sharp = [
+            (98.0, 95.0),
+            (95.0, 92.0),
+            (92.0, 89.0),
+            (89.0, 86.0),
+            (86.0, 83.0),
+            (83.0, 80.0),
+            (86.0, 83.0),
+            (89.0, 86.0),
+            (92.0, 89.0),
+            (95.0, 92.0),
+            (98.0, 95.0),
+            (99.0, 96.0),
+        ];
+        let v_shape = build(sharp, (101.0, 97.0));
+        let rounded = build(ROUNDED, (101.0, 97.0));
+        let shallow = CupRules {
+            max_depth: 0.1,
+            ..CupRules::default()
+        };
+        let tight_handle = CupRules {
+            max_handle_retracement: 0.2,
+            ..CupRules::default()
+        };
+
+        assert_eq!(
+            series(&v_shape).cup_and_handle(2, CupRules::default()),
+            None
+        );
+        assert_eq!(series(&rounded).cup_and_handle(2, shallow), None);
+        assert_eq!(series(&rounded).cup_and_handle(2, tight_handle), None);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{CandleStick, CandleStream};
+
+/// Shape constraints of the dead cat bounce, used by [`CandleStream::dead_cat_bounce`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BounceRules {
+    /// Maximum number of candles of the decline
+    pub decline_bars: usize,
+    /// Minimum decline, as a fraction of its starting high
+    pub min_drop: f64,
+    /// Minimum bounce, as a fraction of the decline
+    pub min_retracement: f64,
+    /// Maximum bounce, as a fraction of the decline, beyond which it is a genuine recovery
+    pub max_retracement: f64,
+}
+
+impl Default for BounceRules {
+    fn default() -> Self {
+        Self {
+            decline_bars: 5,
+            min_drop: 0.1,
+            min_retracement: 0.1,
+            max_retracement: 0.5,
+        }
+    }
+}
+
+/// How far a [`DeadCatBounce`] has developed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BounceStage {
+    /// The latest candle made the low of a sharp decline
+    Decline,
+    /// Prices are bouncing off the low of the decline
+    Bounce,
+    /// Prices broke below the low of the decline after the bounce
+    Resumed,
+}
+
+/// A dead cat bounce, as returned by [`CandleStream::dead_cat_bounce`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeadCatBounce {
+    /// How far the pattern has developed
+    pub stage: BounceStage,
+    /// The highest high where the decline started
+    pub start: f64,
+    /// The lowest low of the decline
+    pub bottom: f64,
+    /// The highest high of the bounce, the bottom itself during the decline
+    pub bounce_high: f64,
+    /// The bounce as a fraction of the decline
+    pub retracement: f64,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Dead Cat Bounce, a bearish continuation made of a sharp decline, a weak
+    /// partial retracement and a break below the low of the decline, reporting the stage of
+    /// the pattern as it develops.
+    ///
+    /// The decline must fall at least `min_drop` within `decline_bars` candles, and the
+    /// bounce must retrace between `min_retracement` and `max_retracement` of it. A bounce
+    /// retracing more is a recovery and ends the pattern.
+    ///
+    /// **Trading Significance**:
+    /// - Bargain hunters and short covering lift prices briefly after a crash
+    /// - The weak bounce shows sellers still in control
+    /// - The break below the low confirms the downtrend resumes
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{BounceRules, BounceStage, CandleStream};
+    /// let candles = [
+    ///     (109.0, 110.0, 108.0, 108.5, 0.0),
+    ///     (108.5, 109.0, 104.0, 104.5, 0.0),
+    ///     (104.5, 105.0, 100.0, 100.5, 0.0),
+    ///     (100.5, 101.0, 96.0, 96.5, 0.0),
+    ///     (96.5, 97.0, 92.0, 92.5, 0.0),
+    ///     (92.5, 93.0, 90.0, 90.5, 0.0),
+    ///     (90.5, 94.0, 90.2, 93.5, 0.0),
+    ///     (93.5, 96.0, 93.0, 95.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let pattern = series.dead_cat_bounce(BounceRules::default()).unwrap();
+    /// assert_eq!((pattern.stage, pattern.bottom, pattern.bounce_high), (BounceStage::Bounce, 90.0, 96.0));
+    /// assert_eq!(pattern.retracement, 0.3);
+    /// ```
+    pub fn dead_cat_bounce(&self, rules: BounceRules) -> Option<DeadCatBounce> {
+        (1..self.len())
+            .find_map(|bottom| self.bounce_from(bottom, rules))
+            .or_else(|| {
+                let (start, bottom) = self.decline_to(0, rules)?;
+                Some(DeadCatBounce {
+                    stage: BounceStage::Decline,
+                    start,
+                    bottom,
+                    bounce_high: bottom,
+                    retracement: 0.0,
+                })
+            })
+    }
+
+    // Returns the dead cat bounce off a decline bottoming at the given position, if any
+    fn bounce_from(&self, position: usize, rules: BounceRules) -> Option<DeadCatBounce> {
+        let (start, bottom) = self.decline_to(position, rules)?;
+
+        // the first candle breaking below the bottom ends the bounce
+        let breakdown = (0..position)
+            .rev()
+            .find(|&n| self.prev(n).is_some_and(|c| c.low() < bottom));
+        let bounce_high = (breakdown.map_or(0, |n| n + 1)..position)
+            .filter_map(|n| self.prev(n))
+            .map(T::high)
+            .reduce(f64::max)?;
+
+        let retracement = (bounce_high - bottom) / (start - bottom);
+        if retracement < rules.min_retracement || retracement > rules.max_retracement {
+            return None;
+        }
+
+        Some(DeadCatBounce {
+            stage: match breakdown {
+                Some(_) => BounceStage::Resumed,
+                None => BounceStage::Bounce,
+            },
+            start,
+            bottom,
+            bounce_high,
+            retracement,
+        })
+    }
+
+    // Returns the starting high and the low of a sharp decline bottoming at the given position
+    fn decline_to(&self, position: usize, rules: BounceRules) -> Option<(f64, f64)> {
+        let decline =
+            || (position + 1..=position + rules.decline_bars).filter_map(|n| self.prev(n));
+        let bottom = self.prev(position)?.low();
+        let start = decline().map(T::high).reduce(f64::max)?;
+
+        let lowest = decline().all(|c| c.low() > bottom);
+        (lowest && start - bottom >= rules.min_drop * start).then_some((start, bottom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (high - quarter, high, low, low + quarter, 0.0)
+    }
+
+    const SHAPE: [(f64, f64); 10] = [
+        (110.0, 108.0),
+        (109.0, 104.0),
+        (105.0, 100.0),
+        (101.0, 96.0),
+        (97.0, 92.0),
+        (93.0, 90.0),
+        (94.0, 91.0),
+        (96.0, 93.0),
+        (95.0, 91.0),
+        (92.0, 89.0),
+    ];
+
+    fn dead_cat(candles: &[Ohlcv]) -> Option<DeadCatBounce> {
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.dead_cat_bounce(BounceRules::default())
+    }
+
+    #[test]
+    fn test_stages() {
+        let candles = SHAPE.map(candle);
+
+        let decline = dead_cat(&candles[..6]);
+        assert!(decline.is_some_and(|p| p.stage == BounceStage::Decline));
+        assert!(decline.is_some_and(|p| (p.start, p.bottom) == (110.0, 90.0)));
+
+        let bounce = dead_cat(&candles[..8]);
+        assert!(bounce.is_some_and(|p| p.stage == BounceStage::Bounce && p.bounce_high == 96.0));
+
+        let resumed = dead_cat(&candles);
+        assert!(resumed.is_some_and(|p| p.stage == BounceStage::Resumed));
+        assert!(resumed.is_some_and(|p| (p.bottom, p.bounce_high) == (90.0, 96.0)));
+    }
+
+    #[test]
+    fn test_recovery_is_not_a_dead_cat() {
+        let mut shape = SHAPE;
+        shape[7] = (103.0, 93.0);
+        let candles = shape.map(candle);
+
+        assert_eq!(dead_cat(&candles[..8]), None);
+        assert_eq!(dead_cat(&candles[..3]), None);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{CandleStick, CandleStream, Tuned};
+
+/// The `DeltaVolume` trait extends [`CandleStick`] with the aggressor side of the volume, for
+/// order-flow oriented analysis.
+///
+/// The delta of a candle is its buy volume minus its sell volume. A reversal pattern backed by
+/// a delta in its own direction shows the aggressive participants are driving the move, which
+/// is what the `*_with_delta` detectors of [`CandleStream`] check.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStick, DeltaVolume};
+/// struct Bar { ohlc: (f64, f64, f64, f64), buys: f64, sells: f64 }
+/// impl CandleStick for Bar {
+///     fn open(&self) -> f64 { self.ohlc.0 }
+///     fn high(&self) -> f64 { self.ohlc.1 }
+///     fn low(&self) -> f64 { self.ohlc.2 }
+///     fn close(&self) -> f64 { self.ohlc.3 }
+///     fn volume(&self) -> f64 { self.buys + self.sells }
+/// }
+/// impl DeltaVolume for Bar {
+///     fn buy_volume(&self) -> f64 { self.buys }
+///     fn sell_volume(&self) -> f64 { self.sells }
+/// }
+/// let bar = Bar { ohlc: (100.0, 103.0, 99.0, 102.5), buys: 700.0, sells: 300.0 };
+/// assert_eq!(bar.delta(), 400.0);
+/// ```
+pub trait DeltaVolume: CandleStick {
+    /// Returns the volume traded by aggressive buyers
+    fn buy_volume(&self) -> f64;
+
+    /// Returns the volume traded by aggressive sellers
+    fn sell_volume(&self) -> f64;
+
+    /// Returns the buy volume minus the sell volume
+    fn delta(&self) -> f64 {
+        self.buy_volume() - self.sell_volume()
+    }
+}
+
+impl<T: DeltaVolume + ?Sized> DeltaVolume for &T {
+    fn buy_volume(&self) -> f64 {
+        (*self).buy_volume()
+    }
+
+    fn sell_volume(&self) -> f64 {
+        (*self).sell_volume()
+    }
+}
+
+impl<T: DeltaVolume> DeltaVolume for Tuned<T> {
+    fn buy_volume(&self) -> f64 {
+        self.candle.buy_volume()
+    }
+
+    fn sell_volume(&self) -> f64 {
+        self.candle.sell_volume()
+    }
+}
+
+impl<T: DeltaVolume, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the sum of the deltas of the last `n` candles, including the latest one.
+    ///
+    /// Returns `None` if `n` is zero or exceeds the number of candles in the stream.
+    pub fn cumulative_delta(&self, n: usize) -> Option<f64> {
+        if n == 0 || n > self.len() {
+            return None;
+        }
+
+        Some((0..n).filter_map(|i| self.prev(i)).map(T::delta).sum())
+    }
+
+    /// Identifies a Bullish Engulfing pattern whose engulfing candle has a positive delta.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStick, CandleStream, DeltaVolume};
+    /// # struct Bar((f64, f64, f64, f64, f64), f64);
+    /// # impl CandleStick for Bar {
+    /// #     fn open(&self) -> f64 { self.0.0 }
+    /// #     fn high(&self) -> f64 { self.0.1 }
+    /// #     fn low(&self) -> f64 { self.0.2 }
+    /// #     fn close(&self) -> f64 { self.0.3 }
+    /// #     fn volume(&self) -> f64 { self.0.4 }
+    /// # }
+    /// # impl DeltaVolume for Bar {
+    /// #     fn buy_volume(&self) -> f64 { (self.0.4 + self.1) / 2.0 }
+    /// #     fn sell_volume(&self) -> f64 { (self.0.4 - self.1) / 2.0 }
+    /// # }
+    /// // `Bar` holds the OHLCV tuple and the delta of the candle
+    /// let prev = Bar((101.0, 102.0, 99.5, 100.5, 800.0), -200.0);
+    /// let curr = Bar((99.0, 103.0, 98.5, 102.5, 1200.0), 400.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev).push(&curr).is_bullish_engulfing_with_delta());
+    /// ```
+    pub fn is_bullish_engulfing_with_delta(&self) -> bool {
+        self.is_bullish_engulfing() && self.get().is_some_and(|c| c.delta() > 0.0)
+    }
+
+    /// Identifies a Bearish Engulfing pattern whose engulfing candle has a negative delta.
+    pub fn is_bearish_engulfing_with_delta(&self) -> bool {
+        self.is_bearish_engulfing() && self.get().is_some_and(|c| c.delta() < 0.0)
+    }
+
+    /// Identifies a Morning Star pattern whose confirmation candle has a positive delta.
+    pub fn is_morning_star_with_delta(&self) -> bool {
+        self.is_morning_star() && self.get().is_some_and(|c| c.delta() > 0.0)
+    }
+
+    /// Identifies an Evening Star pattern whose confirmation candle has a negative delta.
+    pub fn is_evening_star_with_delta(&self) -> bool {
+        self.is_evening_star() && self.get().is_some_and(|c| c.delta() < 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Bar((f64, f64, f64, f64, f64), f64);
+
+    impl CandleStick for Bar {
+        fn open(&self) -> f64 {
+            self.0 .0
+        }
+
+        fn high(&self) -> f64 {
+            self.0 .1
+        }
+
+        fn low(&self) -> f64 {
+            self.0 .2
+        }
+
+        fn close(&self) -> f64 {
+            self.0 .3
+        }
+
+        fn volume(&self) -> f64 {
+            self.0 .4
+        }
+    }
+
+    impl DeltaVolume for Bar {
+        fn buy_volume(&self) -> f64 {
+            (self.0 .4 + self.1) / 2.0
+        }
+
+        fn sell_volume(&self) -> f64 {
+            (self.0 .4 - self.1) / 2.0
+        }
+    }
+
+    #[test]
+    fn test_engulfing_requires_confirming_delta() {
+        let prev = Bar((101.0, 102.0, 99.5, 100.5, 800.0), -200.0);
+        let confirmed = Bar((99.0, 103.0, 98.5, 102.5, 1200.0), 400.0);
+        let diverging = Bar((99.0, 103.0, 98.5, 102.5, 1200.0), -100.0);
+
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&diverging);
+
+        assert!(series.is_bullish_engulfing());
+        assert!(!series.is_bullish_engulfing_with_delta());
+        assert!(!series.is_bearish_engulfing_with_delta());
+
+        series.push(&prev).push(&confirmed);
+
+        assert!(series.is_bullish_engulfing_with_delta());
+    }
+
+    #[test]
+    fn test_cumulative_delta() {
+        let first = Bar((100.0, 101.0, 99.0, 100.5, 100.0), 20.0);
+        let second = Bar((100.5, 102.0, 100.0, 101.5, 100.0), -50.0);
+
+        let mut series = CandleStream::new();
+        series.push(&first).push(&second);
+
+        assert_eq!(series.cumulative_delta(1), Some(-50.0));
+        assert_eq!(series.cumulative_delta(2), Some(-30.0));
+        assert_eq!(series.cumulative_delta(0), None);
+        assert_eq!(series.cumulative_delta(3), None);
+    }
+}
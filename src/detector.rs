@@ -0,0 +1,113 @@
+use crate::{CandleStick, CandleStream, Pattern};
+
+/// A pattern found by a [`PatternDetector`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternMatch {
+    /// Name of the matched pattern
+    pub name: &'static str,
+    /// The built-in pattern, `None` for custom detectors
+    pub pattern: Option<Pattern>,
+    /// The measured move target projected from the pattern geometry, if known
+    pub target: Option<f64>,
+    /// Open time of the candle completing the pattern, for timestamped candles
+    pub timestamp: Option<i64>,
+    /// Identifier of the detection event, increasing with every match of a scan
+    pub id: Option<u64>,
+}
+
+impl PatternMatch {
+    /// Returns a match for a custom pattern
+    pub fn custom(name: &'static str) -> Self {
+        Self {
+            name,
+            pattern: None,
+            target: None,
+            timestamp: None,
+            id: None,
+        }
+    }
+
+    /// Returns a copy of the match with the given measured move target
+    pub fn with_target(self, target: f64) -> Self {
+        Self {
+            target: Some(target),
+            ..self
+        }
+    }
+
+    /// Returns a copy of the match stamped with the completion time and the event id
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::PatternMatch;
+    /// let found = PatternMatch::custom("Gap Up").with_event(1_700_000_000, 7);
+    /// assert_eq!((found.timestamp, found.id), (Some(1_700_000_000), Some(7)));
+    /// ```
+    pub fn with_event(self, timestamp: i64, id: u64) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            id: Some(id),
+            ..self
+        }
+    }
+}
+
+impl From<Pattern> for PatternMatch {
+    fn from(pattern: Pattern) -> Self {
+        Self {
+            name: pattern.name(),
+            pattern: Some(pattern),
+            target: None,
+            timestamp: None,
+            id: None,
+        }
+    }
+}
+
+/// The `PatternDetector` trait is the common interface of every pattern detector, built-in or not.
+///
+/// It is implemented by [`Pattern`] for all the built-in patterns, and by `(name, closure)`
+/// tuples for ad-hoc detectors, so both can be mixed in a single `&[&dyn PatternDetector<T>]`
+/// and handed over to [`crate::DetectorScanner`]. Third-party crates can implement it to ship
+/// their own patterns.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStick, CandleStream, Pattern, PatternDetector};
+/// type Candle = (f64, f64, f64, f64, f64);
+///
+/// let gap_up = ("Gap Up", |s: &CandleStream<'_, Candle>| {
+///     s.get().zip(s.prev(1)).is_some_and(|(c, p)| c.low() > p.high())
+/// });
+/// let detectors: [&dyn PatternDetector<Candle>; 2] = [&Pattern::BullishEngulfing, &gap_up];
+///
+/// let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+/// let curr = (106.0, 110.0, 105.5, 109.0, 0.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+///
+/// assert_eq!(detectors[0].detect(&series), None);
+/// assert_eq!(detectors[1].detect(&series).map(|m| m.name), Some("Gap Up"));
+/// ```
+pub trait PatternDetector<T> {
+    /// Returns the match if the pattern is formed by the latest candles of the stream
+    fn detect(&self, stream: &CandleStream<'_, T>) -> Option<PatternMatch>;
+}
+
+impl<T: CandleStick> PatternDetector<T> for Pattern {
+    fn detect(&self, stream: &CandleStream<'_, T>) -> Option<PatternMatch> {
+        stream.is_pattern(*self).then(|| PatternMatch {
+            target: stream.projection(*self),
+            ..(*self).into()
+        })
+    }
+}
+
+impl<T, F> PatternDetector<T> for (&'static str, F)
+where
+    F: Fn(&CandleStream<'_, T>) -> bool,
+{
+    fn detect(&self, stream: &CandleStream<'_, T>) -> Option<PatternMatch> {
+        (self.1)(stream).then(|| PatternMatch::custom(self.0))
+    }
+}
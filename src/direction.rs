@@ -0,0 +1,8 @@
+/// The direction of the move a pattern anticipates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Prices are expected to rise
+    Bullish,
+    /// Prices are expected to fall
+    Bearish,
+}
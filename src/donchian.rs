@@ -0,0 +1,152 @@
+use crate::{utils::midpoint, CandleStick, CandleStream};
+
+/// Donchian channel bands over a window of candles, as returned by [`CandleStream::donchian`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Donchian {
+    /// Highest high of the window
+    pub upper: f64,
+    /// Lowest low of the window
+    pub lower: f64,
+    /// Midpoint between the upper and lower bands
+    pub middle: f64,
+}
+
+impl Donchian {
+    /// Returns the channel width
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Returns true if the price lies within the channel bands
+    pub fn contains(&self, price: f64) -> bool {
+        self.lower <= price && price <= self.upper
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the Donchian channel of the last `n` candles, including the latest one.
+    ///
+    /// Returns `None` if fewer than `n` candles are available or `n` exceeds the capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 112.0, 99.0, 104.0, 0.0);
+    /// let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let channel = series.push(&candle1).push(&candle2).donchian(2).unwrap();
+    /// assert_eq!((channel.upper, channel.lower, channel.middle), (112.0, 99.0, 105.5));
+    /// ```
+    pub fn donchian(&self, n: usize) -> Option<Donchian> {
+        let upper = self.highest_high(n)?;
+        let lower = self.lowest_low(n)?;
+
+        Some(Donchian {
+            upper,
+            lower,
+            middle: midpoint(upper, lower),
+        })
+    }
+
+    /// Returns true if the latest candle closes above the Donchian channel of the `n`
+    /// candles preceding it, a classic breakout signal.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+    /// let candle2 = (104.0, 106.0, 102.0, 103.0, 0.0);
+    /// let candle3 = (103.0, 108.0, 102.5, 107.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&candle1).push(&candle2).push(&candle3).closes_above_channel(2));
+    /// ```
+    pub fn closes_above_channel(&self, n: usize) -> bool {
+        self.get()
+            .zip(self.preceding_channel(n))
+            .is_some_and(|(c, channel)| c.close() > channel.upper)
+    }
+
+    /// Returns true if the latest candle closes below the Donchian channel of the `n`
+    /// candles preceding it, a classic breakdown signal.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (104.0, 105.0, 99.0, 100.0, 0.0);
+    /// let candle2 = (100.0, 102.0, 99.5, 101.0, 0.0);
+    /// let candle3 = (101.0, 101.5, 97.0, 98.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&candle1).push(&candle2).push(&candle3).closes_below_channel(2));
+    /// ```
+    pub fn closes_below_channel(&self, n: usize) -> bool {
+        self.get()
+            .zip(self.preceding_channel(n))
+            .is_some_and(|(c, channel)| c.close() < channel.lower)
+    }
+
+    // Returns the Donchian channel of the n candles preceding the latest one
+    fn preceding_channel(&self, n: usize) -> Option<Donchian> {
+        if n == 0 {
+            return None;
+        }
+
+        let (upper, lower) = (1..=n).try_fold((f64::MIN, f64::MAX), |(upper, lower), i| {
+            let candle = self.prev(i)?;
+            Some((upper.max(candle.high()), lower.min(candle.low())))
+        })?;
+
+        Some(Donchian {
+            upper,
+            lower,
+            middle: midpoint(upper, lower),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_donchian() {
+        let candle1 = (100.0, 112.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.5, 110.0, 104.0, 109.0, 0.0);
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.donchian(1), None);
+
+        series.push(&candle1).push(&candle2);
+
+        let channel = series.donchian(1);
+        assert_eq!(
+            channel,
+            Some(Donchian {
+                upper: 110.0,
+                lower: 104.0,
+                middle: 107.0
+            })
+        );
+        assert_eq!(channel.map(|c| c.width()), Some(6.0));
+        assert!(channel.is_some_and(|c| c.contains(105.0)));
+        assert_eq!(series.donchian(3), None);
+    }
+
+    #[test]
+    fn test_channel_breakouts() {
+        let candle1 = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let candle2 = (104.0, 106.0, 102.0, 103.0, 0.0);
+        let inside = (103.0, 105.5, 100.0, 105.0, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&candle1).push(&candle2);
+
+        assert!(!series.closes_above_channel(0));
+        assert!(!series.closes_above_channel(2));
+
+        series.push(&inside);
+
+        assert!(!series.closes_above_channel(2));
+        assert!(!series.closes_below_channel(2));
+        assert!(!series.closes_above_channel(3));
+    }
+}
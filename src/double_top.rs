@@ -0,0 +1,202 @@
+use crate::{CandleStick, CandleStream, Swing, SwingKind};
+
+/// A double top or double bottom chart pattern, as returned by [`CandleStream::double_top`]
+/// and [`CandleStream::double_bottom`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoublePattern {
+    /// The oldest of the two peaks, or troughs
+    pub first: Swing,
+    /// The latest of the two peaks, or troughs
+    pub second: Swing,
+    /// The lowest low between the peaks, or the highest high between the troughs
+    pub neckline: f64,
+    /// True if the latest candle closed beyond the neckline
+    pub confirmed: bool,
+}
+
+impl DoublePattern {
+    /// Returns the measured move target: the height from the extreme peak, or trough, to the
+    /// neckline, projected beyond the neckline
+    pub fn target(&self) -> f64 {
+        let extreme = match self.first.kind {
+            SwingKind::High => self.first.price.max(self.second.price),
+            SwingKind::Low => self.first.price.min(self.second.price),
+        };
+        2.0 * self.neckline - extreme
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Double Top, a bearish reversal chart pattern over the buffered history.
+    ///
+    /// The two latest swing highs of the given `strength` must be equal within `tolerance`, a
+    /// fraction of the highest peak (e.g. `0.01` for 1%), and no later candle may have traded
+    /// above them. The pattern is confirmed once a candle closes below the neckline, the
+    /// lowest low between the peaks.
+    ///
+    /// **Trading Significance**:
+    /// - Shows buyers failing twice at the same resistance level
+    /// - The neckline break completes the pattern and usually triggers the entry
+    /// - Unconfirmed double tops frequently resolve as continuation ranges instead
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 102.0, 99.0, 101.0, 0.0),
+    ///     (101.0, 105.0, 100.0, 104.0, 0.0),
+    ///     (104.0, 110.0, 103.0, 108.0, 0.0),
+    ///     (108.0, 108.5, 103.0, 104.0, 0.0),
+    ///     (104.0, 105.0, 100.0, 101.0, 0.0),
+    ///     (101.0, 106.0, 100.5, 105.0, 0.0),
+    ///     (105.0, 110.2, 104.0, 109.0, 0.0),
+    ///     (109.0, 109.5, 104.0, 105.0, 0.0),
+    ///     (105.0, 106.0, 99.0, 99.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let top = series.double_top(2, 0.01).unwrap();
+    /// assert_eq!((top.first.price, top.second.price, top.neckline), (110.0, 110.2, 100.0));
+    /// assert!(top.confirmed);
+    /// assert_eq!(top.target(), 89.8);
+    /// ```
+    pub fn double_top(&self, strength: usize, tolerance: f64) -> Option<DoublePattern> {
+        self.double_pattern(SwingKind::High, strength, tolerance)
+    }
+
+    /// Identifies a Double Bottom, a bullish reversal chart pattern over the buffered history.
+    ///
+    /// Mirror of [`CandleStream::double_top`]: the two latest swing lows must be equal within
+    /// `tolerance`, and the pattern is confirmed once a candle closes above the neckline, the
+    /// highest high between the troughs.
+    pub fn double_bottom(&self, strength: usize, tolerance: f64) -> Option<DoublePattern> {
+        self.double_pattern(SwingKind::Low, strength, tolerance)
+    }
+
+    // Shared implementation of the double top and bottom
+    fn double_pattern(
+        &self,
+        kind: SwingKind,
+        strength: usize,
+        tolerance: f64,
+    ) -> Option<DoublePattern> {
+        let mut extremes = self.swings(strength).filter(|swing| swing.kind == kind);
+        let second = extremes.next()?;
+        let first = extremes.next()?;
+
+        let (outer, inner) = match kind {
+            SwingKind::High => (first.price.max(second.price), first.price.min(second.price)),
+            SwingKind::Low => (first.price.min(second.price), first.price.max(second.price)),
+        };
+        if (outer - inner).abs() > tolerance * outer.abs() {
+            return None;
+        }
+
+        let between = (second.bars_ago + 1..first.bars_ago).filter_map(|n| self.prev(n));
+        let neckline = match kind {
+            SwingKind::High => between.map(T::low).reduce(f64::min)?,
+            SwingKind::Low => between.map(T::high).reduce(f64::max)?,
+        };
+
+        let mut since = (0..second.bars_ago).filter_map(|n| self.prev(n));
+        let invalidated = match kind {
+            SwingKind::High => since.any(|c| c.high() > outer),
+            SwingKind::Low => since.any(|c| c.low() < outer),
+        };
+        if invalidated {
+            return None;
+        }
+
+        let close = self.get()?.close();
+        Some(DoublePattern {
+            first,
+            second,
+            neckline,
+            confirmed: match kind {
+                SwingKind::High => close < neckline,
+                SwingKind::Low => close > neckline,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inverted(candle: &(f64, f64, f64, f64, f64)) -> (f64, f64, f64, f64, f64) {
+        let (o, h, l, c, v) = *candle;
+        (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v)
+    }
+
+    const TOP: [(f64, f64, f64, f64, f64); 8] = [
+        (100.0, 102.0, 99.0, 101.0, 0.0),
+        (101.0, 105.0, 100.0, 104.0, 0.0),
+        (104.0, 110.0, 103.0, 108.0, 0.0),
+        (108.0, 108.5, 103.0, 104.0, 0.0),
+        (104.0, 105.0, 100.0, 101.0, 0.0),
+        (101.0, 106.0, 100.5, 105.0, 0.0),
+        (105.0, 110.2, 104.0, 109.0, 0.0),
+        (109.0, 109.5, 104.0, 105.0, 0.0),
+    ];
+
+    #[test]
+    fn test_double_top_confirmation() {
+        let unconfirmed = (105.0, 106.0, 101.0, 102.0, 0.0);
+        let breakdown = (102.0, 103.0, 98.0, 98.5, 0.0);
+
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in TOP.iter() {
+            series.push(candle);
+        }
+        series.push(&unconfirmed);
+
+        let top = series.double_top(2, 0.01);
+        assert!(top.is_some_and(|top| !top.confirmed && top.neckline == 100.0));
+        assert_eq!(series.double_top(2, 0.001), None);
+        assert_eq!(series.double_bottom(2, 0.01), None);
+
+        series.push(&breakdown);
+
+        assert!(series.double_top(2, 0.01).is_some_and(|top| top.confirmed));
+    }
+
+    #[test]
+    fn test_double_top_invalidated_by_new_high() {
+        let pullback = (105.0, 106.0, 101.0, 102.0, 0.0);
+        let breakout = (102.0, 111.0, 101.5, 110.5, 0.0);
+
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in TOP.iter() {
+            series.push(candle);
+        }
+        series.push(&pullback);
+
+        assert!(series.double_top(2, 0.01).is_some());
+
+        series.push(&breakout);
+
+        assert_eq!(series.swing_highs(2).next().map(|s| s.price), Some(110.2));
+        assert_eq!(series.double_top(2, 0.01), None);
+    }
+
+    #[test]
+    fn test_double_bottom() {
+        let bottom = TOP.map(|c| inverted(&c));
+        let breakout = inverted(&(102.0, 103.0, 98.0, 98.5, 0.0));
+        let extra = inverted(&(105.0, 106.0, 101.0, 102.0, 0.0));
+
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in bottom.iter() {
+            series.push(candle);
+        }
+        series.push(&extra).push(&breakout);
+
+        let pattern = series.double_bottom(2, 0.01);
+        assert!(pattern.is_some_and(|p| p.confirmed && p.neckline == 100.0));
+        assert!(pattern.is_some_and(|p| p.first.price == 90.0));
+        assert!(pattern.is_some_and(|p| p.target() == 110.2));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{CandleStick, CandleStream, PatternSet};
+
+/// The `EdgeTrigger` reports each pattern only on the push completing it.
+///
+/// A [`CandleStream`] evaluates its patterns against the latest candles, so querying it
+/// repeatedly between two pushes, like an alerting loop polling on a timer, keeps finding the
+/// same formation. The trigger remembers the patterns already reported for the latest candle
+/// and only returns the new ones, starting afresh once another candle is pushed.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStream, EdgeTrigger, Pattern, PatternSet};
+/// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+///
+/// let mut trigger = EdgeTrigger::new(PatternSet::all());
+/// assert!(trigger.poll(&series).contains(Pattern::BullishEngulfing));
+/// assert!(trigger.poll(&series).is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeTrigger {
+    mask: PatternSet,
+    pushed: usize,
+    reported: PatternSet,
+}
+
+impl EdgeTrigger {
+    /// Returns a trigger reporting the patterns enabled in the mask
+    pub const fn new(mask: PatternSet) -> Self {
+        Self {
+            mask,
+            pushed: 0,
+            reported: PatternSet::new(),
+        }
+    }
+
+    /// Returns the patterns of the mask formed by the latest candles of the stream that were
+    /// not reported yet for the latest candle
+    pub fn poll<T: CandleStick, const N: usize>(
+        &mut self,
+        stream: &CandleStream<'_, T, N>,
+    ) -> PatternSet {
+        if stream.pushed() != self.pushed {
+            self.pushed = stream.pushed();
+            self.reported = PatternSet::new();
+        }
+
+        let detected = stream.detect(self.mask).difference(self.reported);
+        self.reported = self.reported.union(detected);
+        detected
+    }
+
+    /// Forgets the patterns reported so far, so they are reported again on the next poll
+    pub fn reset(&mut self) {
+        self.reported = PatternSet::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+
+    #[test]
+    fn test_reports_each_formation_once() {
+        let candles = [
+            (54.0, 54.5, 51.8, 52.0, 0.0),
+            (52.2, 53.0, 52.0, 52.8, 0.0),
+            (52.9, 55.0, 52.7, 54.5, 0.0),
+            (54.6, 56.8, 54.5, 56.5, 0.0),
+            (56.6, 58.8, 56.5, 58.5, 0.0),
+        ];
+        let soldiers = PatternSet::new().with(Pattern::ThreeWhiteSoldiers);
+        let mut trigger = EdgeTrigger::new(soldiers);
+        let mut series = CandleStream::new();
+
+        let polls: [PatternSet; 5] = core::array::from_fn(|i| {
+            series.push(&candles[i]);
+            let first = trigger.poll(&series);
+            assert!(trigger.poll(&series).is_empty());
+            first
+        });
+
+        // the soldiers complete on the fourth candle, and a new formation on the fifth
+        assert_eq!(
+            polls,
+            [
+                PatternSet::new(),
+                PatternSet::new(),
+                PatternSet::new(),
+                soldiers,
+                soldiers
+            ]
+        );
+
+        trigger.reset();
+        assert_eq!(trigger.poll(&series), soldiers);
+    }
+}
@@ -0,0 +1,928 @@
+use core::fmt;
+
+use crate::{
+    candle_stream::{LONG_BODY_RATIO, METHODS_BODY_RATIO, NEAR_STAR_OVERLAP, STAR_BODY_RATIO},
+    utils::midpoint,
+    vsa::{close_position, VSA_CONTEXT},
+    CandleStick, CandleStream, Pattern,
+};
+
+// Maximum number of conditions of a pattern definition
+const MAX_CONDITIONS: usize = 12;
+
+/// How the value of a [`Condition`] must compare to its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// Strictly lower than the threshold
+    Below,
+    /// Lower than or equal to the threshold
+    AtMost,
+    /// Strictly greater than the threshold
+    Above,
+    /// Greater than or equal to the threshold
+    AtLeast,
+}
+
+impl Comparison {
+    /// Returns the word describing the comparison
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Comparison::Below => "below",
+            Comparison::AtMost => "at most",
+            Comparison::Above => "above",
+            Comparison::AtLeast => "at least",
+        }
+    }
+}
+
+/// A single condition of a pattern definition, comparing a value measured on the candles to
+/// a threshold.
+///
+/// Displays as a sentence like `close 101.2 below first body midpoint 101.5`, negated when the
+/// condition doesn't hold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Condition {
+    /// What is measured, like `close` or `star body to range`
+    pub subject: &'static str,
+    /// The measured value
+    pub value: f64,
+    /// How the value must compare to the threshold
+    pub comparison: Comparison,
+    /// What the value is compared to, like `first body midpoint`
+    pub reference: &'static str,
+    /// The threshold value
+    pub threshold: f64,
+    /// The typical magnitude of the value, like the average range for prices, which the
+    /// shortfall is measured in
+    pub scale: f64,
+}
+
+impl Condition {
+    /// Returns a condition comparing `value` to `threshold`
+    pub const fn new(
+        subject: &'static str,
+        value: f64,
+        comparison: Comparison,
+        reference: &'static str,
+        threshold: f64,
+    ) -> Self {
+        Self {
+            subject,
+            value,
+            comparison,
+            reference,
+            threshold,
+            scale: 1.0,
+        }
+    }
+
+    /// Returns a copy of the condition measuring its shortfall in multiples of `scale`
+    pub const fn with_scale(self, scale: f64) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Returns true if the value compares to the threshold as required
+    pub fn holds(&self) -> bool {
+        match self.comparison {
+            Comparison::Below => self.value < self.threshold,
+            Comparison::AtMost => self.value <= self.threshold,
+            Comparison::Above => self.value > self.threshold,
+            Comparison::AtLeast => self.value >= self.threshold,
+        }
+    }
+
+    /// Returns how far the value is from satisfying the condition, in multiples of its
+    /// scale, `0.0` if the condition holds
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Comparison, Condition};
+    /// let condition = Condition::new("close", 101.5, Comparison::Below, "midpoint", 101.0);
+    /// assert_eq!(condition.with_scale(2.0).shortfall(), 0.25);
+    /// ```
+    pub fn shortfall(&self) -> f64 {
+        if self.holds() {
+            return 0.0;
+        }
+
+        // a value exactly at a strict threshold still misses it
+        let gap = (self.value - self.threshold).abs().max(f64::EPSILON);
+        match self.scale > 0.0 {
+            true => gap / self.scale,
+            false => gap,
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let not = if self.holds() { "" } else { "not " };
+        write!(
+            f,
+            "{} {} {}{} {} {}",
+            self.subject,
+            self.value,
+            not,
+            self.comparison.name(),
+            self.reference,
+            self.threshold
+        )
+    }
+}
+
+/// The reason why a pattern is not formed by the latest candles, as returned by
+/// [`CandleStream::explain`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mismatch {
+    /// The stream holds fewer candles than the pattern needs
+    MissingCandles {
+        /// Number of candles forming the pattern
+        needed: usize,
+        /// Number of candles held by the stream
+        available: usize,
+    },
+    /// The first condition of the pattern definition that doesn't hold
+    Failed(Condition),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::MissingCandles { needed, available } => {
+                write!(f, "{needed} candles needed, {available} available")
+            }
+            Mismatch::Failed(condition) => condition.fmt(f),
+        }
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Explains why the given [`Pattern`] is not formed by the latest candles, returning the
+    /// first condition of its definition that doesn't hold, or `None` if the pattern matches.
+    ///
+    /// The conditions follow the default definitions: the doji checks are reported by their
+    /// body to range ratio, so candles overriding [`CandleStick::is_doji`] may be explained
+    /// differently than they are detected.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Mismatch, Pattern};
+    /// let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+    /// let curr = (105.5, 106.0, 102.0, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev).push(&curr);
+    ///
+    /// let Some(Mismatch::Failed(condition)) = series.explain(Pattern::DarkCloudCover) else {
+    ///     panic!("the close is above the midpoint");
+    /// };
+    /// assert_eq!(condition.to_string(), "close 102.5 not below first body midpoint 102.25");
+    /// ```
+    pub fn explain(&self, pattern: Pattern) -> Option<Mismatch> {
+        match self.conditions(pattern) {
+            Ok(conditions) => conditions
+                .into_iter()
+                .flatten()
+                .find(|c| !c.holds())
+                .map(Mismatch::Failed),
+            Err(missing) => Some(missing),
+        }
+    }
+
+    /// Returns how far the latest candles are from forming the given [`Pattern`], `0.0` when
+    /// they form it.
+    ///
+    /// The distance sums the shortfalls of the conditions that don't hold: prices in
+    /// multiples of the average range of the pattern candles, volumes relative to their
+    /// threshold, and ratios as they are. Returns infinity if the stream holds fewer candles
+    /// than the pattern needs. Ranking the patterns by distance gives an "almost formed"
+    /// watchlist, alerting before the confirming candle closes.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+    /// let curr = (105.5, 106.0, 102.0, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev).push(&curr);
+    ///
+    /// // the close misses the midpoint by 0.25, a twentieth of the average range
+    /// assert!((series.distance_to(Pattern::DarkCloudCover) - 0.25 / 4.75).abs() < 1e-12);
+    /// assert_eq!(series.distance_to(Pattern::MorningStar), f64::INFINITY);
+    /// ```
+    pub fn distance_to(&self, pattern: Pattern) -> f64 {
+        match self.conditions(pattern) {
+            Ok(conditions) => conditions.iter().flatten().map(Condition::shortfall).sum(),
+            Err(_) => f64::INFINITY,
+        }
+    }
+
+    // Returns the conditions defining the pattern, evaluated on the latest candles
+    pub(crate) fn conditions(
+        &self,
+        pattern: Pattern,
+    ) -> Result<[Option<Condition>; MAX_CONDITIONS], Mismatch> {
+        use Comparison::*;
+
+        let needed = pattern.candle_count();
+        let missing = Mismatch::MissingCandles {
+            needed,
+            available: self.len(),
+        };
+        let (Some(c), Some(p)) = (self.get(), self.prev(1)) else {
+            return Err(missing);
+        };
+        if self.len() < needed {
+            return Err(missing);
+        }
+        let [p2, p3, p4] = [2, 3, 4].map(|n| self.prev(n).unwrap_or(p));
+        let candles = needed.min(self.len());
+        let scale = (0..candles)
+            .filter_map(|i| self.prev(i))
+            .map(|c| c.range())
+            .sum::<f64>()
+            / candles as f64;
+        // prices are compared in multiples of the average range of the pattern candles
+        let price = |subject, value, comparison, reference, threshold| {
+            Condition::new(subject, value, comparison, reference, threshold).with_scale(scale)
+        };
+
+        // volumes are compared in multiples of their threshold
+        let volume = |comparison, reference, threshold: f64| {
+            Condition::new("volume", c.volume(), comparison, reference, threshold)
+                .with_scale(threshold)
+        };
+
+        let bullish =
+            |subject, candle: &T| price(subject, candle.close(), Above, "open", candle.open());
+        let bearish =
+            |subject, candle: &T| price(subject, candle.close(), Below, "open", candle.open());
+        let doji = |subject, candle: &T| {
+            Condition::new(
+                subject,
+                candle.body_range_ratio(),
+                Below,
+                "doji ratio",
+                candle.doji_body_ratio(),
+            )
+        };
+        let long = |subject, candle: &T| {
+            Condition::new(
+                subject,
+                candle.body_range_ratio(),
+                AtLeast,
+                "long body ratio",
+                LONG_BODY_RATIO,
+            )
+        };
+        // the star is either a doji or a rising candle, reported as a doji unless rising
+        let star = |candle: &T| {
+            let doji = doji("star body to range", candle);
+            let rising = bullish("star close", candle);
+            match doji.holds() || !rising.holds() {
+                true => doji,
+                false => rising,
+            }
+        };
+        // a gap must be positive and at least the minimum gap size of the gapping candle, the
+        // one it is evaluated on when pushed, the references name the bound without and with
+        // the minimum gap
+        let min_gap = |candle: &T| candle.min_gap_ticks() as f64 * candle.tick_size();
+        let gap_down =
+            |subject, candle: &T, high: f64, low: f64, (reference, less_gap)| match min_gap(candle)
+            {
+                gap if gap > 0.0 => price(subject, high, AtMost, less_gap, low - gap),
+                _ => price(subject, high, Below, reference, low),
+            };
+        let gap_up =
+            |subject, candle: &T, low: f64, high: f64, (reference, plus_gap)| match min_gap(candle)
+            {
+                gap if gap > 0.0 => price(subject, low, AtLeast, plus_gap, high + gap),
+                _ => price(subject, low, Above, reference, high),
+            };
+        let no_gap_down =
+            |subject, candle: &T, high: f64, low: f64, (reference, less_gap)| match min_gap(candle)
+            {
+                gap if gap > 0.0 => price(subject, high, Above, less_gap, low - gap),
+                _ => price(subject, high, AtLeast, reference, low),
+            };
+        let no_gap_up =
+            |subject, candle: &T, low: f64, high: f64, (reference, plus_gap)| match min_gap(candle)
+            {
+                gap if gap > 0.0 => price(subject, low, Below, plus_gap, high + gap),
+                _ => price(subject, low, AtMost, reference, high),
+            };
+        let first_low = ("first low", "first low less the minimum gap");
+        let first_high = ("first high", "first high plus the minimum gap");
+        let star_high = ("star high", "star high plus the minimum gap");
+        let star_low = ("star low", "star low less the minimum gap");
+        let island_high = ("island high", "island high plus the minimum gap");
+        let island_low = ("island low", "island low less the minimum gap");
+        let below_close = ("first close", "first close less the minimum gap");
+        let above_close = ("first close", "first close plus the minimum gap");
+        let star_body = price(
+            "star body",
+            c.body(),
+            Below,
+            "first body fraction",
+            p.body() * STAR_BODY_RATIO,
+        );
+        let (top, bottom) = (c.open().max(c.close()), c.open().min(c.close()));
+        // matching prices differ by at most the price match tolerance of the first candle
+        let matching = |subject, first: &T, lhs: f64, rhs: f64| {
+            let tolerance =
+                (first.price_match_ratio() * (first.high() - first.low())).max(first.tick_size());
+            price(
+                subject,
+                (lhs - rhs).abs(),
+                AtMost,
+                "match tolerance",
+                tolerance,
+            )
+        };
+        let first_mid = midpoint(p2.open(), p2.close());
+        let prev_mid = midpoint(p.open(), p.close());
+        // the corrective candles of the three methods, between the first and the latest one
+        let methods = [p3, p2, p];
+        let methods_high = methods.iter().map(|m| m.high()).fold(f64::MIN, f64::max);
+        let methods_low = methods.iter().map(|m| m.low()).fold(f64::MAX, f64::min);
+        let methods_body = methods.iter().map(|m| m.body()).fold(0.0, f64::max);
+        let methods_bottom = methods
+            .iter()
+            .map(|m| m.open().min(m.close()))
+            .fold(f64::MAX, f64::min);
+
+        Ok(match pattern {
+            Pattern::BullishDojiStar => list([
+                bearish("first close", p),
+                doji("star body to range", c),
+                gap_down("star high", c, c.high(), p.low(), first_low),
+            ]),
+            Pattern::BearishDojiStar => list([
+                bullish("first close", p),
+                doji("star body to range", c),
+                gap_up("star low", c, c.low(), p.high(), first_high),
+            ]),
+            Pattern::BullishStar => list([
+                bearish("first close", p),
+                star_body,
+                gap_down("star body top", c, top, p.close(), below_close),
+            ]),
+            Pattern::BearishStar => list([
+                bullish("first close", p),
+                star_body,
+                gap_up("star body bottom", c, bottom, p.close(), above_close),
+            ]),
+            Pattern::BullishNearStar => list([
+                bearish("first close", p),
+                star_body,
+                no_gap_down("star body top", c, top, p.close(), below_close),
+                price(
+                    "star body top",
+                    top,
+                    AtMost,
+                    "first close plus a quarter body",
+                    p.close() + (p.open() - p.close()) * NEAR_STAR_OVERLAP,
+                ),
+            ]),
+            Pattern::BearishNearStar => list([
+                bullish("first close", p),
+                star_body,
+                no_gap_up("star body bottom", c, bottom, p.close(), above_close),
+                price(
+                    "star body bottom",
+                    bottom,
+                    AtLeast,
+                    "first close less a quarter body",
+                    p.close() - (p.close() - p.open()) * NEAR_STAR_OVERLAP,
+                ),
+            ]),
+            Pattern::BullishEngulfing => list([
+                bearish("first close", p),
+                bullish("close", c),
+                price("open", c.open(), Below, "first close", p.close()),
+                price("close", c.close(), Above, "first open", p.open()),
+            ]),
+            Pattern::BearishEngulfing => list([
+                bullish("first close", p),
+                bearish("close", c),
+                price("open", c.open(), Above, "first close", p.close()),
+                price("close", c.close(), Below, "first open", p.open()),
+            ]),
+            Pattern::BullishHarami => list([
+                bearish("first close", p),
+                bullish("close", c),
+                price("open", c.open(), Above, "first close", p.close()),
+                price("close", c.close(), Below, "first open", p.open()),
+            ]),
+            Pattern::BearishHarami => list([
+                bullish("first close", p),
+                bearish("close", c),
+                price("open", c.open(), Below, "first close", p.close()),
+                price("close", c.close(), Above, "first open", p.open()),
+            ]),
+            Pattern::DarkCloudCover => list([
+                bearish("close", c),
+                bullish("first close", p),
+                price("open", c.open(), Above, "first close", p.close()),
+                price("close", c.close(), Below, "first body midpoint", prev_mid),
+            ]),
+            Pattern::PiercingLine => list([
+                bullish("close", c),
+                bearish("first close", p),
+                price("open", c.open(), Below, "first low", p.low()),
+                price("close", c.close(), Above, "first body midpoint", prev_mid),
+                price("close", c.close(), Below, "first open", p.open()),
+            ]),
+            Pattern::InNeck => list([
+                bullish("close", c),
+                bearish("first close", p),
+                price("open", c.open(), Below, "first low", p.low()),
+                price("close", c.close(), Above, "first close", p.close()),
+                matching("close difference", p, c.close(), p.close()),
+            ]),
+            Pattern::BullishMeetingLines => list([
+                bearish("first close", p),
+                long("first body to range", p),
+                bullish("close", c),
+                long("body to range", c),
+                price("open", c.open(), Below, "first close", p.close()),
+                matching("close difference", p, c.close(), p.close()),
+            ]),
+            Pattern::BearishMeetingLines => list([
+                bullish("first close", p),
+                long("first body to range", p),
+                bearish("close", c),
+                long("body to range", c),
+                gap_up("open", c, c.open(), p.high(), first_high),
+                matching("close difference", p, c.close(), p.close()),
+            ]),
+            Pattern::TweezerTop => list([
+                bullish("first close", p),
+                bearish("close", c),
+                matching("high difference", p, c.high(), p.high()),
+            ]),
+            Pattern::TweezerBottom => list([
+                bearish("first close", p),
+                bullish("close", c),
+                matching("low difference", p, c.low(), p.low()),
+            ]),
+            Pattern::EveningStar => list([
+                bullish("first close", p2),
+                star(p),
+                bearish("close", c),
+                price("close", c.close(), Below, "first body midpoint", first_mid),
+            ]),
+            Pattern::EveningStarDoji => list([
+                bullish("first close", p2),
+                doji("star body to range", p),
+                bearish("close", c),
+                price("close", c.close(), Below, "first body midpoint", first_mid),
+            ]),
+            Pattern::MorningStar => list([
+                bearish("first close", p2),
+                star(p),
+                bullish("close", c),
+                price("close", c.close(), Above, "first body midpoint", first_mid),
+            ]),
+            Pattern::RisingThreeMethods => list([
+                bullish("first close", p4),
+                long("first body to range", p4),
+                bearish("second close", p3),
+                bearish("third close", p2),
+                bearish("fourth close", p),
+                price(
+                    "pullback high",
+                    methods_high,
+                    AtMost,
+                    "first high",
+                    p4.high(),
+                ),
+                price("pullback low", methods_low, AtLeast, "first low", p4.low()),
+                price(
+                    "largest pullback body",
+                    methods_body,
+                    Below,
+                    "first body fraction",
+                    p4.body() * METHODS_BODY_RATIO,
+                ),
+                bullish("close", c),
+                price("close", c.close(), Above, "first high", p4.high()),
+            ]),
+            Pattern::FallingThreeMethods => list([
+                bearish("first close", p4),
+                long("first body to range", p4),
+                bullish("second close", p3),
+                bullish("third close", p2),
+                bullish("fourth close", p),
+                price(
+                    "pullback high",
+                    methods_high,
+                    AtMost,
+                    "first high",
+                    p4.high(),
+                ),
+                price("pullback low", methods_low, AtLeast, "first low", p4.low()),
+                price(
+                    "largest pullback body",
+                    methods_body,
+                    Below,
+                    "first body fraction",
+                    p4.body() * METHODS_BODY_RATIO,
+                ),
+                bearish("close", c),
+                price("close", c.close(), Below, "first low", p4.low()),
+            ]),
+            Pattern::MatHold => list([
+                bullish("first close", p4),
+                long("first body to range", p4),
+                gap_up("second low", p3, p3.low(), p4.high(), first_high),
+                price(
+                    "pullback body bottom",
+                    methods_bottom,
+                    Above,
+                    "first close",
+                    p4.close(),
+                ),
+                price(
+                    "largest pullback body",
+                    methods_body,
+                    Below,
+                    "first body fraction",
+                    p4.body() * METHODS_BODY_RATIO,
+                ),
+                bullish("close", c),
+                price("close", c.close(), Above, "pullback high", methods_high),
+            ]),
+            Pattern::BullishBreakaway => list([
+                bearish("first close", p4),
+                long("first body to range", p4),
+                gap_down("second high", p3, p3.high(), p4.low(), first_low),
+                price(
+                    "largest drift body",
+                    methods_body,
+                    Below,
+                    "first body fraction",
+                    p4.body() * METHODS_BODY_RATIO,
+                ),
+                price("third close", p2.close(), Below, "second close", p3.close()),
+                price("fourth close", p.close(), Below, "third close", p2.close()),
+                bullish("close", c),
+                long("body to range", c),
+                price(
+                    "body",
+                    c.body(),
+                    Above,
+                    "first body fraction",
+                    p4.body() * METHODS_BODY_RATIO,
+                ),
+                price("close", c.close(), Above, "second high", p3.high()),
+                price("close", c.close(), Below, "first low", p4.low()),
+            ]),
+            Pattern::BullishAbandonedBaby => list([
+                bearish("first close", p2),
+                doji("star body to range", p),
+                gap_down("star high", p, p.high(), p2.low(), first_low),
+                bullish("close", c),
+                gap_up("low", c, c.low(), p.high(), star_high),
+            ]),
+            Pattern::BullishTriStar => list([
+                doji("first body to range", p2),
+                doji("star body to range", p),
+                gap_down("star high", p, p.high(), p2.low(), first_low),
+                doji("body to range", c),
+                gap_up("low", c, c.low(), p.high(), star_high),
+            ]),
+            Pattern::BearishTriStar => list([
+                doji("first body to range", p2),
+                doji("star body to range", p),
+                gap_up("star low", p, p.low(), p2.high(), first_high),
+                doji("body to range", c),
+                gap_down("high", c, c.high(), p.low(), star_low),
+            ]),
+            Pattern::MorningStarDoji => list([
+                bearish("first close", p2),
+                doji("star body to range", p),
+                bullish("close", c),
+                price("close", c.close(), Above, "first body midpoint", first_mid),
+            ]),
+            Pattern::ThreeWhiteSoldiers => list([
+                bullish("first close", p2),
+                bullish("second close", p),
+                price("second open", p.open(), Above, "first close", p2.close()),
+                price("second close", p.close(), Above, "first close", p2.close()),
+                bullish("close", c),
+                price("open", c.open(), Above, "second close", p.close()),
+                price("close", c.close(), Above, "second close", p.close()),
+            ]),
+            Pattern::ThreeBlackCrows => list([
+                bearish("first close", p2),
+                bearish("second close", p),
+                price("second open", p.open(), Below, "first close", p2.close()),
+                price("second close", p.close(), Below, "first close", p2.close()),
+                bearish("close", c),
+                price("open", c.open(), Below, "second close", p.close()),
+                price("close", c.close(), Below, "second close", p.close()),
+            ]),
+            Pattern::ThreeInsideUp => list([
+                bearish("first close", p2),
+                bullish("second close", p),
+                price("second open", p.open(), Above, "first close", p2.close()),
+                price("second close", p.close(), Below, "first open", p2.open()),
+                bullish("close", c),
+                price("close", c.close(), Above, "second close", p.close()),
+                Condition::new(
+                    "body to range",
+                    c.body_range_ratio(),
+                    AtLeast,
+                    "doji ratio",
+                    c.doji_body_ratio(),
+                ),
+            ]),
+            Pattern::ThreeInsideDown => list([
+                bullish("first close", p2),
+                bearish("second close", p),
+                price("second open", p.open(), Below, "first close", p2.close()),
+                price("second close", p.close(), Above, "first open", p2.open()),
+                bearish("close", c),
+                price("close", c.close(), Below, "second close", p.close()),
+                Condition::new(
+                    "body to range",
+                    c.body_range_ratio(),
+                    AtLeast,
+                    "doji ratio",
+                    c.doji_body_ratio(),
+                ),
+            ]),
+            Pattern::ThreeOutsideDown => list([
+                bullish("first close", p2),
+                bearish("second close", p),
+                price("second open", p.open(), Above, "first close", p2.close()),
+                price("second close", p.close(), Below, "first open", p2.open()),
+                bearish("close", c),
+                price("close", c.close(), Below, "second close", p.close()),
+                Condition::new(
+                    "body to range",
+                    c.body_range_ratio(),
+                    AtLeast,
+                    "doji ratio",
+                    c.doji_body_ratio(),
+                ),
+            ]),
+            Pattern::UpsideTasukiGap => list([
+                bullish("first close", p2),
+                bullish("second close", p),
+                gap_up("second low", p, p.low(), p2.high(), first_high),
+                bearish("close", c),
+                price("open", c.open(), Above, "second open", p.open()),
+                price("open", c.open(), Below, "second close", p.close()),
+                price("close", c.close(), Below, "second low", p.low()),
+                price("close", c.close(), Above, "first high", p2.high()),
+            ]),
+            Pattern::DownsideTasukiGap => list([
+                bearish("first close", p2),
+                bearish("second close", p),
+                gap_down("second high", p, p.high(), p2.low(), first_low),
+                bullish("close", c),
+                price("open", c.open(), Below, "second open", p.open()),
+                price("open", c.open(), Above, "second close", p.close()),
+                price("close", c.close(), Above, "second high", p.high()),
+                price("close", c.close(), Below, "first low", p2.low()),
+            ]),
+            Pattern::UpgapSideBySideWhiteLines => list([
+                bullish("first close", p2),
+                bullish("second close", p),
+                gap_up("second low", p, p.low(), p2.high(), first_high),
+                bullish("close", c),
+                gap_up("low", c, c.low(), p2.high(), first_high),
+                matching("open difference", p, c.open(), p.open()),
+                matching(
+                    "body difference",
+                    p,
+                    c.close() - c.open(),
+                    p.close() - p.open(),
+                ),
+            ]),
+            Pattern::DowngapSideBySideWhiteLines => list([
+                bearish("first close", p2),
+                bullish("second close", p),
+                gap_down("second high", p, p.high(), p2.low(), first_low),
+                bullish("close", c),
+                gap_down("high", c, c.high(), p2.low(), first_low),
+                matching("open difference", p, c.open(), p.open()),
+                matching(
+                    "body difference",
+                    p,
+                    c.close() - c.open(),
+                    p.close() - p.open(),
+                ),
+            ]),
+            Pattern::StickSandwich => list([
+                bearish("first close", p2),
+                bullish("second close", p),
+                price("second close", p.close(), Above, "first close", p2.close()),
+                bearish("close", c),
+                matching("close difference", p2, c.close(), p2.close()),
+            ]),
+            Pattern::BullishIslandReversal => list([
+                gap_down("island high", p, p.high(), p2.low(), first_low),
+                gap_up("low", c, c.low(), p.high(), island_high),
+            ]),
+            Pattern::BearishIslandReversal => list([
+                gap_up("island low", p, p.low(), p2.high(), first_high),
+                gap_down("high", c, c.high(), p.low(), island_low),
+            ]),
+            Pattern::Upthrust => {
+                let (average, range) = self.average_volume_range(1, VSA_CONTEXT).ok_or(missing)?;
+                list([
+                    price(
+                        "high",
+                        c.high(),
+                        Above,
+                        "previous highs",
+                        p.high().max(p2.high()),
+                    ),
+                    price("range", c.range(), Above, "average range", range),
+                    Condition::new(
+                        "close position",
+                        close_position(c),
+                        AtMost,
+                        "lower third",
+                        1.0 / 3.0,
+                    ),
+                    volume(Above, "average volume", average),
+                ])
+            }
+            Pattern::Spring => {
+                let support = p.low().min(p2.low());
+                list([
+                    price("low", c.low(), Below, "previous lows", support),
+                    price("close", c.close(), Above, "previous lows", support),
+                    Condition::new(
+                        "close position",
+                        close_position(c),
+                        AtLeast,
+                        "upper third",
+                        2.0 / 3.0,
+                    ),
+                ])
+            }
+            Pattern::NoDemand => {
+                let (_, range) = self.average_volume_range(1, VSA_CONTEXT).ok_or(missing)?;
+                list([
+                    price("close", c.close(), Above, "previous close", p.close()),
+                    price("range", c.range(), Below, "average range", range),
+                    Condition::new("close position", close_position(c), AtMost, "midrange", 0.5),
+                    volume(Below, "previous volumes", p.volume().min(p2.volume())),
+                ])
+            }
+            Pattern::NoSupply => {
+                let (_, range) = self.average_volume_range(1, VSA_CONTEXT).ok_or(missing)?;
+                list([
+                    price("close", c.close(), Below, "previous close", p.close()),
+                    price("range", c.range(), Below, "average range", range),
+                    Condition::new(
+                        "close position",
+                        close_position(c),
+                        AtLeast,
+                        "midrange",
+                        0.5,
+                    ),
+                    volume(Below, "previous volumes", p.volume().min(p2.volume())),
+                ])
+            }
+        })
+    }
+}
+
+// Pads the conditions of a pattern to the maximum number of conditions
+fn list<const K: usize>(conditions: [Condition; K]) -> [Option<Condition>; MAX_CONDITIONS] {
+    const { assert!(K <= MAX_CONDITIONS) };
+    core::array::from_fn(|i| conditions.get(i).copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    // a deterministic pseudo-random walk with doji, gaps and volume swings
+    fn random_walk() -> [Ohlcv; 400] {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % 1000) as f64 / 1000.0
+        };
+        let mut close = 100.0;
+        core::array::from_fn(|_| {
+            let open = close + (next() - 0.5) * 2.0;
+            close = open + (next() - 0.5) * 3.0;
+            let high = open.max(close) + next();
+            let low = open.min(close) - next();
+            (open, high, low, close, 100.0 + next() * 200.0)
+        })
+    }
+
+    #[test]
+    fn test_explanations_agree_with_detection() {
+        let candles = random_walk();
+        let mut matched = 0;
+        for window in candles.windows(5) {
+            let mut series = CandleStream::new();
+            for candle in window {
+                series.push(candle);
+            }
+            for &p in Pattern::ALL {
+                assert_eq!(
+                    series.explain(p).is_none(),
+                    series.is_pattern(p),
+                    "{p} on {window:?}"
+                );
+                matched += usize::from(series.is_pattern(p));
+            }
+        }
+        assert!(matched > 20);
+
+        for &p in Pattern::ALL {
+            let mut series = CandleStream::new();
+            for candle in fixtures::pattern(p) {
+                series.push(candle);
+            }
+            assert_eq!(series.explain(p), None);
+        }
+    }
+
+    #[test]
+    fn test_gaps_use_the_gapping_candle_tick_size() {
+        use crate::Thresholds;
+
+        let [first, second, last] = fixtures::UPGAP_SIDE_BY_SIDE_WHITE_LINES;
+        let ticks = |tick_size| Thresholds {
+            tick_size,
+            ..Thresholds::default()
+        };
+        let pattern = Pattern::UpgapSideBySideWhiteLines;
+
+        // the second candle gaps 0.6 up, within its own minimum gap but not the last one's
+        let candles = [
+            ticks(0.0).apply(first),
+            ticks(0.5).apply(second),
+            ticks(0.7).apply(last),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+        assert!(series.is_pattern(pattern));
+        assert_eq!(series.explain(pattern), None);
+
+        // and the other way around
+        let candles = [
+            ticks(0.0).apply(first),
+            ticks(1.0).apply(second),
+            ticks(0.0).apply(last),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+        assert!(!series.is_pattern(pattern));
+        assert!(matches!(
+            series.explain(pattern),
+            Some(Mismatch::Failed(Condition { subject: "second low", threshold, .. })) if threshold == 104.0
+        ));
+    }
+
+    #[test]
+    fn test_distance_shrinks_towards_the_pattern() {
+        let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+        let distances = [103.5, 103.0, 102.5, 102.0].map(|close| {
+            let curr = (105.5, 106.0, 101.5, close, 0.0);
+            let mut series = CandleStream::new();
+            series.push(&prev).push(&curr);
+            series.distance_to(Pattern::DarkCloudCover)
+        });
+
+        assert!(distances.windows(2).all(|d| d[0] > d[1]));
+        assert_eq!(distances[3], 0.0);
+    }
+
+    #[test]
+    fn test_missing_candles() {
+        let mut series = CandleStream::new();
+        series
+            .push(&fixtures::MORNING_STAR[1])
+            .push(&fixtures::MORNING_STAR[2]);
+
+        assert_eq!(
+            series.explain(Pattern::MorningStar),
+            Some(Mismatch::MissingCandles {
+                needed: 3,
+                available: 2
+            })
+        );
+    }
+}
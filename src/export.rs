@@ -0,0 +1,260 @@
+use std::{
+    io::{self, Write},
+    string::String,
+};
+
+use crate::{CandleStick, Pattern, Scanner};
+
+#[cfg(feature = "parquet")]
+use crate::PatternSet;
+#[cfg(feature = "parquet")]
+use parquet::{
+    basic::{Repetition, Type as PhysicalType},
+    data_type::{BoolType, DoubleType, Int64Type},
+    errors::ParquetError,
+    file::writer::SerializedFileWriter,
+    schema::types::Type,
+};
+#[cfg(feature = "parquet")]
+use std::{format, sync::Arc, vec, vec::Vec};
+
+/// Scans a candle series and writes a labeled dataset as CSV, for training machine learning
+/// models on candlestick features.
+///
+/// Each row describes one bar: its index, a `0`/`1` flag per built-in [`Pattern`] completed on
+/// that bar, and the forward close to close return over each of the given horizons (left
+/// empty when the horizon goes past the end of the series). With the `parquet` feature, the
+/// same dataset can be written as Parquet with [`export_labels_parquet`].
+///
+/// # Example
+/// ```
+/// use candlestick_rs::export_labels;
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+///     (102.5, 104.0, 102.0, 103.5, 0.0),
+/// ];
+/// let mut csv = Vec::new();
+/// export_labels(&candles, &[1], &mut csv).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// let mut rows = csv.lines().skip(1);
+/// assert!(rows.nth(1).unwrap().contains(",1,"));
+/// ```
+pub fn export_labels<T: CandleStick, W: Write>(
+    candles: &[T],
+    horizons: &[usize],
+    mut writer: W,
+) -> io::Result<()> {
+    write!(writer, "index")?;
+    for &pattern in Pattern::ALL {
+        write!(writer, ",{}", column_name(pattern))?;
+    }
+    for horizon in horizons {
+        write!(writer, ",fwd_return_{horizon}")?;
+    }
+    writeln!(writer)?;
+
+    let mut detections = Scanner::new(candles).peekable();
+    for (idx, candle) in candles.iter().enumerate() {
+        let detected = detections
+            .next_if(|(i, _)| *i == idx)
+            .map(|(_, detected)| detected)
+            .unwrap_or_default();
+
+        write!(writer, "{idx}")?;
+        for &pattern in Pattern::ALL {
+            write!(writer, ",{}", u8::from(detected.contains(pattern)))?;
+        }
+        for horizon in horizons {
+            match candles.get(idx + horizon) {
+                Some(future) => write!(writer, ",{}", future.return_from(candle))?,
+                None => write!(writer, ",")?,
+            }
+        }
+        writeln!(writer)?;
+    }
+
+    writer.flush()
+}
+
+/// Scans a candle series and writes the labeled dataset of [`export_labels`] as a Parquet file,
+/// in a single row group (`parquet` feature).
+///
+/// The `index` column is an `INT64`, each pattern flag a `BOOLEAN` and each forward return an
+/// optional `DOUBLE`, null when the horizon goes past the end of the series.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::export_labels_parquet;
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let mut file = Vec::new();
+/// export_labels_parquet(&candles, &[1], &mut file).unwrap();
+/// assert!(file.starts_with(b"PAR1"));
+/// ```
+#[cfg(feature = "parquet")]
+pub fn export_labels_parquet<T: CandleStick, W: Write + Send>(
+    candles: &[T],
+    horizons: &[usize],
+    writer: W,
+) -> Result<(), ParquetError> {
+    let field = |name: &str, physical, repetition| {
+        Type::primitive_type_builder(name, physical)
+            .with_repetition(repetition)
+            .build()
+            .map(Arc::new)
+    };
+    let mut fields = vec![field("index", PhysicalType::INT64, Repetition::REQUIRED)?];
+    for &pattern in Pattern::ALL {
+        let name = column_name(pattern);
+        fields.push(field(&name, PhysicalType::BOOLEAN, Repetition::REQUIRED)?);
+    }
+    for horizon in horizons {
+        let name = format!("fwd_return_{horizon}");
+        fields.push(field(&name, PhysicalType::DOUBLE, Repetition::OPTIONAL)?);
+    }
+    let schema = Type::group_type_builder("labels")
+        .with_fields(fields)
+        .build()?;
+
+    let mut detected = vec![PatternSet::new(); candles.len()];
+    for (idx, patterns) in Scanner::new(candles) {
+        detected[idx] = patterns;
+    }
+
+    let mut file = SerializedFileWriter::new(writer, Arc::new(schema), Default::default())?;
+    let mut group = file.next_row_group()?;
+    let mut column = 0;
+    while let Some(mut writer) = group.next_column()? {
+        if column == 0 {
+            let index: Vec<i64> = (0..candles.len() as i64).collect();
+            writer
+                .typed::<Int64Type>()
+                .write_batch(&index, None, None)?;
+        } else if let Some(&pattern) = Pattern::ALL.get(column - 1) {
+            let flags: Vec<bool> = detected.iter().map(|d| d.contains(pattern)).collect();
+            writer.typed::<BoolType>().write_batch(&flags, None, None)?;
+        } else if let Some(horizon) = horizons.get(column - 1 - Pattern::ALL.len()) {
+            let returns: Vec<Option<f64>> = candles
+                .iter()
+                .enumerate()
+                .map(|(idx, candle)| {
+                    candles
+                        .get(idx + horizon)
+                        .map(|future| future.return_from(candle))
+                })
+                .collect();
+            let values: Vec<f64> = returns.iter().flatten().copied().collect();
+            let levels: Vec<i16> = returns.iter().map(|r| i16::from(r.is_some())).collect();
+            writer
+                .typed::<DoubleType>()
+                .write_batch(&values, Some(&levels), None)?;
+        }
+        writer.close()?;
+        column += 1;
+    }
+    group.close()?;
+    file.close()?;
+
+    Ok(())
+}
+
+// Returns the column name of the pattern, like `bullish_tri_star`
+fn column_name(pattern: Pattern) -> String {
+    pattern
+        .name()
+        .chars()
+        .map(|c| match c {
+            ' ' | '-' => '_',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{string::String, vec::Vec};
+
+    #[test]
+    fn test_export_labels() {
+        let candles = [
+            (101.0, 102.0, 99.5, 100.0, 0.0),
+            (99.0, 103.0, 98.5, 102.0, 0.0),
+            (102.0, 104.0, 101.0, 103.0, 0.0),
+        ];
+
+        let mut csv = Vec::new();
+        assert!(export_labels(&candles, &[1, 2], &mut csv).is_ok());
+
+        let csv = String::from_utf8(csv).unwrap_or_default();
+        let rows: Vec<&str> = csv.lines().collect();
+        let flags = |idx: usize| rows[idx + 1].split(',').skip(1).take(Pattern::ALL.len());
+
+        assert_eq!(rows.len(), 4);
+        assert!(rows[0].starts_with("index,rising_three_methods,falling_three_methods,"));
+        assert!(rows[0].ends_with(",no_supply,fwd_return_1,fwd_return_2"));
+        assert!(rows[0].contains(",upside_gap_side_by_side_white_lines,"));
+        assert!(flags(0).all(|flag| flag == "0"));
+        assert_eq!(
+            flags(1).position(|flag| flag == "1"),
+            Some(Pattern::BullishEngulfing.priority())
+        );
+        assert!(rows[1].ends_with(",0.02,0.03"));
+        assert!(rows[3].ends_with(",,"));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_export_labels_parquet() -> Result<(), ParquetError> {
+        use parquet::{
+            file::reader::{FileReader, SerializedFileReader},
+            record::Field,
+        };
+
+        let candles = [
+            (101.0, 102.0, 99.5, 100.0, 0.0),
+            (99.0, 103.0, 98.5, 102.0, 0.0),
+            (102.0, 104.0, 101.0, 103.0, 0.0),
+        ];
+
+        let mut file = Vec::new();
+        export_labels_parquet(&candles, &[1, 2], &mut file)?;
+
+        let reader = SerializedFileReader::new(bytes::Bytes::from(file))?;
+        let schema = reader.metadata().file_metadata().schema_descr_ptr();
+        let names: Vec<&str> = schema.columns().iter().map(|c| c.name()).collect();
+        assert_eq!(names.len(), Pattern::ALL.len() + 3);
+        assert_eq!(
+            names[..3],
+            ["index", "rising_three_methods", "falling_three_methods"]
+        );
+        assert_eq!(
+            names[names.len() - 3..],
+            ["no_supply", "fwd_return_1", "fwd_return_2"]
+        );
+
+        let rows = reader.get_row_iter(None)?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(rows.len(), 3);
+
+        let engulfing = 1 + Pattern::BullishEngulfing.priority();
+        let columns = |row: usize| rows[row].get_column_iter().map(|(_, field)| field);
+        assert!(matches!(
+            rows[1].get_column_iter().next(),
+            Some((_, Field::Long(1)))
+        ));
+        assert!(columns(0).all(|field| !matches!(field, Field::Bool(true))));
+        assert_eq!(
+            columns(1).position(|field| matches!(field, Field::Bool(true))),
+            Some(engulfing)
+        );
+        assert!(matches!(columns(0).nth(names.len() - 2), Some(Field::Double(r)) if *r == 0.02));
+        assert!(matches!(columns(0).nth(names.len() - 1), Some(Field::Double(r)) if *r == 0.03));
+        assert!(columns(2)
+            .skip(names.len() - 2)
+            .all(|f| matches!(f, Field::Null)));
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{utils::midpoint, CandleStick, CandleStream, Direction};
+
+/// A three-candle price imbalance, as returned by [`CandleStream::fair_value_gaps`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FairValueGap {
+    /// Bullish when the third candle's low is above the first candle's high, bearish when its
+    /// high is below the first candle's low
+    pub direction: Direction,
+    /// Top of the gap
+    pub upper: f64,
+    /// Bottom of the gap
+    pub lower: f64,
+    /// Position of the third candle, which completes the gap, `0` being the latest candle
+    pub bars_ago: usize,
+    /// Fraction of the gap traded back into by the candles after the third one, from `0.0`
+    /// to `1.0`
+    pub filled: f64,
+}
+
+impl FairValueGap {
+    /// Returns true if later candles traded through the whole gap
+    pub fn is_filled(&self) -> bool {
+        self.filled >= 1.0
+    }
+
+    /// Returns the midpoint of the gap, often watched as the equilibrium of the imbalance
+    pub fn midpoint(&self) -> f64 {
+        midpoint(self.upper, self.lower)
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Fair Value Gap, a three-candle imbalance where the wicks of the first and
+    /// third candles do not overlap, leaving a price range traded in one direction only.
+    ///
+    /// The gap must span at least [`CandleStick::min_gap_ticks`] ticks.
+    ///
+    /// **Trading Significance**:
+    /// - Shows an aggressive, one-sided move that left orders unfilled
+    /// - Price often returns to the gap before resuming the move
+    /// - Unfilled gaps act as support, bullish ones, or resistance, bearish ones
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let first = (100.0, 101.0, 99.5, 100.8, 0.0);
+    /// let second = (100.8, 104.5, 100.6, 104.2, 0.0);
+    /// let third = (104.2, 105.0, 102.0, 104.8, 0.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&first).push(&second).push(&third).is_fair_value_gap());
+    /// ```
+    pub fn is_fair_value_gap(&self) -> bool {
+        self.gap_at(0).is_some()
+    }
+
+    /// Iterates over the fair value gaps of the buffered history, from the latest to the
+    /// oldest, with the fraction of each gap filled since it formed.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction};
+    /// let candles = [
+    ///     (100.0, 101.0, 99.5, 100.8, 0.0),
+    ///     (100.8, 104.5, 100.6, 104.2, 0.0),
+    ///     (104.2, 105.0, 102.0, 104.8, 0.0),
+    ///     (104.8, 105.5, 101.5, 102.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let gap = series.fair_value_gaps().next().unwrap();
+    /// assert_eq!((gap.direction, gap.lower, gap.upper), (Direction::Bullish, 101.0, 102.0));
+    /// assert_eq!((gap.bars_ago, gap.filled), (1, 0.5));
+    /// ```
+    pub fn fair_value_gaps(&self) -> impl Iterator<Item = FairValueGap> + '_ {
+        (0..self.len()).filter_map(|bars_ago| self.gap_at(bars_ago))
+    }
+
+    // Returns the gap completed by the candle at the given position, if any
+    fn gap_at(&self, bars_ago: usize) -> Option<FairValueGap> {
+        let third = self.prev(bars_ago)?;
+        let first = self.prev(bars_ago + 2)?;
+
+        let (direction, lower, upper) = match () {
+            _ if third.is_gap(first.high(), third.low()) => {
+                (Direction::Bullish, first.high(), third.low())
+            }
+            _ if third.is_gap(third.high(), first.low()) => {
+                (Direction::Bearish, third.high(), first.low())
+            }
+            _ => return None,
+        };
+
+        let later = || (0..bars_ago).filter_map(|n| self.prev(n));
+        let reached = match direction {
+            Direction::Bullish => later().map(|c| upper - c.low()).fold(0.0, f64::max),
+            Direction::Bearish => later().map(|c| c.high() - lower).fold(0.0, f64::max),
+        };
+
+        Some(FairValueGap {
+            direction,
+            upper,
+            lower,
+            bars_ago,
+            filled: (reached / (upper - lower)).min(1.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearish_fair_value_gap() {
+        let candles = [
+            (104.0, 104.5, 102.0, 102.2, 0.0),
+            (102.2, 102.4, 98.0, 98.3, 0.0),
+            (98.3, 100.0, 97.5, 98.0, 0.0),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        let gap = series.fair_value_gaps().next();
+        assert!(series.is_fair_value_gap());
+        assert!(gap.is_some_and(|g| g.direction == Direction::Bearish));
+        assert!(gap.is_some_and(|g| (g.lower, g.upper, g.midpoint()) == (100.0, 102.0, 101.0)));
+        assert!(gap.is_some_and(|g| g.filled == 0.0 && !g.is_filled()));
+    }
+
+    #[test]
+    fn test_fair_value_gap_fill() {
+        let candles = [
+            (100.0, 101.0, 99.5, 100.8, 0.0),
+            (100.8, 104.5, 100.6, 104.2, 0.0),
+            (104.2, 105.0, 102.0, 104.8, 0.0),
+            (104.8, 105.5, 101.5, 102.0, 0.0),
+            (102.0, 102.5, 100.5, 101.0, 0.0),
+        ];
+        let mut series = CandleStream::<_, 10>::with_capacity();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        assert!(!series.is_fair_value_gap());
+        assert_eq!(series.fair_value_gaps().count(), 1);
+        assert!(series
+            .fair_value_gaps()
+            .next()
+            .is_some_and(|g| g.bars_ago == 2 && g.is_filled()));
+    }
+
+    #[test]
+    fn test_overlapping_wicks() {
+        let candles = [
+            (100.0, 101.0, 99.5, 100.8, 0.0),
+            (100.8, 104.5, 100.6, 104.2, 0.0),
+            (104.2, 105.0, 101.0, 104.8, 0.0),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        assert!(!series.is_fair_value_gap());
+        assert_eq!(series.fair_value_gaps().next(), None);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{CandleStick, CandleStream};
+
+/// Numeric description of the latest candle's geometry and context, as returned by
+/// [`CandleStream::features`].
+///
+/// The features reuse the exact definitions of the pattern detectors, so models trained on
+/// them see candles the same way the detectors do. All features are scale-free.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Features {
+    /// Body to range ratio
+    pub body_ratio: f64,
+    /// Upper shadow to range ratio
+    pub wick_ratio: f64,
+    /// Lower shadow to range ratio
+    pub tail_ratio: f64,
+    /// `1.0` for a bullish candle, `-1.0` for a bearish one, `0.0` otherwise
+    pub direction: f64,
+    /// Signed gap to the previous candle, relative to the previous candle's range
+    pub gap: f64,
+    /// Volume relative to the average volume of the window, `1.0` if the average is zero
+    pub relative_volume: f64,
+    /// Position of the close within the window's high to low range, from `0.0` to `1.0`
+    pub range_position: f64,
+}
+
+impl Features {
+    /// Number of features
+    pub const LEN: usize = 7;
+
+    /// Returns the features as a fixed-size vector, in field declaration order
+    pub fn to_array(&self) -> [f64; Self::LEN] {
+        [
+            self.body_ratio,
+            self.wick_ratio,
+            self.tail_ratio,
+            self.direction,
+            self.gap,
+            self.relative_volume,
+            self.range_position,
+        ]
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the feature vector of the latest candle, using the last `n` candles as context
+    /// for relative volume and range position.
+    ///
+    /// Returns `None` if `n` is lower than 2 or fewer than `n` candles are available.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (100.0, 105.0, 99.0, 104.0, 100.0);
+    /// let curr = (106.0, 110.0, 105.0, 109.0, 300.0);
+    /// let mut series = CandleStream::new();
+    /// let features = series.push(&prev).push(&curr).features(2).unwrap();
+    /// assert_eq!(features.body_ratio, 0.6);
+    /// assert_eq!(features.relative_volume, 1.5);
+    /// assert_eq!(features.range_position, 10.0 / 11.0);
+    /// ```
+    pub fn features(&self, n: usize) -> Option<Features> {
+        if n < 2 || n > self.len() {
+            return None;
+        }
+
+        let (c, p) = self.get().zip(self.prev(1))?;
+        let (highest, lowest) = self.highest_high(n).zip(self.lowest_low(n))?;
+        let mean_volume = self.iter().take(n).map(|c| c.volume()).sum::<f64>() / n as f64;
+
+        Some(Features {
+            body_ratio: c.body_range_ratio(),
+            wick_ratio: c.wick_range_ratio(),
+            tail_ratio: c.tail_range_ratio(),
+            direction: match (c.is_bullish(), c.is_bearish()) {
+                (true, _) => 1.0,
+                (_, true) => -1.0,
+                _ => 0.0,
+            },
+            gap: self.gap_to_prev()? / p.range(),
+            relative_volume: match mean_volume > 0.0 {
+                true => c.volume() / mean_volume,
+                false => 1.0,
+            },
+            range_position: match highest > lowest {
+                true => (c.close() - lowest) / (highest - lowest),
+                false => 0.5,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features() {
+        let prev = (100.0, 105.0, 99.0, 104.0, 100.0);
+        let curr = (106.0, 110.0, 105.0, 109.0, 300.0);
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.push(&prev).features(2), None);
+
+        series.push(&curr);
+
+        assert_eq!(series.features(1), None);
+        assert_eq!(series.features(3), None);
+        assert_eq!(
+            series.features(2).map(|f| f.to_array()),
+            Some([0.6, 0.2, 0.2, 1.0, 0.0, 1.5, 10.0 / 11.0])
+        );
+    }
+
+    #[test]
+    fn test_features_degenerate_context() {
+        let flat = (100.0, 100.0, 100.0, 100.0, 0.0);
+
+        let mut series = CandleStream::new();
+        let features = series.push(&flat).push(&flat).features(2);
+
+        assert_eq!(features.map(|f| f.direction), Some(0.0));
+        assert_eq!(features.map(|f| f.relative_volume), Some(1.0));
+        assert_eq!(features.map(|f| f.range_position), Some(0.5));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Swing, ZigZag};
+
+/// Fibonacci retracement and extension levels of a price leg
+///
+/// Levels are expressed as ratios of the leg: retracements measure back from the end of the
+/// leg toward its start, extensions project from its start beyond its end.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::Fibonacci;
+/// let leg = Fibonacci::new(100.0, 110.0);
+/// assert_eq!(leg.retracement(0.5), 105.0);
+/// assert_eq!(leg.extension(2.0), 120.0);
+/// assert_eq!(leg.near_level(106.25, 0.02), Some(0.382));
+/// assert_eq!(leg.near_level(109.0, 0.02), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fibonacci {
+    /// Price where the leg starts
+    pub start: f64,
+    /// Price where the leg ends
+    pub end: f64,
+}
+
+impl Fibonacci {
+    /// Standard retracement ratios
+    pub const RETRACEMENTS: [f64; 5] = [0.236, 0.382, 0.5, 0.618, 0.786];
+
+    /// Standard extension ratios
+    pub const EXTENSIONS: [f64; 4] = [1.272, 1.618, 2.0, 2.618];
+
+    /// Creates the levels of the leg from `start` to `end`
+    pub fn new(start: f64, end: f64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the levels of the leg between two swing points
+    pub fn from_swings(start: &Swing, end: &Swing) -> Self {
+        Self::new(start.price, end.price)
+    }
+
+    /// Returns the signed height of the leg, positive for an upward leg
+    pub fn height(&self) -> f64 {
+        self.end - self.start
+    }
+
+    /// Returns the price retracing the given `ratio` of the leg from its end
+    pub fn retracement(&self, ratio: f64) -> f64 {
+        self.end - ratio * self.height()
+    }
+
+    /// Returns the price extending the leg to the given `ratio` of its height from its start
+    pub fn extension(&self, ratio: f64) -> f64 {
+        self.start + ratio * self.height()
+    }
+
+    /// Iterates over the standard retracements, as `(ratio, price)` pairs
+    pub fn retracements(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        Self::RETRACEMENTS
+            .into_iter()
+            .map(|ratio| (ratio, self.retracement(ratio)))
+    }
+
+    /// Iterates over the standard extensions, as `(ratio, price)` pairs
+    pub fn extensions(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        Self::EXTENSIONS
+            .into_iter()
+            .map(|ratio| (ratio, self.extension(ratio)))
+    }
+
+    /// Returns the ratio of the standard level closest to `price`, if within `tolerance`, a
+    /// fraction of the leg height (e.g. `0.02` for 2%).
+    ///
+    /// Retracement ratios are returned below `1.0` and extension ratios above it, which is
+    /// handy to qualify a candle pattern forming at a Fibonacci confluence.
+    pub fn near_level(&self, price: f64, tolerance: f64) -> Option<f64> {
+        let allowed = tolerance * self.height().abs();
+        if allowed == 0.0 {
+            return None;
+        }
+
+        self.retracements()
+            .chain(self.extensions())
+            .map(|(ratio, level)| (ratio, (price - level).abs()))
+            .filter(|(_, distance)| *distance <= allowed)
+            .reduce(|closest, level| match level.1 < closest.1 {
+                true => level,
+                false => closest,
+            })
+            .map(|(ratio, _)| ratio)
+    }
+}
+
+impl<const N: usize> ZigZag<N> {
+    /// Returns the Fibonacci levels of the latest completed leg, between the two latest
+    /// confirmed pivots
+    pub fn fibonacci(&self) -> Option<Fibonacci> {
+        self.legs()
+            .next()
+            .map(|(start, end)| Fibonacci::from_swings(&start, &end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CandleStream, ZigZagThreshold};
+
+    #[test]
+    fn test_levels() {
+        let down = Fibonacci::new(110.0, 100.0);
+
+        assert_eq!(down.height(), -10.0);
+        assert!(down
+            .retracements()
+            .zip([102.36, 103.82, 105.0, 106.18, 107.86])
+            .all(|((_, level), expected)| (level - expected).abs() < 1e-9));
+        assert_eq!(down.extension(2.0), 90.0);
+        assert_eq!(down.near_level(93.9, 0.02), Some(1.618));
+        assert_eq!(down.near_level(101.0, 0.02), None);
+        assert_eq!(Fibonacci::new(100.0, 100.0).near_level(100.0, 0.1), None);
+    }
+
+    #[test]
+    fn test_zigzag_leg() {
+        let candles = [
+            (100.0, 101.0, 99.0, 100.5, 0.0),
+            (100.5, 106.0, 100.0, 105.5, 0.0),
+            (105.5, 110.0, 105.0, 109.0, 0.0),
+            (109.0, 109.5, 104.0, 104.5, 0.0),
+            (104.5, 106.5, 104.2, 106.0, 0.0),
+        ];
+        let mut series = CandleStream::<_, 10>::with_capacity();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        let leg = series.zigzag(ZigZagThreshold::Percent(0.03)).fibonacci();
+        assert_eq!(leg, Some(Fibonacci::new(99.0, 110.0)));
+        assert_eq!(leg.and_then(|l| l.near_level(104.3, 0.03)), Some(0.5));
+        assert_eq!(
+            series.zigzag(ZigZagThreshold::Percent(0.2)).fibonacci(),
+            None
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Calendar, Candle, CandleStick, Timestamped};
+
+/// How the synthetic candles filling a hole are built
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMode {
+    /// Flat candles with all prices at the previous close
+    Flat,
+    /// Copies of the previous candle's prices
+    CarryForward,
+}
+
+/// Iterator over a candle series with its holes filled by synthetic candles,
+/// as returned by [`fill_holes`]
+#[derive(Debug)]
+pub struct FillHoles<'a, T, C> {
+    candles: &'a [T],
+    interval: i64,
+    calendar: &'a C,
+    mode: FillMode,
+    idx: usize,
+    last: Option<Candle>,
+}
+
+/// Fills the holes of a candle series with synthetic candles, so that fixed-lookback pattern
+/// logic and resampling behave predictably on imperfect datasets.
+///
+/// Bars are expected every `interval` at the times the calendar is trading. Synthetic candles
+/// have zero volume and are built according to the [`FillMode`]. Candles are keyed by their
+/// [open time](Timestamped::open_time).
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{fill_holes, AlwaysOpen, Candle, FillMode};
+/// let candles = [
+///     Candle::new(0, 100.0, 105.0, 99.0, 104.0, 10.0),
+///     Candle::new(180, 104.0, 106.0, 103.0, 105.0, 10.0),
+/// ];
+/// let filled: Vec<_> = fill_holes(&candles, 60, &AlwaysOpen, FillMode::Flat).collect();
+/// assert_eq!(filled.len(), 4);
+/// assert_eq!(filled[1], Candle::new(60, 104.0, 104.0, 104.0, 104.0, 0.0));
+/// ```
+pub fn fill_holes<'a, T, C>(
+    candles: &'a [T],
+    interval: i64,
+    calendar: &'a C,
+    mode: FillMode,
+) -> FillHoles<'a, T, C> {
+    FillHoles {
+        candles,
+        interval,
+        calendar,
+        mode,
+        idx: 0,
+        last: None,
+    }
+}
+
+impl<T: CandleStick + Timestamped, C: Calendar> Iterator for FillHoles<'_, T, C> {
+    type Item = Candle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let candle = self.candles.get(self.idx)?;
+        let next = Candle {
+            timestamp: candle.open_time(self.interval),
+            ..Candle::from(candle)
+        };
+
+        if let Some(last) = self.last.filter(|_| self.interval > 0) {
+            let mut timestamp = last.timestamp + self.interval;
+            while timestamp < next.timestamp {
+                if self.calendar.is_trading(timestamp) {
+                    let synthetic = match self.mode {
+                        FillMode::Flat => Candle::new(
+                            timestamp, last.close, last.close, last.close, last.close, 0.0,
+                        ),
+                        FillMode::CarryForward => Candle {
+                            timestamp,
+                            volume: 0.0,
+                            ..last
+                        },
+                    };
+                    self.last = Some(synthetic);
+                    return self.last;
+                }
+                timestamp += self.interval;
+            }
+        }
+
+        self.idx += 1;
+        self.last = Some(next);
+        self.last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlwaysOpen;
+
+    #[test]
+    fn test_fill_holes_flat() {
+        let candles = [
+            Candle::new(0, 100.0, 105.0, 99.0, 104.0, 10.0),
+            Candle::new(60, 104.0, 106.0, 103.0, 105.0, 10.0),
+            Candle::new(240, 105.0, 107.0, 104.0, 106.0, 10.0),
+        ];
+
+        let mut filled = fill_holes(&candles, 60, &AlwaysOpen, FillMode::Flat);
+
+        assert_eq!(filled.next(), Some(candles[0]));
+        assert_eq!(filled.next(), Some(candles[1]));
+        assert_eq!(
+            filled.next(),
+            Some(Candle::new(120, 105.0, 105.0, 105.0, 105.0, 0.0))
+        );
+        assert_eq!(
+            filled.next(),
+            Some(Candle::new(180, 105.0, 105.0, 105.0, 105.0, 0.0))
+        );
+        assert_eq!(filled.next(), Some(candles[2]));
+        assert_eq!(filled.next(), None);
+    }
+
+    #[test]
+    fn test_fill_holes_carry_forward_with_calendar() {
+        let candles = [
+            Candle::new(0, 100.0, 105.0, 99.0, 104.0, 10.0),
+            Candle::new(240, 105.0, 107.0, 104.0, 106.0, 10.0),
+        ];
+        let closed_at_120 = |ts: i64| ts != 120;
+
+        let mut filled = fill_holes(&candles, 60, &closed_at_120, FillMode::CarryForward);
+
+        assert_eq!(filled.next(), Some(candles[0]));
+        assert_eq!(
+            filled.next(),
+            Some(Candle::new(60, 100.0, 105.0, 99.0, 104.0, 0.0))
+        );
+        assert_eq!(
+            filled.next(),
+            Some(Candle::new(180, 100.0, 105.0, 99.0, 104.0, 0.0))
+        );
+        assert_eq!(filled.next(), Some(candles[1]));
+        assert_eq!(filled.next(), None);
+    }
+
+    #[test]
+    fn test_fill_holes_without_holes() {
+        let candles = [
+            Candle::new(0, 100.0, 105.0, 99.0, 104.0, 10.0),
+            Candle::new(60, 104.0, 106.0, 103.0, 105.0, 10.0),
+        ];
+
+        assert!(fill_holes(&candles, 60, &AlwaysOpen, FillMode::Flat).eq(candles));
+        assert!(fill_holes(&candles, 0, &AlwaysOpen, FillMode::Flat).eq(candles));
+    }
+}
@@ -0,0 +1,441 @@
+//! Canonical candle sequences for every supported pattern.
+//!
+//! Each sequence ends with the candle completing its pattern, and is guaranteed to be detected
+//! by the current definitions: the crate tests itself against them, so a change of definition
+//! breaking a fixture is a breaking change. Downstream crates can use them to write integration
+//! tests against known-good detections.
+//!
+//! Enable the `fixtures` feature to use this module outside of the crate tests.
+//!
+//! # Example
+//! ```
+//! use candlestick_rs::{fixtures, CandleStream, Pattern};
+//! let mut series = CandleStream::new();
+//! for candle in fixtures::pattern(Pattern::BullishEngulfing) {
+//!     series.push(candle);
+//! }
+//! assert!(series.is_bullish_engulfing());
+//! ```
+
+use crate::{CandleType, Pattern};
+
+/// A candle as an `(open, high, low, close, volume)` tuple
+pub type Ohlcv = (f64, f64, f64, f64, f64);
+
+/// A Morning Star Doji, see [`crate::CandleStream::is_morning_star_doji`]
+pub const MORNING_STAR_DOJI: [Ohlcv; 3] = [
+    (52.0, 52.5, 48.0, 48.5, 0.0),
+    (48.3, 48.9, 47.5, 48.4, 0.0),
+    (48.7, 51.5, 48.5, 51.2, 0.0),
+];
+
+/// A Rising Three Methods, see [`crate::CandleStream::is_rising_three_methods`]
+pub const RISING_THREE_METHODS: [Ohlcv; 5] = [
+    (100.0, 106.0, 99.5, 105.5, 0.0),
+    (105.0, 105.5, 103.5, 104.0, 0.0),
+    (104.0, 104.5, 102.5, 103.0, 0.0),
+    (103.0, 103.5, 101.5, 102.0, 0.0),
+    (102.0, 108.0, 101.8, 107.5, 0.0),
+];
+
+/// A Falling Three Methods, see [`crate::CandleStream::is_falling_three_methods`]
+pub const FALLING_THREE_METHODS: [Ohlcv; 5] = [
+    (106.0, 106.5, 100.0, 100.5, 0.0),
+    (101.0, 102.5, 100.5, 102.0, 0.0),
+    (102.0, 103.5, 101.5, 103.0, 0.0),
+    (103.0, 104.5, 102.5, 104.0, 0.0),
+    (104.0, 104.2, 98.0, 98.5, 0.0),
+];
+
+/// A Mat Hold, see [`crate::CandleStream::is_mat_hold`]
+pub const MAT_HOLD: [Ohlcv; 5] = [
+    (100.0, 105.5, 99.5, 105.0, 0.0),
+    (106.5, 107.5, 106.0, 107.0, 0.0),
+    (107.0, 107.2, 105.8, 106.0, 0.0),
+    (106.0, 106.5, 105.2, 105.5, 0.0),
+    (105.6, 109.0, 105.4, 108.5, 0.0),
+];
+
+/// A Bullish Breakaway, see [`crate::CandleStream::is_bullish_breakaway`]
+pub const BULLISH_BREAKAWAY: [Ohlcv; 5] = [
+    (110.0, 110.5, 104.5, 105.0, 0.0),
+    (103.0, 103.5, 102.0, 102.5, 0.0),
+    (102.5, 102.8, 101.2, 101.5, 0.0),
+    (101.5, 101.8, 100.2, 100.5, 0.0),
+    (100.6, 104.2, 100.4, 104.0, 0.0),
+];
+
+/// A Bullish Abandoned Baby, see [`crate::CandleStream::is_bullish_abandoned_baby`]
+pub const BULLISH_ABANDONED_BABY: [Ohlcv; 3] = [
+    (52.0, 52.5, 48.0, 48.5, 0.0),
+    (47.0, 47.5, 46.8, 47.0, 0.0),
+    (48.0, 51.5, 47.8, 51.2, 0.0),
+];
+
+/// A Bullish Tri-Star, see [`crate::CandleStream::is_bullish_tri_star`]
+pub const BULLISH_TRI_STAR: [Ohlcv; 3] = [
+    (100.0, 100.5, 99.5, 100.02, 0.0),
+    (99.0, 99.3, 98.7, 99.0, 0.0),
+    (99.8, 100.2, 99.5, 99.82, 0.0),
+];
+
+/// A Bearish Tri-Star, see [`crate::CandleStream::is_bearish_tri_star`]
+pub const BEARISH_TRI_STAR: [Ohlcv; 3] = [
+    (100.0, 100.5, 99.5, 99.98, 0.0),
+    (101.0, 101.3, 100.7, 101.0, 0.0),
+    (100.2, 100.5, 99.8, 100.18, 0.0),
+];
+
+/// An Evening Star Doji, see [`crate::CandleStream::is_evening_star_doji`]
+pub const EVENING_STAR_DOJI: [Ohlcv; 3] = [
+    (100.0, 106.0, 99.5, 105.5, 0.0),
+    (106.1, 107.0, 105.8, 106.1, 0.0),
+    (105.0, 105.2, 99.8, 101.0, 0.0),
+];
+
+/// A Morning Star, see [`crate::CandleStream::is_morning_star`]
+pub const MORNING_STAR: [Ohlcv; 3] = [
+    (52.0, 52.5, 48.0, 48.5, 0.0),
+    (48.2, 48.9, 47.5, 48.3, 0.0),
+    (48.7, 51.5, 48.5, 51.2, 0.0),
+];
+
+/// An Evening Star, see [`crate::CandleStream::is_evening_star`]
+pub const EVENING_STAR: [Ohlcv; 3] = [
+    (100.0, 106.0, 99.5, 105.5, 0.0),
+    (106.2, 107.0, 105.8, 106.5, 0.0),
+    (105.5, 106.0, 102.0, 101.5, 0.0),
+];
+
+/// Three White Soldiers, see [`crate::CandleStream::is_three_white_soldiers`]
+pub const THREE_WHITE_SOLDIERS: [Ohlcv; 3] = [
+    (48.0, 50.5, 47.8, 50.2, 0.0),
+    (50.3, 52.7, 50.1, 52.4, 0.0),
+    (52.5, 54.8, 52.3, 54.5, 0.0),
+];
+
+/// Three Black Crows, see [`crate::CandleStream::is_three_black_crows`]
+pub const THREE_BLACK_CROWS: [Ohlcv; 3] = [
+    (54.0, 54.5, 51.8, 52.2, 0.0),
+    (52.0, 52.3, 49.7, 50.4, 0.0),
+    (50.2, 50.5, 47.9, 48.3, 0.0),
+];
+
+/// A Three Inside Up, see [`crate::CandleStream::is_three_inside_up`]
+pub const THREE_INSIDE_UP: [Ohlcv; 3] = [
+    (54.0, 54.5, 51.8, 52.0, 0.0),
+    (52.2, 53.0, 52.0, 52.8, 0.0),
+    (52.9, 55.0, 52.7, 54.5, 0.0),
+];
+
+/// A Three Inside Down, see [`crate::CandleStream::is_three_inside_down`]
+pub const THREE_INSIDE_DOWN: [Ohlcv; 3] = [
+    (48.0, 50.5, 47.8, 50.0, 0.0),
+    (49.5, 49.8, 48.5, 49.0, 0.0),
+    (48.8, 49.0, 47.5, 47.9, 0.0),
+];
+
+/// A Three Outside Down, see [`crate::CandleStream::is_three_outside_down`]
+pub const THREE_OUTSIDE_DOWN: [Ohlcv; 3] = [
+    (99.0, 100.5, 98.5, 100.0, 0.0),
+    (101.5, 102.0, 97.0, 98.5, 0.0),
+    (98.4, 98.6, 96.0, 96.5, 0.0),
+];
+
+/// An Upside Tasuki Gap, see [`crate::CandleStream::is_upside_tasuki_gap`]
+pub const UPSIDE_TASUKI_GAP: [Ohlcv; 3] = [
+    (100.0, 103.0, 99.5, 102.5, 0.0),
+    (104.0, 107.0, 103.5, 106.5, 0.0),
+    (105.5, 106.0, 103.1, 103.2, 0.0),
+];
+
+/// A Downside Tasuki Gap, see [`crate::CandleStream::is_downside_tasuki_gap`]
+pub const DOWNSIDE_TASUKI_GAP: [Ohlcv; 3] = [
+    (106.0, 106.5, 103.0, 103.5, 0.0),
+    (102.0, 102.5, 99.0, 99.5, 0.0),
+    (100.5, 102.9, 100.0, 102.8, 0.0),
+];
+
+/// An Upside-Gap Side-by-Side White Lines, see [`crate::CandleStream::is_upgap_side_by_side_white_lines`]
+pub const UPGAP_SIDE_BY_SIDE_WHITE_LINES: [Ohlcv; 3] = [
+    (100.0, 103.0, 99.5, 102.5, 0.0),
+    (104.0, 106.2, 103.6, 106.0, 0.0),
+    (104.05, 106.3, 103.8, 106.1, 0.0),
+];
+
+/// A Downside-Gap Side-by-Side White Lines, see [`crate::CandleStream::is_downgap_side_by_side_white_lines`]
+pub const DOWNGAP_SIDE_BY_SIDE_WHITE_LINES: [Ohlcv; 3] = [
+    (106.0, 106.5, 103.0, 103.5, 0.0),
+    (100.0, 102.4, 99.8, 102.0, 0.0),
+    (100.05, 102.5, 99.9, 102.1, 0.0),
+];
+
+/// A Stick Sandwich, see [`crate::CandleStream::is_stick_sandwich`]
+pub const STICK_SANDWICH: [Ohlcv; 3] = [
+    (104.0, 104.5, 99.5, 100.0, 0.0),
+    (100.5, 103.5, 100.2, 103.0, 0.0),
+    (103.2, 103.6, 99.8, 100.1, 0.0),
+];
+
+/// A Bullish Island Reversal, see [`crate::CandleStream::is_bullish_island_reversal`]
+pub const BULLISH_ISLAND_REVERSAL: [Ohlcv; 3] = [
+    (106.0, 106.5, 103.0, 103.5, 0.0),
+    (101.0, 102.0, 100.0, 101.5, 0.0),
+    (103.5, 104.8, 103.2, 104.5, 0.0),
+];
+
+/// A Bearish Island Reversal, see [`crate::CandleStream::is_bearish_island_reversal`]
+pub const BEARISH_ISLAND_REVERSAL: [Ohlcv; 3] = [
+    (100.0, 103.0, 99.5, 102.5, 0.0),
+    (104.5, 106.0, 104.0, 105.0, 0.0),
+    (102.5, 103.8, 101.2, 101.5, 0.0),
+];
+
+/// A Bullish Engulfing, see [`crate::CandleStream::is_bullish_engulfing`]
+pub const BULLISH_ENGULFING: [Ohlcv; 2] = [
+    (101.0, 102.0, 99.5, 100.5, 0.0),
+    (99.0, 103.0, 98.5, 102.5, 0.0),
+];
+
+/// A Bearish Engulfing, see [`crate::CandleStream::is_bearish_engulfing`]
+pub const BEARISH_ENGULFING: [Ohlcv; 2] = [
+    (99.0, 100.5, 98.5, 100.0, 0.0),
+    (101.5, 102.0, 97.0, 98.5, 0.0),
+];
+
+/// A Dark Cloud Cover, see [`crate::CandleStream::is_dark_cloud_cover`]
+pub const DARK_CLOUD_COVER: [Ohlcv; 2] = [
+    (100.0, 105.0, 99.5, 104.5, 0.0),
+    (105.5, 106.0, 102.0, 101.5, 0.0),
+];
+
+/// A Piercing Line, see [`crate::CandleStream::is_piercing_line`]
+pub const PIERCING_LINE: [Ohlcv; 2] = [
+    (104.5, 105.0, 99.5, 100.0, 0.0),
+    (99.0, 103.5, 98.5, 103.0, 0.0),
+];
+
+/// An In-Neck line, see [`crate::CandleStream::is_in_neck`]
+pub const IN_NECK: [Ohlcv; 2] = [
+    (104.5, 105.0, 99.5, 100.0, 0.0),
+    (99.0, 100.3, 98.5, 100.2, 0.0),
+];
+
+/// A Bullish Meeting Lines, see [`crate::CandleStream::is_bullish_meeting_lines`]
+pub const BULLISH_MEETING_LINES: [Ohlcv; 2] = [
+    (105.0, 105.3, 99.8, 100.0, 0.0),
+    (95.0, 100.1, 94.8, 99.9, 0.0),
+];
+
+/// A Bearish Meeting Lines, see [`crate::CandleStream::is_bearish_meeting_lines`]
+pub const BEARISH_MEETING_LINES: [Ohlcv; 2] = [
+    (100.0, 105.2, 99.7, 105.0, 0.0),
+    (110.0, 110.2, 104.9, 105.1, 0.0),
+];
+
+/// A Tweezer Top, see [`crate::CandleStream::is_tweezer_top`]
+pub const TWEEZER_TOP: [Ohlcv; 2] = [
+    (100.0, 105.0, 99.5, 104.5, 0.0),
+    (104.5, 105.1, 101.0, 101.5, 0.0),
+];
+
+/// A Tweezer Bottom, see [`crate::CandleStream::is_tweezer_bottom`]
+pub const TWEEZER_BOTTOM: [Ohlcv; 2] = [
+    (104.5, 105.0, 100.0, 100.5, 0.0),
+    (100.5, 103.5, 99.9, 103.0, 0.0),
+];
+
+/// A Bullish Harami, see [`crate::CandleStream::is_bullish_harami`]
+pub const BULLISH_HARAMI: [Ohlcv; 2] = [
+    (129.0, 130.0, 124.0, 125.0, 0.0),
+    (125.2, 127.0, 124.8, 126.5, 0.0),
+];
+
+/// A Bearish Harami, see [`crate::CandleStream::is_bearish_harami`]
+pub const BEARISH_HARAMI: [Ohlcv; 2] = [
+    (124.0, 129.0, 122.0, 127.0, 0.0),
+    (126.9, 129.7, 125.0, 124.8, 0.0),
+];
+
+/// A Bullish Doji Star, see [`crate::CandleStream::is_bullish_doji_star`]
+pub const BULLISH_DOJI_STAR: [Ohlcv; 2] =
+    [(52.0, 52.5, 48.0, 48.5, 0.0), (47.0, 47.5, 46.8, 47.0, 0.0)];
+
+/// A Bearish Doji Star, see [`crate::CandleStream::is_bearish_doji_star`]
+pub const BEARISH_DOJI_STAR: [Ohlcv; 2] =
+    [(48.0, 52.5, 47.8, 52.0, 0.0), (52.6, 53.2, 52.6, 52.6, 0.0)];
+
+/// A Bullish Star, see [`crate::CandleStream::is_bullish_star`]
+pub const BULLISH_STAR: [Ohlcv; 2] = [(52.0, 52.5, 48.0, 48.5, 0.0), (47.6, 48.0, 46.9, 47.2, 0.0)];
+
+/// A Bearish Star, see [`crate::CandleStream::is_bearish_star`]
+pub const BEARISH_STAR: [Ohlcv; 2] = [
+    (100.0, 106.0, 99.5, 105.5, 0.0),
+    (106.2, 107.0, 105.8, 106.8, 0.0),
+];
+
+/// A Bullish Near Star, see [`crate::CandleStream::is_bullish_near_star`]
+pub const BULLISH_NEAR_STAR: [Ohlcv; 2] =
+    [(52.0, 52.5, 48.0, 48.5, 0.0), (48.8, 49.0, 47.9, 48.4, 0.0)];
+
+/// A Bearish Near Star, see [`crate::CandleStream::is_bearish_near_star`]
+pub const BEARISH_NEAR_STAR: [Ohlcv; 2] = [
+    (100.0, 106.0, 99.5, 105.5, 0.0),
+    (105.2, 106.3, 105.0, 105.7, 0.0),
+];
+
+/// An Upthrust, see [`crate::CandleStream::is_upthrust`]
+pub const UPTHRUST: [Ohlcv; 3] = [
+    (100.0, 102.0, 99.5, 101.5, 100.0),
+    (101.5, 102.5, 100.5, 101.0, 100.0),
+    (101.0, 104.0, 100.2, 100.8, 250.0),
+];
+
+/// A Spring, see [`crate::CandleStream::is_spring`]
+pub const SPRING: [Ohlcv; 3] = [
+    (101.0, 101.5, 99.0, 99.5, 100.0),
+    (99.5, 100.5, 99.2, 100.0, 100.0),
+    (100.0, 100.6, 98.0, 100.4, 80.0),
+];
+
+/// A No Demand bar, see [`crate::CandleStream::is_no_demand`]
+pub const NO_DEMAND: [Ohlcv; 3] = [
+    (100.0, 102.0, 99.5, 101.5, 200.0),
+    (101.5, 103.0, 101.0, 102.5, 180.0),
+    (102.5, 103.4, 102.4, 102.8, 90.0),
+];
+
+/// A No Supply bar, see [`crate::CandleStream::is_no_supply`]
+pub const NO_SUPPLY: [Ohlcv; 3] = [
+    (102.0, 102.5, 100.0, 100.5, 200.0),
+    (100.5, 101.0, 99.0, 99.5, 180.0),
+    (99.5, 99.6, 98.8, 99.3, 90.0),
+];
+
+/// Returns the canonical candle sequence of the given multi-candle pattern, ending with the
+/// candle completing it
+pub const fn pattern(pattern: Pattern) -> &'static [Ohlcv] {
+    match pattern {
+        Pattern::MorningStarDoji => &MORNING_STAR_DOJI,
+        Pattern::BullishAbandonedBaby => &BULLISH_ABANDONED_BABY,
+        Pattern::BullishTriStar => &BULLISH_TRI_STAR,
+        Pattern::BearishTriStar => &BEARISH_TRI_STAR,
+        Pattern::RisingThreeMethods => &RISING_THREE_METHODS,
+        Pattern::FallingThreeMethods => &FALLING_THREE_METHODS,
+        Pattern::MatHold => &MAT_HOLD,
+        Pattern::BullishBreakaway => &BULLISH_BREAKAWAY,
+        Pattern::EveningStarDoji => &EVENING_STAR_DOJI,
+        Pattern::MorningStar => &MORNING_STAR,
+        Pattern::EveningStar => &EVENING_STAR,
+        Pattern::ThreeWhiteSoldiers => &THREE_WHITE_SOLDIERS,
+        Pattern::ThreeBlackCrows => &THREE_BLACK_CROWS,
+        Pattern::ThreeInsideUp => &THREE_INSIDE_UP,
+        Pattern::ThreeInsideDown => &THREE_INSIDE_DOWN,
+        Pattern::ThreeOutsideDown => &THREE_OUTSIDE_DOWN,
+        Pattern::UpsideTasukiGap => &UPSIDE_TASUKI_GAP,
+        Pattern::DownsideTasukiGap => &DOWNSIDE_TASUKI_GAP,
+        Pattern::UpgapSideBySideWhiteLines => &UPGAP_SIDE_BY_SIDE_WHITE_LINES,
+        Pattern::DowngapSideBySideWhiteLines => &DOWNGAP_SIDE_BY_SIDE_WHITE_LINES,
+        Pattern::StickSandwich => &STICK_SANDWICH,
+        Pattern::BullishIslandReversal => &BULLISH_ISLAND_REVERSAL,
+        Pattern::BearishIslandReversal => &BEARISH_ISLAND_REVERSAL,
+        Pattern::BullishEngulfing => &BULLISH_ENGULFING,
+        Pattern::BearishEngulfing => &BEARISH_ENGULFING,
+        Pattern::DarkCloudCover => &DARK_CLOUD_COVER,
+        Pattern::PiercingLine => &PIERCING_LINE,
+        Pattern::InNeck => &IN_NECK,
+        Pattern::BullishMeetingLines => &BULLISH_MEETING_LINES,
+        Pattern::BearishMeetingLines => &BEARISH_MEETING_LINES,
+        Pattern::TweezerTop => &TWEEZER_TOP,
+        Pattern::TweezerBottom => &TWEEZER_BOTTOM,
+        Pattern::BullishHarami => &BULLISH_HARAMI,
+        Pattern::BearishHarami => &BEARISH_HARAMI,
+        Pattern::BullishDojiStar => &BULLISH_DOJI_STAR,
+        Pattern::BearishDojiStar => &BEARISH_DOJI_STAR,
+        Pattern::BullishStar => &BULLISH_STAR,
+        Pattern::BearishStar => &BEARISH_STAR,
+        Pattern::BullishNearStar => &BULLISH_NEAR_STAR,
+        Pattern::BearishNearStar => &BEARISH_NEAR_STAR,
+        Pattern::Upthrust => &UPTHRUST,
+        Pattern::Spring => &SPRING,
+        Pattern::NoDemand => &NO_DEMAND,
+        Pattern::NoSupply => &NO_SUPPLY,
+    }
+}
+
+/// Returns a canonical candle classified as the given [`CandleType`]
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{fixtures, CandleStick, CandleType};
+/// assert_eq!(fixtures::candle(CandleType::Hammer).classify(), CandleType::Hammer);
+/// ```
+pub const fn candle(kind: CandleType) -> Ohlcv {
+    match kind {
+        CandleType::DragonflyDoji => (100.0, 100.5, 90.0, 100.1, 0.0),
+        CandleType::GravestoneDoji => (100.0, 110.0, 99.5, 100.1, 0.0),
+        CandleType::LongLeggedDoji => (100.0, 110.0, 90.0, 100.2, 0.0),
+        CandleType::Doji => (100.0, 100.3, 99.0, 100.05, 0.0),
+        CandleType::BullishMarubozu => (100.0, 110.0, 99.0, 109.0, 0.0),
+        CandleType::BearishMarubozu => (110.0, 111.0, 99.0, 100.0, 0.0),
+        CandleType::Hammer => (100.0, 101.0, 95.0, 100.8, 0.0),
+        CandleType::InvertedHammer => (100.0, 104.0, 99.8, 100.5, 0.0),
+        CandleType::SpinningTop => (100.0, 105.0, 95.0, 101.5, 0.0),
+        CandleType::Bullish => (100.0, 106.0, 98.0, 104.0, 0.0),
+        CandleType::Bearish => (104.0, 106.0, 98.0, 100.0, 0.0),
+        CandleType::Neutral => (100.0, 100.0, 100.0, 100.0, 0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CandleStick, CandleStream};
+
+    #[test]
+    fn test_every_pattern_fixture_is_detected() {
+        for &p in Pattern::ALL {
+            let candles = pattern(p);
+            assert_eq!(candles.len(), p.candle_count(), "{p} has the wrong length");
+
+            let mut series = CandleStream::new();
+            for candle in candles {
+                series.push(candle);
+            }
+            assert!(series.is_pattern(p), "{p} fixture is not detected");
+        }
+    }
+
+    #[test]
+    fn test_every_pattern_reads_candle_count_candles() {
+        for &p in Pattern::ALL {
+            let mut series = CandleStream::new();
+            for candle in &pattern(p)[1..] {
+                series.push(candle);
+            }
+            assert!(!series.is_pattern(p), "{p} is detected with fewer candles");
+        }
+    }
+
+    #[test]
+    fn test_every_candle_fixture_is_classified() {
+        let kinds = [
+            CandleType::DragonflyDoji,
+            CandleType::GravestoneDoji,
+            CandleType::LongLeggedDoji,
+            CandleType::Doji,
+            CandleType::BullishMarubozu,
+            CandleType::BearishMarubozu,
+            CandleType::Hammer,
+            CandleType::InvertedHammer,
+            CandleType::SpinningTop,
+            CandleType::Bullish,
+            CandleType::Bearish,
+            CandleType::Neutral,
+        ];
+
+        for kind in kinds {
+            assert_eq!(candle(kind).classify(), kind);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{CandleStick, CandleStream, Direction, Trendline};
+
+/// The shape of the consolidation of a [`Flag`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlagKind {
+    /// Parallel channel sloping against the impulse
+    Flag,
+    /// Converging range, lower highs and higher lows
+    Pennant,
+}
+
+/// A flag or pennant continuation pattern, as returned by [`CandleStream::flag`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Flag {
+    /// The shape of the consolidation
+    pub kind: FlagKind,
+    /// The direction of the impulse, and of the expected breakout
+    pub direction: Direction,
+    /// The height of the impulse leg, or pole
+    pub pole: f64,
+    /// The line fitted to the highs of the consolidation
+    pub upper: Trendline,
+    /// The line fitted to the lows of the consolidation
+    pub lower: Trendline,
+    /// True if the latest candle closed beyond the consolidation in the impulse direction
+    pub broken_out: bool,
+    /// The measured move target: the pole height projected from the breakout line
+    pub target: f64,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Flag or a Pennant, continuation patterns made of a strong impulse leg of
+    /// `pole_bars` candles followed by a tight consolidation of `flag_bars` candles, before
+    /// the latest candle.
+    ///
+    /// The pole must be efficient, its open to close move covering at least 60% of its range,
+    /// and the consolidation must retrace at most half of it. Lines are fitted to the highs
+    /// and lows of the consolidation: both sloping against the impulse make a flag, lower
+    /// highs with higher lows make a pennant. The latest candle is checked for the breakout.
+    ///
+    /// **Trading Significance**:
+    /// - Brief pause of a strong trend while early participants take profits
+    /// - The breakout in the impulse direction resumes the trend
+    /// - The pole height projected from the breakout gives the classic measured target
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction, FlagKind};
+    /// let candles = [
+    ///     (100.0, 102.5, 99.5, 102.0, 0.0),
+    ///     (102.0, 105.0, 101.5, 104.5, 0.0),
+    ///     (104.5, 108.0, 104.0, 107.5, 0.0),
+    ///     (107.5, 110.5, 107.0, 110.0, 0.0),
+    ///     (109.5, 110.0, 108.0, 108.5, 0.0),
+    ///     (108.5, 109.5, 107.5, 108.0, 0.0),
+    ///     (108.0, 109.0, 107.0, 107.5, 0.0),
+    ///     (107.5, 108.5, 106.5, 107.0, 0.0),
+    ///     (107.0, 108.0, 106.0, 106.5, 0.0),
+    ///     (106.5, 110.0, 106.4, 109.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let flag = series.flag(4, 5).unwrap();
+    /// assert_eq!((flag.kind, flag.direction), (FlagKind::Flag, Direction::Bullish));
+    /// assert!(flag.broken_out);
+    /// assert_eq!(flag.target, 117.5);
+    /// ```
+    pub fn flag(&self, pole_bars: usize, flag_bars: usize) -> Option<Flag> {
+        if pole_bars == 0 {
+            return None;
+        }
+
+        let pole = || (flag_bars + 1..=flag_bars + pole_bars).filter_map(|n| self.prev(n));
+        let consolidation = || (1..=flag_bars).filter_map(|n| self.prev(n));
+
+        let start = self.prev(flag_bars + pole_bars)?.open();
+        let end = self.prev(flag_bars + 1)?.close();
+        let pole_high = pole().map(T::high).reduce(f64::max)?;
+        let pole_low = pole().map(T::low).reduce(f64::min)?;
+        let height = (end - start).abs();
+        if height < 0.6 * (pole_high - pole_low) {
+            return None;
+        }
+
+        let flag_high = consolidation().map(T::high).reduce(f64::max)?;
+        let flag_low = consolidation().map(T::low).reduce(f64::min)?;
+        let (direction, retracement) = match end > start {
+            true => (Direction::Bullish, pole_high - flag_low),
+            false => (Direction::Bearish, flag_high - pole_low),
+        };
+        if retracement > 0.5 * height {
+            return None;
+        }
+
+        let upper = Trendline::fit((1..=flag_bars).zip(consolidation().map(T::high)))?;
+        let lower = Trendline::fit((1..=flag_bars).zip(consolidation().map(T::low)))?;
+        let kind = match (direction, upper.slope, lower.slope) {
+            (_, upper, lower) if upper < 0.0 && lower > 0.0 => FlagKind::Pennant,
+            (Direction::Bullish, upper, lower) if upper <= 0.0 && lower <= 0.0 => FlagKind::Flag,
+            (Direction::Bearish, upper, lower) if upper >= 0.0 && lower >= 0.0 => FlagKind::Flag,
+            _ => return None,
+        };
+
+        let close = self.get()?.close();
+        let (broken_out, target) = match direction {
+            Direction::Bullish => (close > upper.level, upper.level + height),
+            Direction::Bearish => (close < lower.level, lower.level - height),
+        };
+
+        Some(Flag {
+            kind,
+            direction,
+            pole: height,
+            upper,
+            lower,
+            broken_out,
+            target,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn inverted(candle: &Ohlcv) -> Ohlcv {
+        let (o, h, l, c, v) = *candle;
+        (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v)
+    }
+
+    const POLE: [Ohlcv; 4] = [
+        (100.0, 102.5, 99.5, 102.0, 0.0),
+        (102.0, 105.0, 101.5, 104.5, 0.0),
+        (104.5, 108.0, 104.0, 107.5, 0.0),
+        (107.5, 110.5, 107.0, 110.0, 0.0),
+    ];
+
+    const PENNANT: [Ohlcv; 5] = [
+        (109.5, 110.0, 106.0, 107.0, 0.0),
+        (107.0, 109.6, 106.2, 108.5, 0.0),
+        (108.5, 109.2, 106.4, 107.0, 0.0),
+        (107.0, 108.8, 106.6, 108.0, 0.0),
+        (108.0, 108.4, 106.8, 107.5, 0.0),
+    ];
+
+    fn series<'s>(candles: impl Iterator<Item = &'s Ohlcv>) -> CandleStream<'s, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_bullish_pennant() {
+        let inside = (107.5, 107.9, 107.2, 107.5, 0.0);
+        let stream = series(POLE.iter().chain(PENNANT.iter()).chain([&inside]));
+
+        let pennant = stream.flag(4, 5);
+        assert!(pennant.is_some_and(|p| p.kind == FlagKind::Pennant && !p.broken_out));
+        assert!(pennant.is_some_and(|p| p.direction == Direction::Bullish && p.pole == 10.0));
+    }
+
+    #[test]
+    fn test_bearish_pennant_breakdown() {
+        let bars = POLE.iter().chain(PENNANT.iter()).map(inverted);
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 10];
+        for (slot, candle) in candles.iter_mut().zip(bars) {
+            *slot = candle;
+        }
+        candles[9] = (92.5, 92.8, 89.5, 90.0, 0.0);
+
+        let pennant = series(candles.iter()).flag(4, 5);
+        assert!(pennant.is_some_and(|p| p.direction == Direction::Bearish && p.broken_out));
+        assert!(pennant.is_some_and(|p| p.target < 90.0));
+    }
+
+    #[test]
+    fn test_deep_pullback_is_not_a_flag() {
+        let deep = [
+            (110.0, 110.2, 106.0, 106.5, 0.0),
+            (106.5, 107.0, 104.0, 104.5, 0.0),
+            (104.5, 105.0, 102.0, 102.5, 0.0),
+        ];
+        let breakout = (102.5, 108.0, 102.0, 107.5, 0.0);
+        let stream = series(POLE.iter().chain(deep.iter()).chain([&breakout]));
+
+        assert_eq!(stream.flag(4, 3), None);
+        assert_eq!(stream.flag(0, 3), None);
+    }
+}
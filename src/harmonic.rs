@@ -0,0 +1,276 @@
+use crate::{CandleStick, CandleStream, Direction, Swing, SwingKind};
+
+/// The family of a [`Harmonic`] pattern, defined by its Fibonacci ratios
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HarmonicKind {
+    /// AB retraces 0.618 of XA, D completes at 0.786 of XA
+    Gartley,
+    /// AB retraces 0.382 to 0.5 of XA, D completes at 0.886 of XA
+    Bat,
+    /// AB retraces 0.786 of XA, D extends 1.27 to 1.618 of XA beyond X
+    Butterfly,
+}
+
+impl HarmonicKind {
+    /// All harmonic kinds, in detection order
+    pub const ALL: [HarmonicKind; 3] = [Self::Gartley, Self::Bat, Self::Butterfly];
+
+    // Ratio ranges of the AB/XA, BC/AB, CD/BC and XD/XA legs
+    fn ratios(self) -> [(f64, f64); 4] {
+        match self {
+            Self::Gartley => [
+                (0.618, 0.618),
+                (0.382, 0.886),
+                (1.272, 1.618),
+                (0.786, 0.786),
+            ],
+            Self::Bat => [(0.382, 0.5), (0.382, 0.886), (1.618, 2.618), (0.886, 0.886)],
+            Self::Butterfly => [(0.786, 0.786), (0.382, 0.886), (1.618, 2.24), (1.27, 1.618)],
+        }
+    }
+}
+
+/// A harmonic XABCD pattern awaiting its D point, as returned by [`CandleStream::harmonic`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Harmonic {
+    /// The family of the pattern
+    pub kind: HarmonicKind,
+    /// The direction of the expected reversal at D
+    pub direction: Direction,
+    /// The swing starting the pattern
+    pub x: Swing,
+    /// The swing ending the XA leg
+    pub a: Swing,
+    /// The swing ending the AB retracement
+    pub b: Swing,
+    /// The swing ending the BC leg, where the CD leg starts
+    pub c: Swing,
+    /// Upper bound of the potential reversal zone
+    pub prz_upper: f64,
+    /// Lower bound of the potential reversal zone
+    pub prz_lower: f64,
+    /// True if the latest candle traded into the potential reversal zone
+    pub in_zone: bool,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Gartley, Bat or Butterfly harmonic pattern from the latest four
+    /// alternating swing points of the given `strength`, taken as X, A, B and C.
+    ///
+    /// Each leg must match the Fibonacci ratios of the [`HarmonicKind`], widened by
+    /// `tolerance`, a fraction of the ratio (e.g. `0.05` for 5%). The potential reversal zone
+    /// is where the XA retracement and the BC projection of D agree. The pattern is dropped
+    /// once a candle after C trades beyond the zone. Consecutive swings of the same side are
+    /// merged into the most extreme one.
+    ///
+    /// **Trading Significance**:
+    /// - Bullish patterns complete at a low, bearish ones at a high
+    /// - The reversal zone gives a precise, low-risk entry with a stop just beyond it
+    /// - Usually confirmed by a reversal candle pattern within the zone
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction, HarmonicKind};
+    /// let candles = [
+    ///     (101.0, 102.0, 100.5, 101.5, 0.0),
+    ///     (101.0, 101.5, 100.0, 101.0, 0.0),
+    ///     (101.0, 105.0, 101.0, 104.5, 0.0),
+    ///     (104.5, 110.0, 104.0, 109.0, 0.0),
+    ///     (108.0, 108.0, 105.0, 105.5, 0.0),
+    ///     (105.5, 106.0, 103.82, 104.0, 0.0),
+    ///     (105.0, 107.0, 104.5, 106.5, 0.0),
+    ///     (106.5, 108.0, 105.0, 107.0, 0.0),
+    ///     (107.0, 107.0, 104.0, 104.5, 0.0),
+    ///     (104.5, 105.0, 103.0, 103.5, 0.0),
+    ///     (103.5, 103.5, 102.2, 103.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let pattern = series.harmonic(1, 0.05).unwrap();
+    /// assert_eq!((pattern.kind, pattern.direction), (HarmonicKind::Gartley, Direction::Bullish));
+    /// assert!(pattern.in_zone);
+    /// ```
+    pub fn harmonic(&self, strength: usize, tolerance: f64) -> Option<Harmonic> {
+        let [c, b, a, x] = self.alternating_swings(strength)?;
+        let (direction, sign) = match x.kind {
+            SwingKind::Low => (Direction::Bullish, 1.0),
+            SwingKind::High => (Direction::Bearish, -1.0),
+        };
+
+        let xa = sign * (a.price - x.price);
+        let ab = sign * (a.price - b.price);
+        let bc = sign * (c.price - b.price);
+        if xa <= 0.0 || ab <= 0.0 || bc <= 0.0 {
+            return None;
+        }
+
+        let widen = |(low, high): (f64, f64)| (low * (1.0 - tolerance), high * (1.0 + tolerance));
+        let within = |ratio: f64, range: (f64, f64)| {
+            let (low, high) = widen(range);
+            low <= ratio && ratio <= high
+        };
+
+        HarmonicKind::ALL.into_iter().find_map(|kind| {
+            let [ab_xa, bc_ab, cd_bc, xd_xa] = kind.ratios();
+            if !within(ab / xa, ab_xa) || !within(bc / ab, bc_ab) {
+                return None;
+            }
+
+            // D projected from A along XA, and from C along BC
+            let span = |origin: f64, leg: f64, (near, far): (f64, f64)| {
+                let (near, far) = (origin - sign * near * leg, origin - sign * far * leg);
+                (near.min(far), near.max(far))
+            };
+            let (xd_lower, xd_upper) = span(a.price, xa, widen(xd_xa));
+            let (cd_lower, cd_upper) = span(c.price, bc, widen(cd_bc));
+            let (prz_lower, prz_upper) = (xd_lower.max(cd_lower), xd_upper.min(cd_upper));
+            if prz_lower > prz_upper {
+                return None;
+            }
+
+            let mut since = (0..c.bars_ago).filter_map(|n| self.prev(n));
+            let failed = match direction {
+                Direction::Bullish => since.any(|candle| candle.low() < prz_lower),
+                Direction::Bearish => since.any(|candle| candle.high() > prz_upper),
+            };
+            if failed {
+                return None;
+            }
+
+            let latest = self.get()?;
+            Some(Harmonic {
+                kind,
+                direction,
+                x,
+                a,
+                b,
+                c,
+                prz_upper,
+                prz_lower,
+                in_zone: latest.low() <= prz_upper && latest.high() >= prz_lower,
+            })
+        })
+    }
+
+    // Returns the latest four alternating swings, newest first, merging consecutive swings of
+    // the same side into the most extreme one
+    fn alternating_swings(&self, strength: usize) -> Option<[Swing; 4]> {
+        let mut pivots: [Option<Swing>; 4] = [None; 4];
+        let mut len = 0usize;
+
+        for swing in self.swings(strength) {
+            match len.checked_sub(1).and_then(|last| pivots[last]) {
+                Some(last) if last.kind == swing.kind => {
+                    let beyond = match swing.kind {
+                        SwingKind::High => swing.price > last.price,
+                        SwingKind::Low => swing.price < last.price,
+                    };
+                    if beyond {
+                        pivots[len - 1] = Some(swing);
+                    }
+                }
+                _ if len == pivots.len() => break,
+                _ => {
+                    pivots[len] = Some(swing);
+                    len += 1;
+                }
+            }
+        }
+
+        let [c, b, a, x] = pivots;
+        Some([c?, b?, a?, x?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    fn inverted(candle: &Ohlcv) -> Ohlcv {
+        let (o, h, l, c, v) = *candle;
+        (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v)
+    }
+
+    // X at 100, A at 110, B at 103.82 and C at 108, then a CD leg down
+    const GARTLEY: [(f64, f64); 10] = [
+        (102.0, 100.5),
+        (101.5, 100.0),
+        (105.0, 101.0),
+        (110.0, 104.0),
+        (108.0, 105.0),
+        (106.0, 103.82),
+        (107.0, 104.5),
+        (108.0, 105.0),
+        (107.0, 104.0),
+        (105.0, 103.0),
+    ];
+
+    fn build(shape: [(f64, f64); 10], latest: (f64, f64)) -> [Ohlcv; 11] {
+        let mut candles = [candle(latest); 11];
+        for (slot, bar) in candles.iter_mut().zip(shape) {
+            *slot = candle(bar);
+        }
+        candles
+    }
+
+    fn harmonic(candles: &[Ohlcv]) -> Option<Harmonic> {
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.harmonic(1, 0.05)
+    }
+
+    #[test]
+    fn test_bullish_gartley() {
+        let pattern = harmonic(&build(GARTLEY, (103.5, 102.2)));
+
+        assert!(pattern.is_some_and(|p| p.kind == HarmonicKind::Gartley && p.in_zone));
+        assert!(pattern.is_some_and(
+            |p| (p.x.price, p.a.price, p.b.price, p.c.price) == (100.0, 110.0, 103.82, 108.0)
+        ));
+        assert!(pattern.is_some_and(|p| p.prz_lower > 101.7 && p.prz_upper < 102.6));
+
+        let approaching = harmonic(&build(GARTLEY, (104.0, 102.9)));
+        assert!(approaching.is_some_and(|p| !p.in_zone));
+    }
+
+    #[test]
+    fn test_bearish_gartley() {
+        let candles = build(GARTLEY, (103.5, 102.2)).map(|c| inverted(&c));
+        let pattern = harmonic(&candles);
+
+        assert!(pattern.is_some_and(|p| p.direction == Direction::Bearish && p.in_zone));
+        assert!(pattern.is_some_and(|p| p.prz_lower > 97.4 && p.prz_upper < 98.3));
+    }
+
+    #[test]
+    fn test_bat() {
+        let mut deep = GARTLEY;
+        deep[1] = (101.5, 95.0);
+        let pattern = harmonic(&build(deep, (103.5, 102.2)));
+
+        assert!(pattern.is_some_and(|p| p.kind == HarmonicKind::Bat && !p.in_zone));
+        assert!(pattern.is_some_and(|p| p.prz_lower > 96.4 && p.prz_upper < 97.4));
+    }
+
+    #[test]
+    fn test_harmonic_rejections() {
+        let mut shallow = GARTLEY;
+        shallow[0] = (107.0, 105.5);
+        shallow[1] = (106.0, 105.0);
+        shallow[2] = (108.0, 105.5);
+
+        assert_eq!(harmonic(&build(shallow, (103.5, 102.2))), None);
+        assert_eq!(harmonic(&build(GARTLEY, (103.0, 101.0))), None);
+    }
+}
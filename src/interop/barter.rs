@@ -0,0 +1,124 @@
+use barter_data::subscription::candle::Candle;
+
+use crate::{CandleStick, Timestamped};
+
+/// barter-data candles implement [`CandleStick`] with the `barter` feature, so market events
+/// of the barter ecosystem can be piped straight into a [`crate::CandleStream`].
+///
+/// # Example
+/// ```
+/// use barter_data::subscription::candle::Candle;
+/// use candlestick_rs::CandleStick;
+///
+/// fn on_candle(candle: &Candle) -> bool {
+///     candle.is_hammer()
+/// }
+/// ```
+impl CandleStick for Candle {
+    fn open(&self) -> f64 {
+        self.open
+    }
+
+    fn high(&self) -> f64 {
+        self.high
+    }
+
+    fn low(&self) -> f64 {
+        self.low
+    }
+
+    fn close(&self) -> f64 {
+        self.close
+    }
+
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+/// barter-data only provides the close time of a candle: its timestamp is the close time in
+/// milliseconds, and its open time is one interval earlier.
+impl Timestamped for Candle {
+    fn timestamp(&self) -> i64 {
+        self.close_time.timestamp_millis()
+    }
+
+    fn open_time(&self, interval: i64) -> i64 {
+        self.timestamp().saturating_sub(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, CandleStream, OpeningRangeBreakout, Pattern};
+    use chrono::DateTime;
+
+    fn barter(close_time: i64, (open, high, low, close, volume): fixtures::Ohlcv) -> Candle {
+        Candle {
+            close_time: DateTime::from_timestamp_millis(close_time).unwrap_or_default(),
+            open,
+            high,
+            low,
+            close,
+            volume,
+            trade_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        let candle = barter(1_700_000_060_000, (100.0, 105.0, 99.0, 104.0, 1_500.0));
+
+        assert_eq!(
+            (
+                candle.open(),
+                candle.high(),
+                candle.low(),
+                candle.close(),
+                candle.volume()
+            ),
+            (100.0, 105.0, 99.0, 104.0, 1_500.0)
+        );
+        assert_eq!(candle.timestamp(), 1_700_000_060_000);
+        assert_eq!(candle.open_time(60_000), 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_detects_patterns() {
+        let candles: [Candle; 3] =
+            core::array::from_fn(|i| barter(60_000 * (i as i64 + 1), fixtures::MORNING_STAR[i]));
+
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        assert!(series.is_pattern(Pattern::MorningStar));
+        assert!(series.missing_bars(60_000, &crate::AlwaysOpen).is_none());
+    }
+
+    #[test]
+    fn test_bars_judged_by_open_time() {
+        // the session opens at 600_000: the bar closing then opened before it
+        let opens_at = |ts: i64| ts >= 600_000;
+        let candles = [
+            barter(60_000, fixtures::MORNING_STAR[0]),
+            barter(720_000, fixtures::MORNING_STAR[1]),
+        ];
+
+        let mut series = CandleStream::new();
+        let hole = series
+            .push(&candles[0])
+            .push(&candles[1])
+            .missing_bars(60_000, &opens_at);
+        assert_eq!(
+            hole.map(|h| (h.after, h.before, h.missing)),
+            Some((0, 660_000, 1))
+        );
+
+        let mut tracker = OpeningRangeBreakout::new(1, 60_000);
+        tracker.push(&candles[0]);
+        assert_eq!(tracker.range().map(|r| r.start), Some(0));
+    }
+}
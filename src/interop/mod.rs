@@ -0,0 +1,10 @@
+// Adapters implementing CandleStick for the candle types of other crates, each behind the
+// feature named after the crate
+
+#[cfg(feature = "barter")]
+mod barter;
+
+#[cfg(feature = "yata")]
+mod yata;
+#[cfg(feature = "yata")]
+pub use self::yata::Yata;
@@ -0,0 +1,96 @@
+use ::yata::core::OHLCV;
+
+use crate::CandleStick;
+
+/// The `Yata` newtype implements [`CandleStick`] for any type implementing yata's `OHLCV`
+/// trait, so candles of a codebase standardized on yata can be pushed into a
+/// [`crate::CandleStream`] as they are.
+///
+/// A blanket implementation over `OHLCV` would conflict with the existing implementations
+/// of [`CandleStick`], hence the wrapper. Requires the `yata` feature.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStream, Yata};
+/// use yata::core::Candle;
+///
+/// let prev = Yata(Candle { open: 101.0, high: 102.0, low: 99.5, close: 100.5, volume: 0.0 });
+/// let curr = Yata(Candle { open: 99.0, high: 103.0, low: 98.5, close: 102.5, volume: 0.0 });
+/// let mut series = CandleStream::new();
+/// assert!(series.push(&prev).push(&curr).is_bullish_engulfing());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Yata<T>(pub T);
+
+// yata values are `f32` with its `value_type_f32` feature, hence the conversions
+#[allow(clippy::useless_conversion)]
+impl<T: OHLCV> CandleStick for Yata<T> {
+    fn open(&self) -> f64 {
+        self.0.open().into()
+    }
+
+    fn high(&self) -> f64 {
+        self.0.high().into()
+    }
+
+    fn low(&self) -> f64 {
+        self.0.low().into()
+    }
+
+    fn close(&self) -> f64 {
+        self.0.close().into()
+    }
+
+    fn volume(&self) -> f64 {
+        self.0.volume().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, CandleStream, Pattern};
+    use ::yata::core::Candle;
+
+    #[test]
+    fn test_fields() {
+        let candle = Yata(Candle {
+            open: 100.0,
+            high: 105.0,
+            low: 99.0,
+            close: 104.0,
+            volume: 1_500.0,
+        });
+
+        assert_eq!(
+            (
+                candle.open(),
+                candle.high(),
+                candle.low(),
+                candle.close(),
+                candle.volume()
+            ),
+            (100.0, 105.0, 99.0, 104.0, 1_500.0)
+        );
+    }
+
+    #[test]
+    fn test_detects_patterns() {
+        let candles = fixtures::MORNING_STAR.map(|(open, high, low, close, volume)| {
+            Yata(Candle {
+                open,
+                high,
+                low,
+                close,
+                volume,
+            })
+        });
+
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        assert!(series.is_pattern(Pattern::MorningStar));
+    }
+}
@@ -0,0 +1,205 @@
+use core::iter::once;
+
+use crate::CandleStick;
+
+type Ohlcv = (f64, f64, f64, f64, f64);
+
+/// A plausible price path inside a candle, for simulating what happened within the bar:
+/// whether a stop or a confirmation level was reached first, or how lower timeframe candles
+/// might have looked.
+///
+/// Without intrabar data the path follows the standard assumption: open, low, high, close
+/// for bullish and flat candles, and open, high, low, close for bearish ones. Known intrabar
+/// prices, like the last trades sampled at regular intervals, refine the path: it goes
+/// through them in order, and reaches the high and low of the candle next to the closest
+/// sampled price when they weren't sampled.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::IntrabarPath;
+/// let candle = (100.0, 104.0, 98.0, 103.0, 400.0);
+/// let path = IntrabarPath::new(&candle);
+///
+/// // the low comes first on a bullish candle, so the stop is hit before the target
+/// assert_eq!(path.first_touch(&[104.0, 98.5]), Some(1));
+///
+/// let [first, second] = path.split::<2>();
+/// assert_eq!(first, (100.0, 100.5, 98.0, 100.5, 200.0));
+/// assert_eq!(second, (100.5, 104.0, 100.5, 103.0, 200.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntrabarPath<'p> {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    points: &'p [f64],
+}
+
+impl<'p> IntrabarPath<'p> {
+    /// Returns the path of the candle under the standard open, low, high, close assumption,
+    /// reversed for bearish candles
+    pub fn new<T: CandleStick>(candle: &T) -> Self {
+        Self::with_points(candle, &[])
+    }
+
+    /// Returns the path of the candle through the given intrabar prices, in time order
+    pub fn with_points<T: CandleStick>(candle: &T, points: &'p [f64]) -> Self {
+        Self {
+            open: candle.open(),
+            high: candle.high(),
+            low: candle.low(),
+            close: candle.close(),
+            volume: candle.volume(),
+            points,
+        }
+    }
+
+    /// Returns the prices visited by the path in order, from the open to the close
+    pub fn waypoints(&self) -> impl Iterator<Item = f64> + '_ {
+        let base = || {
+            once(self.open)
+                .chain(self.points.iter().copied())
+                .chain(once(self.close))
+        };
+        let last = self.points.len() + 1;
+        let top = base().fold(f64::NEG_INFINITY, f64::max);
+        let bottom = base().fold(f64::INFINITY, f64::min);
+        let top_at = base().position(|p| p == top);
+        let bottom_at = base().position(|p| p == bottom);
+        let high = (self.high > top).then_some(self.high);
+        let low = (self.low < bottom).then_some(self.low);
+
+        // unsampled extremes are reached right after the closest price, or before the close
+        base().enumerate().flat_map(move |(i, price)| {
+            let high = high.filter(|_| top_at == Some(i));
+            let low = low.filter(|_| bottom_at == Some(i));
+            let extremes = match self.close >= self.open {
+                true => [low, high],
+                false => [high, low],
+            };
+            let (before, after) = match i == last {
+                true => (extremes, [None; 2]),
+                false => ([None; 2], extremes),
+            };
+            before
+                .into_iter()
+                .flatten()
+                .chain(once(price))
+                .chain(after.into_iter().flatten())
+        })
+    }
+
+    /// Returns the index of the first level reached by the path, or `None` if the candle
+    /// reached none of them.
+    ///
+    /// Levels reached within the same leg of the path are ordered by their distance from the
+    /// start of the leg.
+    pub fn first_touch(&self, levels: &[f64]) -> Option<usize> {
+        self.legs().find_map(|(from, to)| {
+            levels
+                .iter()
+                .enumerate()
+                .filter(|(_, level)| from.min(to) <= **level && **level <= from.max(to))
+                .min_by(|(_, a), (_, b)| (*a - from).abs().total_cmp(&(*b - from).abs()))
+                .map(|(idx, _)| idx)
+        })
+    }
+
+    /// Splits the candle into `N` consecutive sub-candles covering equal distances along the
+    /// path, sharing the volume evenly.
+    ///
+    /// The sub-candles aggregate back to the candle: the first opens at its open, the last
+    /// closes at its close, and their extremes reach its high and low.
+    pub fn split<const N: usize>(&self) -> [Ohlcv; N] {
+        let length = self.legs().map(|(from, to)| (to - from).abs()).sum::<f64>();
+        let volume = self.volume / N as f64;
+
+        core::array::from_fn(|k| {
+            let start = length * k as f64 / N as f64;
+            let end = length * (k + 1) as f64 / N as f64;
+            let (open, close) = (self.price_at(start), self.price_at(end));
+            let (high, low) = self
+                .visits()
+                .filter(|(distance, _)| start < *distance && *distance < end)
+                .fold((open.max(close), open.min(close)), |(high, low), (_, p)| {
+                    (high.max(p), low.min(p))
+                });
+
+            (open, high, low, close, volume)
+        })
+    }
+
+    // Iterates over the legs of the path, as pairs of consecutive waypoints
+    fn legs(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.waypoints().zip(self.waypoints().skip(1))
+    }
+
+    // Iterates over the waypoints along with their distance from the open along the path
+    fn visits(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        once((0.0, self.open)).chain(self.legs().scan(0.0, |distance, (from, to)| {
+            *distance += (to - from).abs();
+            Some((*distance, to))
+        }))
+    }
+
+    // Returns the price at the given distance from the open along the path
+    fn price_at(&self, distance: f64) -> f64 {
+        self.visits()
+            .zip(self.visits().skip(1))
+            .find(|(_, (to, _))| distance <= *to)
+            .map_or(self.close, |((from, price), (to, next))| match to > from {
+                true => price + (next - price).signum() * (distance - from),
+                false => next,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_paths() {
+        let bullish = (100.0, 104.0, 98.0, 103.0, 0.0);
+        let bearish = (103.0, 104.0, 98.0, 100.0, 0.0);
+        let flat = (100.0, 101.0, 99.0, 100.0, 0.0);
+
+        assert!(IntrabarPath::new(&bullish)
+            .waypoints()
+            .eq([100.0, 98.0, 104.0, 103.0]));
+        assert!(IntrabarPath::new(&bearish)
+            .waypoints()
+            .eq([103.0, 104.0, 98.0, 100.0]));
+        assert!(IntrabarPath::new(&flat)
+            .waypoints()
+            .eq([100.0, 99.0, 101.0, 100.0]));
+        assert_eq!(
+            IntrabarPath::new(&bearish).first_touch(&[98.0, 104.0]),
+            Some(1)
+        );
+        assert_eq!(IntrabarPath::new(&bearish).first_touch(&[90.0]), None);
+    }
+
+    #[test]
+    fn test_points_refine_the_path() {
+        let candle = (100.0, 104.0, 98.0, 103.0, 300.0);
+        let points = [103.5, 99.0];
+        let path = IntrabarPath::with_points(&candle, &points);
+
+        // the high was not sampled, it is reached after the highest sample
+        assert!(path
+            .waypoints()
+            .eq([100.0, 103.5, 104.0, 99.0, 98.0, 103.0]));
+        assert_eq!(path.first_touch(&[98.5, 103.8]), Some(1));
+
+        let parts = path.split::<3>();
+        assert_eq!(parts[0].0, 100.0);
+        assert_eq!(parts[2].3, 103.0);
+        assert_eq!(parts.iter().map(|p| p.1).fold(f64::MIN, f64::max), 104.0);
+        assert_eq!(parts.iter().map(|p| p.2).fold(f64::MAX, f64::min), 98.0);
+        assert_eq!(parts.iter().map(|p| p.4).sum::<f64>(), 300.0);
+        assert!(parts.windows(2).all(|w| w[0].3 == w[1].0));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{CandleStick, Timestamped};
+
+/// A candle reflected around a pivot price, turning rallies into declines and highs into lows
+///
+/// Returned by [`CandleStick::invert`] and [`invert_series`]. A bullish pattern of the
+/// original candles becomes the matching bearish pattern of the inverted ones, which makes
+/// the wrapper handy to check that detectors behave symmetrically, or to reuse long-side
+/// research for short setups.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Inverted<T> {
+    /// The original candle
+    pub candle: T,
+    /// The price prices are reflected around
+    pub pivot: f64,
+}
+
+impl<T: CandleStick> Inverted<T> {
+    // Reflects a price around the pivot, geometrically for candles in log scale
+    fn reflect(&self, price: f64) -> f64 {
+        #[cfg(feature = "std")]
+        if self.candle.log_scale() {
+            return self.pivot * self.pivot / price;
+        }
+
+        2.0 * self.pivot - price
+    }
+}
+
+/// Iterates over the candles of the series reflected around `pivot`.
+///
+/// Use a pivot within the price range of the series, like its first close, to keep the
+/// reflected prices positive.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{invert_series, CandleStream};
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let mut series = CandleStream::new();
+/// assert!(series.push(&candles[0]).push(&candles[1]).is_bullish_engulfing());
+///
+/// let inverted: Vec<_> = invert_series(&candles, 100.0).collect();
+/// let mut mirror = CandleStream::new();
+/// assert!(mirror.push(&inverted[0]).push(&inverted[1]).is_bearish_engulfing());
+/// ```
+pub fn invert_series<T: CandleStick>(
+    candles: &[T],
+    pivot: f64,
+) -> impl Iterator<Item = Inverted<&T>> + '_ {
+    candles.iter().map(move |candle| candle.invert(pivot))
+}
+
+impl<T: CandleStick> CandleStick for Inverted<T> {
+    forward_candlestick!(thresholds, log_scale => |c| c.candle);
+
+    fn open(&self) -> f64 {
+        self.reflect(self.candle.open())
+    }
+
+    fn high(&self) -> f64 {
+        self.reflect(self.candle.low())
+    }
+
+    fn low(&self) -> f64 {
+        self.reflect(self.candle.high())
+    }
+
+    fn close(&self) -> f64 {
+        self.reflect(self.candle.close())
+    }
+
+    fn volume(&self) -> f64 {
+        self.candle.volume()
+    }
+}
+
+impl<T: Timestamped> Timestamped for Inverted<T> {
+    fn timestamp(&self) -> i64 {
+        self.candle.timestamp()
+    }
+
+    fn open_time(&self, interval: i64) -> i64 {
+        self.candle.open_time(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+
+    #[test]
+    fn test_inverted_candle() {
+        let hammer = Candle::new(60, 108.0, 110.0, 100.0, 109.5, 10.0);
+        let inverted = Candle::from(&hammer.invert(100.0));
+
+        assert_eq!(inverted, Candle::new(60, 92.0, 100.0, 90.0, 90.5, 10.0));
+        assert!(hammer.is_hammer() && inverted.is_inverted_hammer());
+        assert_eq!(inverted.invert(100.0).ohlc(), hammer.ohlc());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_log_scale_reflection() {
+        struct LogCandle(f64, f64, f64, f64);
+
+        impl CandleStick for LogCandle {
+            fn log_scale(&self) -> bool {
+                true
+            }
+
+            fn open(&self) -> f64 {
+                self.0
+            }
+
+            fn high(&self) -> f64 {
+                self.1
+            }
+
+            fn low(&self) -> f64 {
+                self.2
+            }
+
+            fn close(&self) -> f64 {
+                self.3
+            }
+
+            fn volume(&self) -> f64 {
+                0.0
+            }
+        }
+
+        let candle = LogCandle(100.0, 400.0, 50.0, 200.0);
+        let inverted = candle.invert(100.0);
+
+        assert_eq!(inverted.ohlc(), (100.0, 200.0, 25.0, 50.0));
+        assert!((inverted.wick() - candle.tail()).abs() < 1e-12);
+    }
+}
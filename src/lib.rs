@@ -14,10 +14,225 @@
     clippy::unimplemented
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub(crate) mod rolling;
 pub(crate) mod utils;
 
+#[macro_use]
+mod macros;
+
 mod candle_stick;
 pub use candle_stick::CandleStick;
 
+mod candle;
+pub use candle::{Candle, Timestamped};
+
+mod invert;
+pub use invert::{invert_series, Inverted};
+
+mod symmetry;
+pub use symmetry::{Asymmetry, SymmetryCheck};
+
+mod calendar;
+pub use calendar::{AlwaysOpen, Calendar, Hole};
+
+mod session;
+pub use session::SessionGaps;
+
+mod opening_range;
+pub use opening_range::{OpeningRange, OpeningRangeBreakout, RangeEvent, RangeSignal};
+
+mod fill;
+pub use fill::{fill_holes, FillHoles, FillMode};
+
+mod repair;
+pub use repair::{repair_outliers, Repair, RepairOutliers};
+
+mod quality;
+pub use quality::{quality_report, QualityReport};
+
+mod intrabar;
+pub use intrabar::IntrabarPath;
+
+mod thresholds;
+pub use thresholds::{Knob, Preset, Thresholds, Tuned};
+
+mod bid_ask;
+pub use bid_ask::{BidAsk, BidAskCandle, SpreadGated};
+
+mod delta;
+pub use delta::DeltaVolume;
+
+mod candle_type;
+pub use candle_type::CandleType;
+
+mod shape;
+
 mod candle_stream;
 pub use candle_stream::CandleStream;
+
+mod pattern;
+pub use pattern::{Pattern, PatternSet, Suppression};
+
+mod snapshot;
+pub use snapshot::Snapshot;
+
+mod donchian;
+pub use donchian::Donchian;
+
+mod swing;
+pub use swing::{Swing, SwingKind};
+
+mod double_top;
+pub use double_top::DoublePattern;
+
+mod cup_handle;
+pub use cup_handle::{CupAndHandle, CupRules};
+
+mod direction;
+pub use direction::Direction;
+
+mod zigzag;
+pub use zigzag::{ZigZag, ZigZagThreshold};
+
+mod fibonacci;
+pub use fibonacci::Fibonacci;
+
+mod trendline;
+pub use trendline::Trendline;
+
+mod regression;
+pub use regression::Regression;
+
+mod regime;
+pub use regime::{Regime, RegimeRules};
+
+mod trend_strength;
+
+mod climax;
+
+mod spinning_top;
+
+mod vsa;
+
+mod explain;
+pub use explain::{Comparison, Condition, Mismatch};
+
+mod lookback;
+
+mod rule;
+pub use rule::{And, InRegime, Not, Or, Rule, VolumeAbove};
+
+mod alert;
+pub use alert::{Alert, AlertEngine, AlertRule};
+
+#[cfg(all(feature = "std", feature = "serde"))]
+mod config;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use config::{Config, RuleConfig};
+
+mod structure;
+pub use structure::{StructureLabel, StructurePoint};
+
+mod fair_value_gap;
+pub use fair_value_gap::FairValueGap;
+
+mod sweep;
+pub use sweep::LiquiditySweep;
+
+mod liquidity;
+pub use liquidity::LiquidityLevel;
+
+mod flag;
+pub use flag::{Flag, FlagKind};
+
+mod triangle;
+pub use triangle::{Triangle, TriangleKind};
+
+mod wedge;
+pub use wedge::{Wedge, WedgeKind};
+
+mod rounding_bottom;
+pub use rounding_bottom::RoundingBottom;
+
+mod rectangle;
+pub use rectangle::Rectangle;
+
+mod harmonic;
+pub use harmonic::{Harmonic, HarmonicKind};
+
+mod dead_cat;
+pub use dead_cat::{BounceRules, BounceStage, DeadCatBounce};
+
+mod v_reversal;
+pub use v_reversal::VReversal;
+
+mod target;
+
+mod detector;
+pub use detector::{PatternDetector, PatternMatch};
+
+mod registry;
+pub use registry::{CustomDetector, Registry};
+
+mod stats;
+pub use stats::WindowStats;
+
+mod walk_forward;
+pub use walk_forward::{TradeScore, WalkForward, WalkForwardWindow};
+
+mod significance;
+pub use significance::{Resampling, Significance, SignificanceTest};
+
+#[cfg(feature = "std")]
+mod monte_carlo;
+#[cfg(feature = "std")]
+pub use monte_carlo::{MonteCarlo, Outcomes};
+
+#[cfg(feature = "std")]
+mod export;
+#[cfg(feature = "std")]
+pub use export::export_labels;
+#[cfg(feature = "parquet")]
+pub use export::export_labels_parquet;
+
+mod talib;
+pub use talib::TaLib;
+
+#[cfg(feature = "std")]
+mod binary;
+#[cfg(feature = "std")]
+pub use binary::BinaryScanner;
+
+#[cfg(feature = "std")]
+mod compact;
+#[cfg(feature = "std")]
+pub use compact::{CompactCandles, CompactIter, CompactScanner};
+
+mod features;
+pub use features::Features;
+
+mod scanner;
+pub use scanner::{DetectorScanner, Scanner};
+
+mod source;
+#[cfg(feature = "std")]
+pub use source::CsvSource;
+#[cfg(feature = "parquet")]
+pub use source::ParquetSource;
+pub use source::{OhlcvSource, SourceScanner};
+
+mod edge;
+pub use edge::EdgeTrigger;
+
+mod columns;
+pub use columns::{CandleSeries, ColumnCandle, ColumnScanner, Columns};
+
+mod interop;
+#[cfg(feature = "yata")]
+pub use interop::Yata;
+
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
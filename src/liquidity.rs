@@ -0,0 +1,191 @@
+use crate::{CandleStick, CandleStream, Swing, SwingKind};
+
+/// A cluster of equal swing highs or lows, as returned by [`CandleStream::equal_highs`] and
+/// [`CandleStream::equal_lows`]
+///
+/// Stop orders tend to rest just beyond such levels, which makes them targets for sweeps and
+/// the reference of tweezer and double top or bottom formations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidityLevel {
+    /// Whether the cluster is made of swing highs or swing lows
+    pub kind: SwingKind,
+    /// The most extreme price of the cluster, highest high or lowest low
+    pub price: f64,
+    /// Number of swings in the cluster
+    pub touches: usize,
+    /// Position of the latest swing of the cluster, `0` being the latest candle
+    pub latest: usize,
+    /// Position of the oldest swing of the cluster
+    pub oldest: usize,
+}
+
+impl LiquidityLevel {
+    /// Returns true if the candle trades beyond the level and closes back inside
+    pub fn is_swept_by<T: CandleStick>(&self, candle: &T) -> bool {
+        match self.kind {
+            SwingKind::High => candle.high() > self.price && candle.close() < self.price,
+            SwingKind::Low => candle.low() < self.price && candle.close() > self.price,
+        }
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Iterates over the clusters of equal swing highs of the given `strength`, from the
+    /// latest to the oldest.
+    ///
+    /// Swing highs within `tolerance`, a fraction of the price (e.g. `0.001` for 0.1%), of
+    /// the latest swing of a cluster belong to it. Clusters a later candle already traded
+    /// above are spent and skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 101.0, 99.0, 100.5, 0.0),
+    ///     (100.5, 105.0, 100.0, 104.0, 0.0),
+    ///     (104.0, 104.5, 101.0, 102.0, 0.0),
+    ///     (102.0, 105.05, 101.5, 104.5, 0.0),
+    ///     (104.5, 104.8, 102.0, 103.0, 0.0),
+    ///     (103.0, 103.5, 101.0, 101.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 10>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let level = series.equal_highs(1, 0.001).next().unwrap();
+    /// assert_eq!((level.price, level.touches, level.latest, level.oldest), (105.05, 2, 2, 4));
+    /// ```
+    pub fn equal_highs(
+        &self,
+        strength: usize,
+        tolerance: f64,
+    ) -> impl Iterator<Item = LiquidityLevel> + '_ {
+        self.clusters(strength, tolerance, SwingKind::High)
+    }
+
+    /// Iterates over the clusters of equal swing lows of the given `strength`, from the latest
+    /// to the oldest.
+    ///
+    /// Mirror of [`CandleStream::equal_highs`], clusters a later candle already traded below
+    /// are skipped.
+    pub fn equal_lows(
+        &self,
+        strength: usize,
+        tolerance: f64,
+    ) -> impl Iterator<Item = LiquidityLevel> + '_ {
+        self.clusters(strength, tolerance, SwingKind::Low)
+    }
+
+    // Shared implementation of the equal highs and lows
+    fn clusters(
+        &self,
+        strength: usize,
+        tolerance: f64,
+        kind: SwingKind,
+    ) -> impl Iterator<Item = LiquidityLevel> + '_ {
+        let swings = move || self.swings(strength).filter(move |s| s.kind == kind);
+        let equal =
+            move |a: &Swing, b: &Swing| (a.price - b.price).abs() <= tolerance * a.price.abs();
+        let beyond = move |price: f64, level: f64| match kind {
+            SwingKind::High => price > level,
+            SwingKind::Low => price < level,
+        };
+
+        swings().filter_map(move |anchor| {
+            // each cluster is reported once, from its latest swing
+            if swings()
+                .take_while(|s| s.bars_ago < anchor.bars_ago)
+                .any(|s| equal(&anchor, &s))
+            {
+                return None;
+            }
+
+            let members = || {
+                swings()
+                    .skip_while(|s| s.bars_ago < anchor.bars_ago)
+                    .filter(|s| equal(&anchor, s))
+            };
+            let price = members()
+                .map(|s| s.price)
+                .reduce(|a, b| if beyond(b, a) { b } else { a })?;
+            let touches = members().count();
+            if touches < 2 {
+                return None;
+            }
+            let oldest = members().last()?.bars_ago;
+
+            let spent = (0..anchor.bars_ago)
+                .filter_map(|n| self.prev(n))
+                .any(|c| match kind {
+                    SwingKind::High => beyond(c.high(), price),
+                    SwingKind::Low => beyond(c.low(), price),
+                });
+
+            (!spent).then_some(LiquidityLevel {
+                kind,
+                price,
+                touches,
+                latest: anchor.bars_ago,
+                oldest,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const SHAPE: [(f64, f64); 10] = [
+        (103.0, 101.0),
+        (102.0, 100.0),
+        (104.0, 101.5),
+        (103.5, 100.02),
+        (105.0, 102.0),
+        (104.0, 101.0),
+        (103.0, 99.98),
+        (104.5, 101.0),
+        (103.0, 101.2),
+        (104.0, 102.0),
+    ];
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_equal_lows() {
+        let candles = SHAPE.map(candle);
+        let stream = series(&candles);
+
+        let level = stream.equal_lows(1, 0.001).next();
+        assert!(level.is_some_and(|l| (l.price, l.touches) == (99.98, 3)));
+        assert!(level.is_some_and(|l| (l.latest, l.oldest) == (3, 8)));
+        assert_eq!(stream.equal_lows(1, 0.001).count(), 1);
+        assert_eq!(stream.equal_lows(1, 0.0001).next(), None);
+
+        let sweep = (101.0, 102.0, 99.5, 100.5, 0.0);
+        assert!(level.is_some_and(|l| l.is_swept_by(&sweep)));
+    }
+
+    #[test]
+    fn test_spent_cluster() {
+        let mut shape = SHAPE;
+        shape[9] = (102.0, 99.0);
+        let candles = shape.map(candle);
+
+        assert_eq!(series(&candles).equal_lows(1, 0.001).next(), None);
+        assert_eq!(series(&candles).equal_highs(1, 0.001).next(), None);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{candle_stream::SERIES_SIZE, CandleStick, CandleStream, Pattern};
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the number of candles pushed since the given [`Pattern`] last completed, `0`
+    /// if the latest candle completes it, or `None` if it did not form within the buffered
+    /// candles.
+    ///
+    /// The search is bounded by the capacity of the stream, see
+    /// [`CandleStream::with_capacity`] to look further back.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let candles = [
+    ///     (101.0, 102.0, 99.5, 100.5, 0.0),
+    ///     (99.0, 103.0, 98.5, 102.5, 0.0),
+    ///     (102.5, 103.5, 102.0, 103.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert_eq!(series.bars_since(Pattern::BullishEngulfing), Some(1));
+    /// assert_eq!(series.bars_since(Pattern::BearishEngulfing), None);
+    /// ```
+    pub fn bars_since(&self, pattern: Pattern) -> Option<usize> {
+        (0..self.len()).find(|&bars_ago| self.formed_at(pattern, bars_ago))
+    }
+
+    /// Returns true if the given [`Pattern`] completed within the last `n` pushes, the latest
+    /// one included.
+    ///
+    /// Lets strategies act on a formation a few bars after it completed, like entering long
+    /// if a morning star formed within the last 3 bars and the price still holds above its
+    /// low.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let candles = [
+    ///     (52.0, 52.5, 48.0, 48.5, 0.0),
+    ///     (48.3, 48.9, 47.5, 48.4, 0.0),
+    ///     (48.7, 51.5, 48.5, 51.2, 0.0),
+    ///     (51.2, 52.0, 50.6, 51.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    ///
+    /// let star_low = 47.5;
+    /// let enter = series.detected_within(Pattern::MorningStar, 3)
+    ///     && series.get().is_some_and(|c| c.3 > star_low);
+    /// assert!(enter);
+    /// assert!(!series.detected_within(Pattern::MorningStar, 1));
+    /// ```
+    pub fn detected_within(&self, pattern: Pattern, n: usize) -> bool {
+        (0..n.min(self.len())).any(|bars_ago| self.formed_at(pattern, bars_ago))
+    }
+
+    // Returns true if the candles up to the one `bars_ago` back formed the pattern
+    fn formed_at(&self, pattern: Pattern, bars_ago: usize) -> bool {
+        if bars_ago == 0 {
+            return self.is_pattern(pattern);
+        }
+
+        let mut stream = CandleStream::new();
+        for candle in (bars_ago..bars_ago + SERIES_SIZE)
+            .rev()
+            .filter_map(|i| self.prev(i))
+        {
+            stream.push(candle);
+        }
+        stream.is_pattern(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_bars_since_follows_the_pushes() {
+        let after = [
+            (51.2, 52.0, 50.6, 51.0, 0.0),
+            (51.0, 51.3, 50.2, 50.5, 0.0),
+            (50.5, 50.9, 49.8, 50.2, 0.0),
+            (50.2, 50.6, 49.5, 49.8, 0.0),
+        ];
+        let mut series = CandleStream::<_, 6>::with_capacity();
+        for candle in &fixtures::MORNING_STAR {
+            series.push(candle);
+        }
+
+        let star = Pattern::MorningStar;
+        for (i, candle) in after.iter().enumerate() {
+            assert_eq!(series.bars_since(star), Some(i));
+            assert!(series.detected_within(star, i + 1));
+            assert!(!series.detected_within(star, i));
+            series.push(candle);
+        }
+
+        // the first candle of the star has left the six-candle buffer
+        assert_eq!(series.bars_since(star), None);
+        assert!(!series.detected_within(star, 10));
+    }
+}
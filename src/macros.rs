@@ -0,0 +1,147 @@
+/// Declares a custom multi-candle pattern detector.
+///
+/// The candles are bound from the latest one backwards: the first name refers to the current
+/// candle, the second to the previous one, and so on up to the stream capacity. The condition
+/// is any boolean expression over those candles, and the macro expands to a closure
+/// `Fn(&CandleStream<T>) -> bool` over the given candle type `T`, returning false until enough
+/// candles have been pushed.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{pattern, CandleStick, CandleStream};
+/// type Candle = (f64, f64, f64, f64, f64);
+///
+/// let outside_up = pattern!(Candle; [c, p1] =>
+///     p1.is_bearish() && c.is_bullish() && c.close() > p1.open()
+/// );
+///
+/// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+/// let mut series = CandleStream::new();
+/// assert!(!outside_up(series.push(&prev)));
+/// assert!(outside_up(series.push(&curr)));
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    ($candle:ty; [$($name:ident),+ $(,)?] => $cond:expr) => {
+        |stream: &$crate::CandleStream<'_, $candle>| -> bool {
+            #[allow(unused_imports)]
+            use $crate::CandleStick as _;
+            $crate::__pattern_bind!(stream, 0; $cond; $($name),+)
+        }
+    };
+}
+
+/// Binds the candles of a [`pattern!`] condition, from the latest one backwards
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pattern_bind {
+    ($stream:ident, $n:expr; $cond:expr; ) => {
+        $cond
+    };
+    ($stream:ident, $n:expr; $cond:expr; $head:ident $(, $tail:ident)*) => {
+        match $stream.prev($n) {
+            Some($head) => $crate::__pattern_bind!($stream, $n + 1; $cond; $($tail),*),
+            None => false,
+        }
+    };
+}
+
+// Forwards the given groups of `CandleStick` methods to the candle the closure returns, so the
+// wrappers can't drift from the trait: `thresholds`, `log_scale`, `prices` (OHLCV) and `is_doji`,
+// whose overrides like spread gating then hold through the wrapper
+macro_rules! forward_candlestick {
+    ($($group:ident),+ => |$c:ident| $inner:expr) => {
+        $(forward_candlestick!(@$group |$c| $inner);)+
+    };
+    (@thresholds |$c:ident| $inner:expr) => {
+        forward_candlestick!(@f64 |$c| $inner;
+            hammer_body_ratio, hammer_wick_ratio, hammer_tail_ratio, spinning_top_body_ratio,
+            spinning_top_shadow_ratio, doji_body_ratio, doji_long_leg_ratio, doji_tail_ratio,
+            doji_wick_ratio, doji_min_ratio, marubozu_ratio, tick_size, price_match_ratio);
+
+        fn min_gap_ticks(&self) -> u32 {
+            let $c = self;
+            $inner.min_gap_ticks()
+        }
+    };
+    (@log_scale |$c:ident| $inner:expr) => {
+        #[cfg(feature = "std")]
+        fn log_scale(&self) -> bool {
+            let $c = self;
+            $inner.log_scale()
+        }
+    };
+    (@prices |$c:ident| $inner:expr) => {
+        forward_candlestick!(@f64 |$c| $inner; open, high, low, close, volume);
+    };
+    (@is_doji |$c:ident| $inner:expr) => {
+        fn is_doji(&self) -> bool {
+            let $c = self;
+            $inner.is_doji()
+        }
+    };
+    (@f64 |$c:ident| $inner:expr; $($method:ident),+) => {
+        $(
+            fn $method(&self) -> f64 {
+                let $c = self;
+                $inner.$method()
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CandleStream, Registry};
+
+    type Candle = (f64, f64, f64, f64, f64);
+
+    #[test]
+    fn test_pattern_matches_builtin() {
+        let dark_cloud_cover = pattern!(Candle; [c, p1] =>
+            c.is_bearish()
+                && p1.is_bullish()
+                && c.open() > p1.close()
+                && c.close() < (p1.open() + p1.close()) / 2.0
+        );
+
+        let prev = (100.0, 105.0, 99.5, 104.5, 0.0);
+        let curr = (105.5, 106.0, 102.0, 101.5, 0.0);
+
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&curr);
+
+        assert_eq!(dark_cloud_cover(&series), series.is_dark_cloud_cover());
+        assert!(dark_cloud_cover(&series));
+    }
+
+    #[test]
+    fn test_pattern_with_insufficient_candles() {
+        let three_bulls = pattern!(Candle; [c, p1, p2] =>
+            c.is_bullish() && p1.is_bullish() && p2.is_bullish()
+        );
+
+        let candle = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let mut series = CandleStream::new();
+
+        assert!(!three_bulls(series.push(&candle)));
+        assert!(!three_bulls(series.push(&candle)));
+        assert!(three_bulls(series.push(&candle)));
+    }
+
+    #[test]
+    fn test_pattern_in_registry() {
+        let gap_up = pattern!(Candle; [c, p1] => c.low() > p1.high());
+
+        let mut registry = Registry::<Candle, 1>::new();
+        registry.register("gap_up", &gap_up);
+
+        let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let curr = (106.0, 110.0, 105.5, 109.0, 0.0);
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&curr);
+
+        assert!(registry.is_pattern("gap_up", &series));
+    }
+}
@@ -0,0 +1,202 @@
+use std::vec::Vec;
+
+use crate::{utils::Rng, CandleStick, Direction, Pattern, PatternSet, Scanner};
+
+/// A Monte Carlo simulation of trading a pattern, giving the distribution of outcomes its
+/// historical occurrences allow.
+///
+/// Every occurrence of the pattern followed by a complete horizon is a trade entered at the
+/// close of the completing bar, in the direction anticipated by the pattern, and exited at
+/// the close `horizon` bars later. Each trial draws `trades` of those trades at random, with
+/// replacement, and compounds them into an equity curve, recording its final return and its
+/// maximum drawdown, adverse excursions within trades included.
+///
+/// Draws are driven by a seeded generator, so that results are reproducible.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{MonteCarlo, Pattern};
+/// // bullish engulfing candles, followed by a rally then a pullback
+/// let candles: Vec<_> = (0..200)
+///     .map(|i| match i % 4 {
+///         0 => (101.0, 102.0, 99.5, 100.5, 0.0),
+///         1 => (99.0, 103.0, 98.5, 102.5, 0.0),
+///         2 => (102.5, 106.0, 101.5, 105.5, 0.0),
+///         _ => (105.5, 106.0, 100.5, 101.0, 0.0),
+///     })
+///     .collect();
+/// let simulation = MonteCarlo { horizon: 1, trades: 10, ..Default::default() };
+///
+/// let outcomes = simulation.run(&candles, Pattern::BullishEngulfing).unwrap();
+/// assert_eq!(outcomes.occurrences, 50);
+/// assert_eq!(outcomes.win_rate, 1.0);
+/// assert!(outcomes.expectancy > 0.02);
+/// // every trade dips to 101.5 before closing higher
+/// assert!(outcomes.drawdown_quantile(0.95) < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonteCarlo {
+    /// Number of bars each trade is held
+    pub horizon: usize,
+    /// Number of trades compounded per trial
+    pub trades: usize,
+    /// Number of simulated equity curves
+    pub trials: usize,
+    /// Seed of the random generator
+    pub seed: u64,
+}
+
+impl Default for MonteCarlo {
+    fn default() -> Self {
+        Self {
+            horizon: 5,
+            trades: 50,
+            trials: 1000,
+            seed: 0,
+        }
+    }
+}
+
+/// The distribution of outcomes computed by a [`MonteCarlo`] simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcomes {
+    /// Number of historical trades the simulation drew from
+    pub occurrences: usize,
+    /// Average return of the historical trades
+    pub expectancy: f64,
+    /// Fraction of the historical trades closing with a gain
+    pub win_rate: f64,
+    returns: Vec<f64>,
+    drawdowns: Vec<f64>,
+}
+
+impl Outcomes {
+    /// Returns the final return of the equity curves at the given quantile, between `0.0`
+    /// and `1.0`, e.g. `0.05` for the return exceeded by 95% of the trials
+    pub fn return_quantile(&self, q: f64) -> f64 {
+        quantile(&self.returns, q)
+    }
+
+    /// Returns the maximum drawdown of the equity curves at the given quantile, as a positive
+    /// fraction of the peak equity, e.g. `0.95` for the drawdown exceeded by 5% of the trials
+    pub fn drawdown_quantile(&self, q: f64) -> f64 {
+        quantile(&self.drawdowns, q)
+    }
+}
+
+// Returns the nearest-rank quantile of sorted values
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = (q.clamp(0.0, 1.0) * sorted.len().saturating_sub(1) as f64).round() as usize;
+    sorted.get(rank).copied().unwrap_or(f64::NAN)
+}
+
+// Return and adverse excursion of a historical trade
+#[derive(Debug, Clone, Copy)]
+struct Trade {
+    ret: f64,
+    adverse: f64,
+}
+
+impl MonteCarlo {
+    /// Runs the simulation on the occurrences of the pattern in the series, or returns `None`
+    /// if none of them is followed by a complete horizon
+    pub fn run<T: CandleStick>(&self, candles: &[T], pattern: Pattern) -> Option<Outcomes> {
+        let sign = match pattern.direction() {
+            Direction::Bullish => 1.0,
+            Direction::Bearish => -1.0,
+        };
+        let trades = Scanner::with_mask(candles, PatternSet::new().with(pattern))
+            .filter_map(|(idx, _)| {
+                let entry = candles.get(idx)?.close();
+                let path = candles.get(idx + 1..=idx + self.horizon)?;
+                let exit = path.last()?.close();
+                let adverse = path
+                    .iter()
+                    .map(|c| match sign > 0.0 {
+                        true => c.low() / entry - 1.0,
+                        false => 1.0 - c.high() / entry,
+                    })
+                    .fold(0.0, f64::min);
+
+                Some(Trade {
+                    ret: sign * (exit / entry - 1.0),
+                    adverse,
+                })
+            })
+            .collect::<Vec<_>>();
+        if trades.is_empty() {
+            return None;
+        }
+
+        let occurrences = trades.len();
+        let mut rng = Rng::new(self.seed);
+        let (mut returns, mut drawdowns): (Vec<_>, Vec<_>) = (0..self.trials)
+            .map(|_| {
+                let (mut equity, mut peak, mut drawdown) = (1.0_f64, 1.0_f64, 0.0_f64);
+                for _ in 0..self.trades {
+                    let Some(trade) = trades.get(rng.below(occurrences)) else {
+                        continue;
+                    };
+                    let trough = equity * (1.0 + trade.adverse);
+                    drawdown = drawdown.max(1.0 - trough / peak);
+                    equity *= 1.0 + trade.ret;
+                    peak = peak.max(equity);
+                    drawdown = drawdown.max(1.0 - equity / peak);
+                }
+                (equity - 1.0, drawdown)
+            })
+            .unzip();
+        returns.sort_unstable_by(f64::total_cmp);
+        drawdowns.sort_unstable_by(f64::total_cmp);
+
+        Some(Outcomes {
+            occurrences,
+            expectancy: trades.iter().map(|t| t.ret).sum::<f64>() / occurrences as f64,
+            win_rate: trades.iter().filter(|t| t.ret > 0.0).count() as f64 / occurrences as f64,
+            returns,
+            drawdowns,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_distribution() {
+        // evening stars followed by a fall of 2, or a rise of 1 every third time
+        let candles: Vec<_> = (0..300)
+            .map(|i| match i % 5 {
+                0 => (100.0, 104.5, 99.5, 104.0, 0.0),
+                1 => (105.0, 105.5, 104.5, 105.2, 0.0),
+                2 => (104.0, 104.5, 100.5, 101.0, 0.0),
+                3 if i % 15 == 3 => (101.0, 102.5, 100.0, 102.0, 0.0),
+                _ => (101.0, 102.0, 98.5, 99.0, 0.0),
+            })
+            .collect();
+        let simulation = MonteCarlo {
+            horizon: 1,
+            trades: 20,
+            trials: 500,
+            seed: 3,
+        };
+
+        let outcomes = simulation.run(&candles, Pattern::EveningStar);
+        assert_eq!(outcomes.as_ref().map(|o| o.occurrences), Some(60));
+        assert!(outcomes.as_ref().is_some_and(|o| {
+            (o.win_rate - 2.0 / 3.0).abs() < 1e-9
+                && o.expectancy > 0.0
+                && o.return_quantile(0.05) < o.return_quantile(0.5)
+                && o.return_quantile(0.5) < o.return_quantile(0.95)
+                && o.drawdown_quantile(0.5) > 0.0
+                && o.drawdown_quantile(0.5) <= o.drawdown_quantile(1.0)
+        }));
+
+        let longer = MonteCarlo {
+            horizon: 400,
+            ..simulation
+        };
+        assert_eq!(longer.run(&candles, Pattern::EveningStar), None);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{CandleStick, Direction, Timestamped};
+
+/// The opening range of a session, spanning its first candles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpeningRange {
+    /// Open time of the first candle of the session
+    pub start: i64,
+    /// Highest high of the opening candles
+    pub high: f64,
+    /// Lowest low of the opening candles
+    pub low: f64,
+    /// Average volume of the opening candles
+    pub volume: f64,
+    /// Open of the session minus the close of the previous one, `0.0` for the first session
+    pub gap: f64,
+}
+
+/// A signal of an [`OpeningRangeBreakout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeSignal {
+    /// The candle closed beyond the opening range, in the given direction
+    Breakout(Direction),
+    /// The candle closed back inside the opening range after a breakout in the given direction
+    Failure(Direction),
+}
+
+/// A signal raised by an [`OpeningRangeBreakout`], with the context to filter it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeEvent {
+    /// The signal
+    pub signal: RangeSignal,
+    /// The opening range of the session
+    pub range: OpeningRange,
+    /// Volume of the candle relative to the average volume of the opening candles, `1.0`
+    /// when the volume is unknown
+    pub volume_ratio: f64,
+}
+
+/// The `OpeningRangeBreakout` tracks the opening range of each intraday session and detects
+/// the candles breaking out of it, or failing back inside after a breakout.
+///
+/// A session starts whenever consecutive candles open more than `interval` apart, like the
+/// close of a day and the open of the next one. Its opening range is built from its first
+/// `bars` candles, signals being raised on the candles that follow. The opening gap and the
+/// relative volume of the signal candle are reported alongside, as gap-and-go breakouts on
+/// heavy volume are the classic day-trading setups.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Candle, Direction, OpeningRangeBreakout, RangeSignal};
+/// let mut orb = OpeningRangeBreakout::new(2, 60);
+/// let candles = [
+///     Candle::new(0, 100.0, 101.0, 99.5, 100.5, 500.0),
+///     Candle::new(60, 100.5, 101.5, 100.0, 101.0, 500.0),
+///     Candle::new(120, 101.0, 102.5, 100.8, 102.2, 1500.0),
+///     Candle::new(180, 102.2, 102.4, 100.5, 100.8, 400.0),
+/// ];
+/// let signals: Vec<_> = candles.iter().filter_map(|c| orb.push(c)).collect();
+///
+/// assert_eq!(signals[0].signal, RangeSignal::Breakout(Direction::Bullish));
+/// assert_eq!((signals[0].range.high, signals[0].range.low), (101.5, 99.5));
+/// assert_eq!(signals[0].volume_ratio, 3.0);
+/// assert_eq!(signals[1].signal, RangeSignal::Failure(Direction::Bullish));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpeningRangeBreakout {
+    bars: usize,
+    interval: i64,
+    last: Option<(i64, f64)>,
+    opening: usize,
+    range: Option<OpeningRange>,
+    breakout: Option<Direction>,
+}
+
+impl OpeningRangeBreakout {
+    /// Returns a tracker building the opening range from the first `bars` candles of each
+    /// session, sessions being separated by more than `interval` between candle opens
+    pub fn new(bars: usize, interval: i64) -> Self {
+        Self {
+            bars: bars.max(1),
+            interval,
+            last: None,
+            opening: 0,
+            range: None,
+            breakout: None,
+        }
+    }
+
+    /// Returns the opening range of the current session, once its opening candles are in
+    pub fn range(&self) -> Option<OpeningRange> {
+        self.range.filter(|_| self.opening == self.bars)
+    }
+
+    /// Feeds the next candle, returning the signal it raises, if any
+    pub fn push<T: CandleStick + Timestamped>(&mut self, candle: &T) -> Option<RangeEvent> {
+        let (timestamp, close) = (candle.open_time(self.interval), candle.close());
+        let last = self.last.replace((timestamp, close));
+        let new_session = last.is_none_or(|(t, _)| timestamp - t > self.interval);
+
+        if new_session {
+            self.opening = 1;
+            self.breakout = None;
+            self.range = Some(OpeningRange {
+                start: timestamp,
+                high: candle.high(),
+                low: candle.low(),
+                volume: candle.volume(),
+                gap: last.map_or(0.0, |(_, prev)| candle.open() - prev),
+            });
+        } else if self.opening < self.bars {
+            self.opening += 1;
+            if let Some(range) = self.range.as_mut() {
+                range.high = range.high.max(candle.high());
+                range.low = range.low.min(candle.low());
+                range.volume += candle.volume();
+            }
+        } else {
+            return self.signal(candle);
+        }
+
+        if self.opening == self.bars {
+            if let Some(range) = self.range.as_mut() {
+                range.volume /= self.bars as f64;
+            }
+        }
+        None
+    }
+
+    // Returns the signal raised by a candle following the opening range
+    fn signal<T: CandleStick>(&mut self, candle: &T) -> Option<RangeEvent> {
+        let range = self.range?;
+        let close = candle.close();
+
+        let signal = match self.breakout {
+            Some(Direction::Bullish) if close < range.low => {
+                RangeSignal::Breakout(Direction::Bearish)
+            }
+            Some(Direction::Bearish) if close > range.high => {
+                RangeSignal::Breakout(Direction::Bullish)
+            }
+            Some(Direction::Bullish) if close <= range.high => {
+                RangeSignal::Failure(Direction::Bullish)
+            }
+            Some(Direction::Bearish) if close >= range.low => {
+                RangeSignal::Failure(Direction::Bearish)
+            }
+            None if close > range.high => RangeSignal::Breakout(Direction::Bullish),
+            None if close < range.low => RangeSignal::Breakout(Direction::Bearish),
+            _ => return None,
+        };
+        self.breakout = match signal {
+            RangeSignal::Breakout(direction) => Some(direction),
+            RangeSignal::Failure(_) => None,
+        };
+
+        Some(RangeEvent {
+            signal,
+            range,
+            volume_ratio: match range.volume > 0.0 {
+                true => candle.volume() / range.volume,
+                false => 1.0,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+
+    const MINUTE: i64 = 60;
+
+    #[test]
+    fn test_ranges_reset_every_session() {
+        let mut orb = OpeningRangeBreakout::new(2, MINUTE);
+        let day = 24 * 60 * MINUTE;
+        let candles = [
+            Candle::new(0, 100.0, 101.0, 99.0, 100.5, 0.0),
+            Candle::new(MINUTE, 100.5, 101.0, 99.5, 100.0, 0.0),
+            Candle::new(2 * MINUTE, 100.0, 100.2, 98.0, 98.5, 0.0),
+            Candle::new(3 * MINUTE, 98.5, 98.8, 97.5, 98.0, 0.0),
+            // the next day gaps up and starts a new range, despite breaking the previous one
+            Candle::new(day, 102.0, 103.0, 101.5, 102.5, 0.0),
+            Candle::new(day + MINUTE, 102.5, 102.8, 101.0, 101.2, 0.0),
+            Candle::new(day + 2 * MINUTE, 101.2, 101.4, 100.0, 100.5, 0.0),
+            Candle::new(day + 3 * MINUTE, 100.5, 104.0, 100.4, 103.5, 0.0),
+        ];
+
+        let signals: [_; 8] = core::array::from_fn(|i| candles.get(i).and_then(|c| orb.push(c)));
+        let kinds = signals.map(|event| event.map(|e| e.signal));
+        assert_eq!(
+            kinds,
+            [
+                None,
+                None,
+                Some(RangeSignal::Breakout(Direction::Bearish)),
+                None,
+                None,
+                None,
+                Some(RangeSignal::Breakout(Direction::Bearish)),
+                Some(RangeSignal::Breakout(Direction::Bullish)),
+            ]
+        );
+
+        let range = orb.range();
+        assert_eq!(
+            range.map(|r| (r.start, r.high, r.low)),
+            Some((day, 103.0, 101.0))
+        );
+        assert_eq!(range.map(|r| r.gap), Some(4.0));
+        assert!(signals[7].is_some_and(|e| e.volume_ratio == 1.0));
+    }
+}
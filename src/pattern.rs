@@ -0,0 +1,766 @@
+use crate::{vsa::VSA_CONTEXT, Direction};
+
+/// The multi-candle patterns recognized by [`crate::CandleStream`].
+///
+/// Each variant maps one-to-one to an `is_*` method of the stream, and can be evaluated
+/// generically through [`crate::CandleStream::is_pattern`]. New patterns are added in minor
+/// releases, so matches on `Pattern` need a wildcard arm, and [`Pattern::ALL`] grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Pattern {
+    /// See [`crate::CandleStream::is_rising_three_methods`]
+    RisingThreeMethods,
+    /// See [`crate::CandleStream::is_falling_three_methods`]
+    FallingThreeMethods,
+    /// See [`crate::CandleStream::is_mat_hold`]
+    MatHold,
+    /// See [`crate::CandleStream::is_bullish_breakaway`]
+    BullishBreakaway,
+    /// See [`crate::CandleStream::is_bullish_abandoned_baby`]
+    BullishAbandonedBaby,
+    /// See [`crate::CandleStream::is_bullish_tri_star`]
+    BullishTriStar,
+    /// See [`crate::CandleStream::is_bearish_tri_star`]
+    BearishTriStar,
+    /// See [`crate::CandleStream::is_morning_star_doji`]
+    MorningStarDoji,
+    /// See [`crate::CandleStream::is_evening_star_doji`]
+    EveningStarDoji,
+    /// See [`crate::CandleStream::is_morning_star`]
+    MorningStar,
+    /// See [`crate::CandleStream::is_evening_star`]
+    EveningStar,
+    /// See [`crate::CandleStream::is_three_white_soldiers`]
+    ThreeWhiteSoldiers,
+    /// See [`crate::CandleStream::is_three_black_crows`]
+    ThreeBlackCrows,
+    /// See [`crate::CandleStream::is_three_inside_up`]
+    ThreeInsideUp,
+    /// See [`crate::CandleStream::is_three_inside_down`]
+    ThreeInsideDown,
+    /// See [`crate::CandleStream::is_three_outside_down`]
+    ThreeOutsideDown,
+    /// See [`crate::CandleStream::is_upside_tasuki_gap`]
+    UpsideTasukiGap,
+    /// See [`crate::CandleStream::is_downside_tasuki_gap`]
+    DownsideTasukiGap,
+    /// See [`crate::CandleStream::is_upgap_side_by_side_white_lines`]
+    UpgapSideBySideWhiteLines,
+    /// See [`crate::CandleStream::is_downgap_side_by_side_white_lines`]
+    DowngapSideBySideWhiteLines,
+    /// See [`crate::CandleStream::is_stick_sandwich`]
+    StickSandwich,
+    /// See [`crate::CandleStream::is_bullish_island_reversal`]
+    BullishIslandReversal,
+    /// See [`crate::CandleStream::is_bearish_island_reversal`]
+    BearishIslandReversal,
+    /// See [`crate::CandleStream::is_bullish_engulfing`]
+    BullishEngulfing,
+    /// See [`crate::CandleStream::is_bearish_engulfing`]
+    BearishEngulfing,
+    /// See [`crate::CandleStream::is_dark_cloud_cover`]
+    DarkCloudCover,
+    /// See [`crate::CandleStream::is_piercing_line`]
+    PiercingLine,
+    /// See [`crate::CandleStream::is_in_neck`]
+    InNeck,
+    /// See [`crate::CandleStream::is_bullish_meeting_lines`]
+    BullishMeetingLines,
+    /// See [`crate::CandleStream::is_bearish_meeting_lines`]
+    BearishMeetingLines,
+    /// See [`crate::CandleStream::is_tweezer_top`]
+    TweezerTop,
+    /// See [`crate::CandleStream::is_tweezer_bottom`]
+    TweezerBottom,
+    /// See [`crate::CandleStream::is_bullish_harami`]
+    BullishHarami,
+    /// See [`crate::CandleStream::is_bearish_harami`]
+    BearishHarami,
+    /// See [`crate::CandleStream::is_bullish_doji_star`]
+    BullishDojiStar,
+    /// See [`crate::CandleStream::is_bearish_doji_star`]
+    BearishDojiStar,
+    /// See [`crate::CandleStream::is_bullish_star`]
+    BullishStar,
+    /// See [`crate::CandleStream::is_bearish_star`]
+    BearishStar,
+    /// See [`crate::CandleStream::is_bullish_near_star`]
+    BullishNearStar,
+    /// See [`crate::CandleStream::is_bearish_near_star`]
+    BearishNearStar,
+    /// See [`crate::CandleStream::is_upthrust`]
+    Upthrust,
+    /// See [`crate::CandleStream::is_spring`]
+    Spring,
+    /// See [`crate::CandleStream::is_no_demand`]
+    NoDemand,
+    /// See [`crate::CandleStream::is_no_supply`]
+    NoSupply,
+}
+
+impl Pattern {
+    /// All patterns, ordered from the highest to the lowest priority.
+    ///
+    /// Longer formations rank above shorter ones, since they embed more confirmation, and
+    /// within the same length the more reliable reversal signals come first. Volume
+    /// spread analysis bars, which judge a single candle in its context, rank last.
+    pub const ALL: &'static [Pattern] = &[
+        Pattern::RisingThreeMethods,
+        Pattern::FallingThreeMethods,
+        Pattern::MatHold,
+        Pattern::BullishBreakaway,
+        Pattern::BullishAbandonedBaby,
+        Pattern::BullishTriStar,
+        Pattern::BearishTriStar,
+        Pattern::MorningStarDoji,
+        Pattern::EveningStarDoji,
+        Pattern::MorningStar,
+        Pattern::EveningStar,
+        Pattern::ThreeWhiteSoldiers,
+        Pattern::ThreeBlackCrows,
+        Pattern::ThreeInsideUp,
+        Pattern::ThreeInsideDown,
+        Pattern::ThreeOutsideDown,
+        Pattern::UpsideTasukiGap,
+        Pattern::DownsideTasukiGap,
+        Pattern::UpgapSideBySideWhiteLines,
+        Pattern::DowngapSideBySideWhiteLines,
+        Pattern::StickSandwich,
+        Pattern::BullishIslandReversal,
+        Pattern::BearishIslandReversal,
+        Pattern::BullishEngulfing,
+        Pattern::BearishEngulfing,
+        Pattern::DarkCloudCover,
+        Pattern::PiercingLine,
+        Pattern::InNeck,
+        Pattern::BullishMeetingLines,
+        Pattern::BearishMeetingLines,
+        Pattern::TweezerTop,
+        Pattern::TweezerBottom,
+        Pattern::BullishHarami,
+        Pattern::BearishHarami,
+        Pattern::BullishDojiStar,
+        Pattern::BearishDojiStar,
+        Pattern::BullishStar,
+        Pattern::BearishStar,
+        Pattern::BullishNearStar,
+        Pattern::BearishNearStar,
+        Pattern::Upthrust,
+        Pattern::Spring,
+        Pattern::NoDemand,
+        Pattern::NoSupply,
+    ];
+
+    /// Returns the priority rank of the pattern, `0` being the highest priority
+    pub fn priority(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the human-readable name of the pattern
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Pattern::MorningStarDoji => "Morning Star Doji",
+            Pattern::BullishAbandonedBaby => "Bullish Abandoned Baby",
+            Pattern::BullishTriStar => "Bullish Tri-Star",
+            Pattern::BearishTriStar => "Bearish Tri-Star",
+            Pattern::RisingThreeMethods => "Rising Three Methods",
+            Pattern::FallingThreeMethods => "Falling Three Methods",
+            Pattern::MatHold => "Mat Hold",
+            Pattern::BullishBreakaway => "Bullish Breakaway",
+            Pattern::EveningStarDoji => "Evening Star Doji",
+            Pattern::MorningStar => "Morning Star",
+            Pattern::EveningStar => "Evening Star",
+            Pattern::ThreeWhiteSoldiers => "Three White Soldiers",
+            Pattern::ThreeBlackCrows => "Three Black Crows",
+            Pattern::ThreeInsideUp => "Three Inside Up",
+            Pattern::ThreeInsideDown => "Three Inside Down",
+            Pattern::ThreeOutsideDown => "Three Outside Down",
+            Pattern::UpsideTasukiGap => "Upside Tasuki Gap",
+            Pattern::DownsideTasukiGap => "Downside Tasuki Gap",
+            Pattern::UpgapSideBySideWhiteLines => "Upside-Gap Side-by-Side White Lines",
+            Pattern::DowngapSideBySideWhiteLines => "Downside-Gap Side-by-Side White Lines",
+            Pattern::StickSandwich => "Stick Sandwich",
+            Pattern::BullishIslandReversal => "Bullish Island Reversal",
+            Pattern::BearishIslandReversal => "Bearish Island Reversal",
+            Pattern::BullishEngulfing => "Bullish Engulfing",
+            Pattern::BearishEngulfing => "Bearish Engulfing",
+            Pattern::DarkCloudCover => "Dark Cloud Cover",
+            Pattern::PiercingLine => "Piercing Line",
+            Pattern::InNeck => "In-Neck",
+            Pattern::BullishMeetingLines => "Bullish Meeting Lines",
+            Pattern::BearishMeetingLines => "Bearish Meeting Lines",
+            Pattern::TweezerTop => "Tweezer Top",
+            Pattern::TweezerBottom => "Tweezer Bottom",
+            Pattern::BullishHarami => "Bullish Harami",
+            Pattern::BearishHarami => "Bearish Harami",
+            Pattern::BullishDojiStar => "Bullish Doji Star",
+            Pattern::BearishDojiStar => "Bearish Doji Star",
+            Pattern::BullishStar => "Bullish Star",
+            Pattern::BearishStar => "Bearish Star",
+            Pattern::BullishNearStar => "Bullish Near Star",
+            Pattern::BearishNearStar => "Bearish Near Star",
+            Pattern::Upthrust => "Upthrust",
+            Pattern::Spring => "Spring",
+            Pattern::NoDemand => "No Demand",
+            Pattern::NoSupply => "No Supply",
+        }
+    }
+
+    /// Returns the stable numeric code of the pattern, for databases, message formats and FFI.
+    ///
+    /// Unlike the [`Pattern::priority`] rank, codes are guaranteed never to change across
+    /// releases: a code is never reassigned, and new patterns receive the next unused code.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::Pattern;
+    /// assert_eq!(Pattern::MorningStarDoji.code(), 1);
+    /// assert_eq!(Pattern::from_code(Pattern::Spring.code()), Some(Pattern::Spring));
+    /// ```
+    pub const fn code(&self) -> u16 {
+        match self {
+            Pattern::MorningStarDoji => 1,
+            Pattern::EveningStarDoji => 2,
+            Pattern::MorningStar => 3,
+            Pattern::EveningStar => 4,
+            Pattern::ThreeWhiteSoldiers => 5,
+            Pattern::ThreeBlackCrows => 6,
+            Pattern::ThreeInsideUp => 7,
+            Pattern::ThreeInsideDown => 8,
+            Pattern::BullishEngulfing => 9,
+            Pattern::BearishEngulfing => 10,
+            Pattern::DarkCloudCover => 11,
+            Pattern::BullishHarami => 12,
+            Pattern::BearishHarami => 13,
+            Pattern::BullishDojiStar => 14,
+            Pattern::BearishDojiStar => 15,
+            Pattern::Upthrust => 16,
+            Pattern::Spring => 17,
+            Pattern::NoDemand => 18,
+            Pattern::NoSupply => 19,
+            Pattern::BullishStar => 20,
+            Pattern::BearishStar => 21,
+            Pattern::BullishNearStar => 22,
+            Pattern::BearishNearStar => 23,
+            Pattern::PiercingLine => 24,
+            Pattern::TweezerTop => 25,
+            Pattern::TweezerBottom => 26,
+            Pattern::BullishAbandonedBaby => 27,
+            Pattern::ThreeOutsideDown => 28,
+            Pattern::RisingThreeMethods => 29,
+            Pattern::FallingThreeMethods => 30,
+            Pattern::UpsideTasukiGap => 31,
+            Pattern::DownsideTasukiGap => 32,
+            Pattern::MatHold => 33,
+            Pattern::StickSandwich => 34,
+            Pattern::BullishTriStar => 35,
+            Pattern::BearishTriStar => 36,
+            Pattern::InNeck => 37,
+            Pattern::UpgapSideBySideWhiteLines => 38,
+            Pattern::DowngapSideBySideWhiteLines => 39,
+            Pattern::BullishBreakaway => 40,
+            Pattern::BullishIslandReversal => 41,
+            Pattern::BearishIslandReversal => 42,
+            Pattern::BullishMeetingLines => 43,
+            Pattern::BearishMeetingLines => 44,
+        }
+    }
+
+    /// Returns the pattern with the given stable code, see [`Pattern::code`]
+    pub const fn from_code(code: u16) -> Option<Self> {
+        match code {
+            1 => Some(Pattern::MorningStarDoji),
+            2 => Some(Pattern::EveningStarDoji),
+            3 => Some(Pattern::MorningStar),
+            4 => Some(Pattern::EveningStar),
+            5 => Some(Pattern::ThreeWhiteSoldiers),
+            6 => Some(Pattern::ThreeBlackCrows),
+            7 => Some(Pattern::ThreeInsideUp),
+            8 => Some(Pattern::ThreeInsideDown),
+            9 => Some(Pattern::BullishEngulfing),
+            10 => Some(Pattern::BearishEngulfing),
+            11 => Some(Pattern::DarkCloudCover),
+            12 => Some(Pattern::BullishHarami),
+            13 => Some(Pattern::BearishHarami),
+            14 => Some(Pattern::BullishDojiStar),
+            15 => Some(Pattern::BearishDojiStar),
+            16 => Some(Pattern::Upthrust),
+            17 => Some(Pattern::Spring),
+            18 => Some(Pattern::NoDemand),
+            19 => Some(Pattern::NoSupply),
+            20 => Some(Pattern::BullishStar),
+            21 => Some(Pattern::BearishStar),
+            22 => Some(Pattern::BullishNearStar),
+            23 => Some(Pattern::BearishNearStar),
+            24 => Some(Pattern::PiercingLine),
+            25 => Some(Pattern::TweezerTop),
+            26 => Some(Pattern::TweezerBottom),
+            27 => Some(Pattern::BullishAbandonedBaby),
+            28 => Some(Pattern::ThreeOutsideDown),
+            29 => Some(Pattern::RisingThreeMethods),
+            30 => Some(Pattern::FallingThreeMethods),
+            31 => Some(Pattern::UpsideTasukiGap),
+            32 => Some(Pattern::DownsideTasukiGap),
+            33 => Some(Pattern::MatHold),
+            34 => Some(Pattern::StickSandwich),
+            35 => Some(Pattern::BullishTriStar),
+            36 => Some(Pattern::BearishTriStar),
+            37 => Some(Pattern::InNeck),
+            38 => Some(Pattern::UpgapSideBySideWhiteLines),
+            39 => Some(Pattern::DowngapSideBySideWhiteLines),
+            40 => Some(Pattern::BullishBreakaway),
+            41 => Some(Pattern::BullishIslandReversal),
+            42 => Some(Pattern::BearishIslandReversal),
+            43 => Some(Pattern::BullishMeetingLines),
+            44 => Some(Pattern::BearishMeetingLines),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of candles the pattern is read from, the latest one included.
+    ///
+    /// The volume spread analysis bars count the previous candles they are judged against.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::Pattern;
+    /// assert_eq!(Pattern::MorningStar.candle_count(), 3);
+    /// assert_eq!(Pattern::BullishEngulfing.candle_count(), 2);
+    /// ```
+    pub const fn candle_count(&self) -> usize {
+        match self {
+            Pattern::BullishEngulfing
+            | Pattern::BearishEngulfing
+            | Pattern::BullishHarami
+            | Pattern::BearishHarami
+            | Pattern::BullishDojiStar
+            | Pattern::BearishDojiStar
+            | Pattern::BullishStar
+            | Pattern::BearishStar
+            | Pattern::BullishNearStar
+            | Pattern::BearishNearStar
+            | Pattern::DarkCloudCover
+            | Pattern::PiercingLine
+            | Pattern::TweezerTop
+            | Pattern::TweezerBottom
+            | Pattern::InNeck
+            | Pattern::BullishMeetingLines
+            | Pattern::BearishMeetingLines => 2,
+            Pattern::MorningStarDoji
+            | Pattern::EveningStarDoji
+            | Pattern::MorningStar
+            | Pattern::EveningStar
+            | Pattern::ThreeWhiteSoldiers
+            | Pattern::ThreeBlackCrows
+            | Pattern::ThreeInsideUp
+            | Pattern::ThreeInsideDown
+            | Pattern::BullishAbandonedBaby
+            | Pattern::ThreeOutsideDown
+            | Pattern::UpsideTasukiGap
+            | Pattern::DownsideTasukiGap
+            | Pattern::StickSandwich
+            | Pattern::BullishTriStar
+            | Pattern::BearishTriStar
+            | Pattern::UpgapSideBySideWhiteLines
+            | Pattern::DowngapSideBySideWhiteLines
+            | Pattern::BullishIslandReversal
+            | Pattern::BearishIslandReversal => 3,
+            Pattern::RisingThreeMethods
+            | Pattern::FallingThreeMethods
+            | Pattern::MatHold
+            | Pattern::BullishBreakaway => 5,
+            Pattern::Upthrust | Pattern::Spring | Pattern::NoDemand | Pattern::NoSupply => {
+                VSA_CONTEXT + 1
+            }
+        }
+    }
+
+    /// Returns the direction of the move the pattern anticipates
+    pub const fn direction(&self) -> Direction {
+        match self {
+            Pattern::MorningStarDoji
+            | Pattern::MorningStar
+            | Pattern::ThreeWhiteSoldiers
+            | Pattern::ThreeInsideUp
+            | Pattern::BullishEngulfing
+            | Pattern::BullishHarami
+            | Pattern::BullishDojiStar
+            | Pattern::BullishStar
+            | Pattern::BullishNearStar
+            | Pattern::Spring
+            | Pattern::NoSupply
+            | Pattern::PiercingLine
+            | Pattern::TweezerBottom
+            | Pattern::BullishAbandonedBaby
+            | Pattern::RisingThreeMethods
+            | Pattern::UpsideTasukiGap
+            | Pattern::MatHold
+            | Pattern::StickSandwich
+            | Pattern::BullishTriStar
+            | Pattern::UpgapSideBySideWhiteLines
+            | Pattern::BullishBreakaway
+            | Pattern::BullishIslandReversal
+            | Pattern::BullishMeetingLines => Direction::Bullish,
+            Pattern::EveningStarDoji
+            | Pattern::EveningStar
+            | Pattern::ThreeBlackCrows
+            | Pattern::ThreeInsideDown
+            | Pattern::BearishEngulfing
+            | Pattern::BearishHarami
+            | Pattern::BearishDojiStar
+            | Pattern::BearishStar
+            | Pattern::BearishNearStar
+            | Pattern::Upthrust
+            | Pattern::NoDemand
+            | Pattern::DarkCloudCover
+            | Pattern::TweezerTop
+            | Pattern::ThreeOutsideDown
+            | Pattern::FallingThreeMethods
+            | Pattern::DownsideTasukiGap
+            | Pattern::BearishTriStar
+            | Pattern::InNeck
+            | Pattern::DowngapSideBySideWhiteLines
+            | Pattern::BearishIslandReversal
+            | Pattern::BearishMeetingLines => Direction::Bearish,
+        }
+    }
+
+    /// Returns true if the pattern anticipates the reversal of the prevailing trend
+    pub const fn is_reversal(&self) -> bool {
+        !self.is_continuation()
+    }
+
+    /// Returns true if the pattern anticipates that the move its candles form goes on.
+    ///
+    /// The candles either run in the anticipated direction, like Three White Soldiers, or pause
+    /// against it, like the Rising Three Methods or a No Supply bar on a pullback. Patterns
+    /// anticipating a turn against the move their candles form are reversals.
+    pub const fn is_continuation(&self) -> bool {
+        matches!(
+            self,
+            Pattern::ThreeWhiteSoldiers
+                | Pattern::ThreeBlackCrows
+                | Pattern::NoDemand
+                | Pattern::NoSupply
+                | Pattern::RisingThreeMethods
+                | Pattern::FallingThreeMethods
+                | Pattern::UpsideTasukiGap
+                | Pattern::DownsideTasukiGap
+                | Pattern::MatHold
+                | Pattern::InNeck
+                | Pattern::UpgapSideBySideWhiteLines
+                | Pattern::DowngapSideBySideWhiteLines
+        )
+    }
+
+    /// Returns the bullish reversal patterns
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::Pattern;
+    /// let patterns = Pattern::bullish_reversals();
+    /// assert!(patterns.contains(Pattern::MorningStar));
+    /// assert!(!patterns.contains(Pattern::EveningStar));
+    /// assert!(!patterns.contains(Pattern::NoSupply));
+    /// ```
+    pub fn bullish_reversals() -> PatternSet {
+        Self::select(|p| p.is_reversal() && p.direction() == Direction::Bullish)
+    }
+
+    /// Returns the bearish reversal patterns
+    pub fn bearish_reversals() -> PatternSet {
+        Self::select(|p| p.is_reversal() && p.direction() == Direction::Bearish)
+    }
+
+    /// Returns the bullish continuation patterns
+    pub fn bullish_continuations() -> PatternSet {
+        Self::select(|p| p.is_continuation() && p.direction() == Direction::Bullish)
+    }
+
+    /// Returns the bearish continuation patterns
+    pub fn bearish_continuations() -> PatternSet {
+        Self::select(|p| p.is_continuation() && p.direction() == Direction::Bearish)
+    }
+
+    /// Returns the patterns formed by exactly `n` candles
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::Pattern;
+    /// assert!(Pattern::with_candle_count(2).contains(Pattern::DarkCloudCover));
+    /// assert!(Pattern::with_candle_count(1).is_empty());
+    /// ```
+    pub fn with_candle_count(n: usize) -> PatternSet {
+        Self::select(|p| p.candle_count() == n)
+    }
+
+    // Returns the patterns satisfying the predicate
+    fn select(predicate: impl Fn(&Pattern) -> bool) -> PatternSet {
+        Pattern::ALL.iter().copied().filter(predicate).collect()
+    }
+}
+
+impl core::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A compact set of [`Pattern`]s, backed by a bitmask.
+///
+/// Iteration yields the patterns ordered by priority, highest first.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Pattern, PatternSet};
+/// let set = PatternSet::new()
+///     .with(Pattern::BullishHarami)
+///     .with(Pattern::MorningStar);
+/// assert!(set.contains(Pattern::MorningStar));
+/// assert_eq!(set.iter().next(), Some(Pattern::MorningStar));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternSet(u128);
+
+impl PatternSet {
+    /// Returns an empty set
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns a set containing every pattern
+    pub const fn all() -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+        while i < Pattern::ALL.len() {
+            set = set.with(Pattern::ALL[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns a copy of the set including the given pattern
+    pub const fn with(self, pattern: Pattern) -> Self {
+        Self(self.0 | 1 << pattern as u32)
+    }
+
+    /// Returns a copy of the set excluding the given pattern
+    pub const fn without(self, pattern: Pattern) -> Self {
+        Self(self.0 & !(1 << pattern as u32))
+    }
+
+    /// Adds a pattern to the set
+    pub fn insert(&mut self, pattern: Pattern) {
+        *self = self.with(pattern);
+    }
+
+    /// Removes a pattern from the set
+    pub fn remove(&mut self, pattern: Pattern) {
+        *self = self.without(pattern);
+    }
+
+    /// Returns true if the set contains the given pattern
+    pub const fn contains(&self, pattern: Pattern) -> bool {
+        self.0 & 1 << pattern as u32 != 0
+    }
+
+    /// Returns true if the set contains no pattern
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of patterns in the set
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the patterns present in either set
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the patterns present in this set but not in the other
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the highest-priority pattern of the set, if any
+    pub fn first(&self) -> Option<Pattern> {
+        self.iter().next()
+    }
+
+    /// Iterates over the patterns of the set, highest priority first
+    pub fn iter(&self) -> impl Iterator<Item = Pattern> + '_ {
+        Pattern::ALL.iter().copied().filter(|p| self.contains(*p))
+    }
+}
+
+impl FromIterator<Pattern> for PatternSet {
+    fn from_iter<I: IntoIterator<Item = Pattern>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+// Pattern sets are serialized as sequences of patterns, rather than as their bitmask
+#[cfg(feature = "serde")]
+impl serde::Serialize for PatternSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PatternSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Patterns;
+
+        impl<'de> serde::de::Visitor<'de> for Patterns {
+            type Value = PatternSet;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a sequence of patterns")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<PatternSet, A::Error> {
+                let mut set = PatternSet::new();
+                while let Some(pattern) = seq.next_element()? {
+                    set.insert(pattern);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(Patterns)
+    }
+}
+
+/// A suppression rule: whenever `dominant` is detected, the patterns in `suppressed` are
+/// considered redundant and dropped from the detection result.
+///
+/// Used with [`crate::CandleStream::detect_dominant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suppression {
+    /// The pattern taking precedence
+    pub dominant: Pattern,
+    /// The patterns implied by, or redundant with, the dominant one
+    pub suppressed: PatternSet,
+}
+
+impl Suppression {
+    /// Returns a new suppression rule
+    pub const fn new(dominant: Pattern, suppressed: PatternSet) -> Self {
+        Self {
+            dominant,
+            suppressed,
+        }
+    }
+
+    /// The default suppression table.
+    ///
+    /// Doji star variants suppress their generic star counterpart, and three-candle reversals
+    /// suppress the same-direction two-candle patterns completed by their last two candles.
+    pub const DEFAULTS: [Suppression; 8] = [
+        Suppression::new(
+            Pattern::MorningStarDoji,
+            PatternSet::new()
+                .with(Pattern::MorningStar)
+                .with(Pattern::BullishEngulfing)
+                .with(Pattern::BullishHarami),
+        ),
+        Suppression::new(
+            Pattern::EveningStarDoji,
+            PatternSet::new()
+                .with(Pattern::EveningStar)
+                .with(Pattern::BearishEngulfing)
+                .with(Pattern::BearishHarami)
+                .with(Pattern::DarkCloudCover),
+        ),
+        Suppression::new(
+            Pattern::MorningStar,
+            PatternSet::new()
+                .with(Pattern::BullishEngulfing)
+                .with(Pattern::BullishHarami),
+        ),
+        Suppression::new(
+            Pattern::EveningStar,
+            PatternSet::new()
+                .with(Pattern::BearishEngulfing)
+                .with(Pattern::BearishHarami)
+                .with(Pattern::DarkCloudCover),
+        ),
+        Suppression::new(
+            Pattern::ThreeInsideUp,
+            PatternSet::new()
+                .with(Pattern::BullishEngulfing)
+                .with(Pattern::BullishHarami),
+        ),
+        Suppression::new(
+            Pattern::ThreeInsideDown,
+            PatternSet::new()
+                .with(Pattern::BearishEngulfing)
+                .with(Pattern::BearishHarami),
+        ),
+        Suppression::new(
+            Pattern::BullishDojiStar,
+            PatternSet::new().with(Pattern::BullishStar),
+        ),
+        Suppression::new(
+            Pattern::BearishDojiStar,
+            PatternSet::new().with(Pattern::BearishStar),
+        ),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_stable() {
+        for &pattern in Pattern::ALL {
+            assert_eq!(Pattern::from_code(pattern.code()), Some(pattern));
+        }
+        assert_eq!(Pattern::from_code(0), None);
+
+        // persisted codes must never change
+        assert_eq!(Pattern::BullishEngulfing.code(), 9);
+        assert_eq!(Pattern::NoSupply.code(), 19);
+        assert_eq!(Pattern::BearishNearStar.code(), 23);
+    }
+
+    #[test]
+    fn test_taxonomy_groups_partition_all_patterns() {
+        let groups = [
+            Pattern::bullish_reversals(),
+            Pattern::bearish_reversals(),
+            Pattern::bullish_continuations(),
+            Pattern::bearish_continuations(),
+        ];
+
+        assert_eq!(
+            groups.iter().map(PatternSet::len).sum::<usize>(),
+            Pattern::ALL.len()
+        );
+        assert_eq!(
+            groups
+                .into_iter()
+                .fold(PatternSet::new(), PatternSet::union),
+            PatternSet::all()
+        );
+        assert_eq!(
+            Pattern::with_candle_count(2)
+                .union(Pattern::with_candle_count(3))
+                .union(Pattern::with_candle_count(5)),
+            PatternSet::all()
+        );
+
+        assert!(Pattern::bullish_continuations().contains(Pattern::ThreeWhiteSoldiers));
+        assert!(Pattern::bearish_continuations().contains(Pattern::ThreeBlackCrows));
+        assert!(!Pattern::ThreeWhiteSoldiers.is_reversal());
+        assert!(!Pattern::ThreeBlackCrows.is_reversal());
+    }
+}
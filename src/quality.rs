@@ -0,0 +1,150 @@
+use crate::{repair_outliers, CandleStick, Timestamped};
+
+// Number of preceding candles averaged by the outlier check
+const OUTLIER_PERIOD: usize = 14;
+// Minimum range of an outlier, in multiples of the average true range
+const OUTLIER_MULTIPLE: f64 = 5.0;
+
+/// Summary of the data issues found by [`quality_report`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct QualityReport {
+    /// Number of candles checked
+    pub candles: usize,
+    /// Candles with a non-finite price, a high below the open or close, or a low above them
+    pub ohlc_violations: usize,
+    /// Candles sharing the timestamp of the previous candle
+    pub duplicate_timestamps: usize,
+    /// Candles with a timestamp earlier than the previous candle's
+    pub non_monotonic_timestamps: usize,
+    /// Candles without any traded volume
+    pub zero_volume: usize,
+    /// Candles ranging over five times the average true range of the 14 preceding ones, see
+    /// [`repair_outliers`]
+    pub suspected_outliers: usize,
+    /// Index of the first candle with any of the issues above
+    pub first_issue: Option<usize>,
+}
+
+impl QualityReport {
+    /// Returns the total number of issues, a candle being counted once per issue
+    pub fn issues(&self) -> usize {
+        self.ohlc_violations
+            + self.duplicate_timestamps
+            + self.non_monotonic_timestamps
+            + self.zero_volume
+            + self.suspected_outliers
+    }
+
+    /// Returns true if no issue was found
+    pub fn is_clean(&self) -> bool {
+        self.issues() == 0
+    }
+}
+
+/// Checks a candle series for data issues, to assess a dataset before trusting pattern
+/// statistics computed from it.
+///
+/// Zero-volume candles are reported but are legitimate for sources without volume data, like
+/// most FX feeds.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{quality_report, Candle};
+/// let candles = [
+///     Candle::new(0, 100.0, 101.0, 99.0, 100.5, 10.0),
+///     Candle::new(60, 100.5, 100.0, 99.5, 101.0, 10.0), // high below the close
+///     Candle::new(60, 101.0, 101.5, 100.5, 101.2, 0.0), // duplicate, no volume
+/// ];
+/// let report = quality_report(&candles);
+/// assert_eq!(report.ohlc_violations, 1);
+/// assert_eq!(report.duplicate_timestamps, 1);
+/// assert_eq!(report.zero_volume, 1);
+/// assert_eq!(report.first_issue, Some(1));
+/// ```
+pub fn quality_report<T: CandleStick + Timestamped>(candles: &[T]) -> QualityReport {
+    let mut report = QualityReport {
+        candles: candles.len(),
+        ..QualityReport::default()
+    };
+    let mut flag = |count: &mut usize, idx: usize| {
+        *count += 1;
+        report.first_issue = Some(report.first_issue.map_or(idx, |first| first.min(idx)));
+    };
+
+    let mut ohlc_violations = 0;
+    let mut duplicates = 0;
+    let mut non_monotonic = 0;
+    let mut zero_volume = 0;
+    for (idx, c) in candles.iter().enumerate() {
+        let prices = [c.open(), c.high(), c.low(), c.close()];
+        if !prices.iter().all(|p| p.is_finite())
+            || c.high() < c.open().max(c.close())
+            || c.low() > c.open().min(c.close())
+        {
+            flag(&mut ohlc_violations, idx);
+        }
+        if c.volume() == 0.0 {
+            flag(&mut zero_volume, idx);
+        }
+
+        let Some(prev) = idx.checked_sub(1).and_then(|i| candles.get(i)) else {
+            continue;
+        };
+        if c.timestamp() == prev.timestamp() {
+            flag(&mut duplicates, idx);
+        } else if c.timestamp() < prev.timestamp() {
+            flag(&mut non_monotonic, idx);
+        }
+    }
+
+    let mut outliers = 0;
+    for repair in repair_outliers::<_, OUTLIER_PERIOD>(candles, OUTLIER_MULTIPLE)
+        .filter_map(|(_, repair)| repair)
+    {
+        flag(&mut outliers, repair.index);
+    }
+
+    QualityReport {
+        ohlc_violations,
+        duplicate_timestamps: duplicates,
+        non_monotonic_timestamps: non_monotonic,
+        zero_volume,
+        suspected_outliers: outliers,
+        ..report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+
+    #[test]
+    fn test_quality_report() {
+        let mut candles: [Candle; 20] = core::array::from_fn(|i| {
+            let open = 100.0 + i as f64 * 0.1;
+            Candle::new(
+                i as i64 * 60,
+                open,
+                open + 1.0,
+                open - 1.0,
+                open + 0.1,
+                10.0,
+            )
+        });
+        assert!(quality_report(&candles).is_clean());
+        assert_eq!(quality_report(&candles).candles, 20);
+
+        candles[16].high = 140.0;
+        candles[17].timestamp = candles[15].timestamp;
+        candles[18].close = f64::NAN;
+
+        let report = quality_report(&candles);
+        assert_eq!(report.suspected_outliers, 1);
+        assert_eq!(report.non_monotonic_timestamps, 1);
+        assert_eq!(report.duplicate_timestamps, 0);
+        assert_eq!(report.ohlc_violations, 1);
+        assert_eq!(report.first_issue, Some(16));
+        assert_eq!(report.issues(), 3);
+    }
+}
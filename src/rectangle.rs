@@ -0,0 +1,186 @@
+use crate::{CandleStick, CandleStream, Direction};
+
+/// A rectangle, or trading range, chart pattern, as returned by [`CandleStream::rectangle`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    /// The top of the box, the highest of the swing highs
+    pub resistance: f64,
+    /// The bottom of the box, the lowest of the swing lows
+    pub support: f64,
+    /// Position of the oldest touch, where the box starts, `0` being the latest candle
+    pub start: usize,
+    /// Direction of the breakout if the latest candle closed outside of the box
+    pub breakout: Option<Direction>,
+}
+
+impl Rectangle {
+    /// Returns the height of the box
+    pub fn height(&self) -> f64 {
+        self.resistance - self.support
+    }
+
+    /// Returns true if the price lies within the box
+    pub fn contains(&self, price: f64) -> bool {
+        self.support <= price && price <= self.resistance
+    }
+
+    /// Returns the measured move target of the breakout: the box height projected beyond the
+    /// broken level
+    pub fn target(&self) -> Option<f64> {
+        self.breakout.map(|direction| match direction {
+            Direction::Bullish => self.resistance + self.height(),
+            Direction::Bearish => self.support - self.height(),
+        })
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Rectangle, a horizontal consolidation chart pattern bounded by parallel
+    /// support and resistance levels, each touched by the latest `touches` swing highs and
+    /// swing lows of the given `strength`.
+    ///
+    /// The touches of each side must be equal within `tolerance`, a fraction of the level
+    /// (e.g. `0.005` for 0.5%), and every candle from the oldest touch up to the latest one
+    /// must close within the box. The latest candle is checked for the breakout.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a balance between buyers and sellers, usually a pause within a trend
+    /// - Range traders fade the boundaries until one of them gives way
+    /// - A close outside the box often travels about the height of the box
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction};
+    /// let candles = [
+    ///     (102.0, 105.0, 101.0, 104.0, 0.0),
+    ///     (104.0, 110.0, 104.0, 108.5, 0.0),
+    ///     (106.0, 106.0, 100.2, 101.0, 0.0),
+    ///     (101.0, 104.0, 100.0, 103.0, 0.0),
+    ///     (103.0, 108.0, 103.0, 107.0, 0.0),
+    ///     (107.0, 109.8, 105.0, 106.0, 0.0),
+    ///     (106.0, 106.0, 101.0, 102.0, 0.0),
+    ///     (102.0, 103.0, 100.1, 102.5, 0.0),
+    ///     (102.5, 107.0, 102.0, 106.0, 0.0),
+    ///     (106.0, 110.1, 104.0, 109.0, 0.0),
+    ///     (109.0, 109.5, 103.6, 104.0, 0.0),
+    ///     (104.0, 111.5, 103.5, 111.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let range = series.rectangle(1, 2, 0.005).unwrap();
+    /// assert_eq!((range.resistance, range.support, range.start), (110.1, 100.0, 8));
+    /// assert_eq!(range.breakout, Some(Direction::Bullish));
+    /// ```
+    pub fn rectangle(&self, strength: usize, touches: usize, tolerance: f64) -> Option<Rectangle> {
+        if touches < 2 {
+            return None;
+        }
+
+        let highs = || self.swing_highs(strength).take(touches);
+        let lows = || self.swing_lows(strength).take(touches);
+        if highs().count() < touches || lows().count() < touches {
+            return None;
+        }
+
+        let resistance = highs().map(|s| s.price).reduce(f64::max)?;
+        let support = lows().map(|s| s.price).reduce(f64::min)?;
+        let flat_top = highs().all(|s| resistance - s.price <= tolerance * resistance.abs());
+        let flat_bottom = lows().all(|s| s.price - support <= tolerance * support.abs());
+        if !flat_top || !flat_bottom || resistance <= support {
+            return None;
+        }
+
+        let start = highs().chain(lows()).map(|s| s.bars_ago).max()?;
+        let mut inside = (1..=start).filter_map(|n| self.prev(n));
+        if !inside.all(|c| support <= c.close() && c.close() <= resistance) {
+            return None;
+        }
+
+        let close = self.get()?.close();
+        Some(Rectangle {
+            resistance,
+            support,
+            start,
+            breakout: match (close > resistance, close < support) {
+                (true, _) => Some(Direction::Bullish),
+                (_, true) => Some(Direction::Bearish),
+                _ => None,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const RANGE: [(f64, f64); 11] = [
+        (105.0, 101.0),
+        (110.0, 104.0),
+        (106.0, 100.2),
+        (104.0, 100.0),
+        (108.0, 103.0),
+        (109.8, 105.0),
+        (106.0, 101.0),
+        (103.0, 100.1),
+        (107.0, 102.0),
+        (110.1, 104.0),
+        (105.0, 103.8),
+    ];
+
+    fn build(shape: [(f64, f64); 11], latest: Ohlcv) -> [Ohlcv; 12] {
+        let mut candles = [latest; 12];
+        for (slot, bar) in candles.iter_mut().zip(shape) {
+            *slot = candle(bar);
+        }
+        candles
+    }
+
+    fn rectangle(candles: &[Ohlcv], touches: usize) -> Option<Rectangle> {
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.rectangle(1, touches, 0.005)
+    }
+
+    #[test]
+    fn test_rectangle() {
+        let inside = build(RANGE, (104.0, 106.0, 103.5, 105.5, 0.0));
+        let breakdown = build(RANGE, (102.0, 102.5, 98.5, 99.0, 0.0));
+
+        let range = rectangle(&inside, 2);
+        assert!(range.is_some_and(|r| (r.resistance, r.support, r.start) == (110.1, 100.0, 8)));
+        assert!(range.is_some_and(|r| r.breakout.is_none() && r.contains(105.5)));
+        assert!(range.is_some_and(|r| (r.height() - 10.1).abs() < 1e-9));
+        assert!(range.is_some_and(|r| r.target().is_none()));
+
+        let range = rectangle(&breakdown, 2);
+        assert!(range.is_some_and(|r| r.breakout == Some(Direction::Bearish)));
+        assert!(range.is_some_and(|r| r.target().is_some_and(|t| (t - 89.9).abs() < 1e-9)));
+    }
+
+    #[test]
+    fn test_rectangle_constraints() {
+        let mut sloped = RANGE;
+        sloped[9] = (113.0, 104.0);
+        let mut broken = RANGE;
+        broken[9] = (112.0, 104.0);
+        broken[10] = (112.0, 110.0);
+        let latest = (104.0, 106.0, 103.5, 105.5, 0.0);
+
+        assert_eq!(rectangle(&build(RANGE, latest), 3), None);
+        assert_eq!(rectangle(&build(RANGE, latest), 1), None);
+        assert_eq!(rectangle(&build(sloped, latest), 2), None);
+        assert_eq!(rectangle(&build(broken, latest), 2), None);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{CandleStick, CandleStream};
+
+/// The market regime, as returned by [`CandleStream::regime`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Regime {
+    /// Prices advance in an orderly trend
+    TrendingUp,
+    /// Prices decline in an orderly trend
+    TrendingDown,
+    /// Prices move sideways or too erratically to call a trend
+    Ranging,
+}
+
+/// Thresholds of the trend measures, used by [`CandleStream::regime`]
+///
+/// A measure is disabled by setting its threshold to `0.0`, or `1.0` for `max_overlap`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RegimeRules {
+    /// Number of candles measured, including the latest one
+    pub period: usize,
+    /// Minimum directional efficiency, the net change of the closes over the sum of their
+    /// absolute changes
+    pub min_efficiency: f64,
+    /// Maximum average overlap of consecutive candle ranges, as a fraction of their combined
+    /// range
+    pub max_overlap: f64,
+    /// Minimum R² of the regression of the closes
+    pub min_r_squared: f64,
+}
+
+impl Default for RegimeRules {
+    fn default() -> Self {
+        Self {
+            period: 20,
+            min_efficiency: 0.3,
+            max_overlap: 0.6,
+            min_r_squared: 0.5,
+        }
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Classifies the last `period` candles as trending up, trending down or ranging.
+    ///
+    /// The market is trending when the directional efficiency, the overlap of consecutive
+    /// candles and the R² of the regression of the closes all pass their thresholds, in the
+    /// direction of the regression slope. Returns `None` if fewer than `period` candles are
+    /// available or `period` is lower than three.
+    ///
+    /// Pattern reliability depends on the regime: reversals are best traded against a trend
+    /// about to exhaust, and breakouts of consolidations out of ranges.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Regime, RegimeRules};
+    /// let candles = [
+    ///     (100.0, 101.5, 99.5, 101.0, 0.0),
+    ///     (101.0, 102.5, 100.8, 102.0, 0.0),
+    ///     (102.0, 103.5, 101.8, 103.0, 0.0),
+    ///     (103.0, 104.5, 102.8, 104.0, 0.0),
+    ///     (104.0, 105.5, 103.8, 105.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let rules = RegimeRules {
+    ///     period: 5,
+    ///     ..RegimeRules::default()
+    /// };
+    /// assert_eq!(series.regime(rules), Some(Regime::TrendingUp));
+    /// ```
+    pub fn regime(&self, rules: RegimeRules) -> Option<Regime> {
+        let fit = self.regression(rules.period)?;
+        let candles = || (0..rules.period).filter_map(|n| self.prev(n));
+        let pairs = || candles().zip(candles().skip(1));
+
+        let path = pairs()
+            .map(|(c, prev)| (c.close() - prev.close()).abs())
+            .sum::<f64>();
+        let efficiency = match path {
+            0.0 => 0.0,
+            _ => (self.get()?.close() - self.prev(rules.period - 1)?.close()).abs() / path,
+        };
+
+        let overlap = pairs()
+            .map(|(c, prev)| {
+                let shared = c.high().min(prev.high()) - c.low().max(prev.low());
+                let combined = c.high().max(prev.high()) - c.low().min(prev.low());
+                match combined {
+                    0.0 => 1.0,
+                    _ => shared.max(0.0) / combined,
+                }
+            })
+            .sum::<f64>()
+            / (rules.period - 1) as f64;
+
+        let trending = efficiency >= rules.min_efficiency
+            && overlap <= rules.max_overlap
+            && fit.r_squared >= rules.min_r_squared;
+
+        Some(match (trending, fit.line.slope) {
+            (true, slope) if slope > 0.0 => Regime::TrendingUp,
+            (true, slope) if slope < 0.0 => Regime::TrendingDown,
+            _ => Regime::Ranging,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    const RULES: RegimeRules = RegimeRules {
+        period: 10,
+        min_efficiency: 0.3,
+        max_overlap: 0.6,
+        min_r_squared: 0.5,
+    };
+
+    fn trend(step: f64) -> [Ohlcv; 10] {
+        core::array::from_fn(|i| {
+            let base = 100.0 + step * i as f64;
+            (base, base + 0.8, base - 0.2, base + 0.6, 0.0)
+        })
+    }
+
+    #[test]
+    fn test_trending() {
+        let up = trend(1.0);
+        assert_eq!(series(&up).regime(RULES), Some(Regime::TrendingUp));
+
+        let down = trend(-1.0);
+        assert_eq!(series(&down).regime(RULES), Some(Regime::TrendingDown));
+        assert_eq!(series(&down[..9]).regime(RULES), None);
+    }
+
+    #[test]
+    fn test_ranging() {
+        let range: [Ohlcv; 10] = core::array::from_fn(|i| match i % 2 {
+            0 => (100.5, 102.0, 100.0, 101.5, 0.0),
+            _ => (101.5, 102.2, 100.2, 100.6, 0.0),
+        });
+        assert_eq!(series(&range).regime(RULES), Some(Regime::Ranging));
+
+        // a slow grind with overlapping candles is ranging until the overlap check is relaxed
+        let grind = trend(0.2);
+        assert_eq!(series(&grind).regime(RULES), Some(Regime::Ranging));
+        let relaxed = RegimeRules {
+            max_overlap: 1.0,
+            ..RULES
+        };
+        assert_eq!(series(&grind).regime(relaxed), Some(Regime::TrendingUp));
+    }
+}
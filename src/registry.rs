@@ -0,0 +1,162 @@
+use crate::CandleStream;
+
+/// A user-defined pattern detector evaluated against a [`CandleStream`]
+pub type CustomDetector<'r, T> = &'r dyn Fn(&CandleStream<'_, T>) -> bool;
+
+/// A fixed-capacity registry of user-defined patterns.
+///
+/// Custom detectors are registered under a name and evaluated alongside the built-in
+/// patterns, without any allocation: the registry holds up to `N` detectors.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStick, CandleStream, Registry};
+/// let two_bulls = |s: &CandleStream<'_, (f64, f64, f64, f64, f64)>| {
+///     s.get().zip(s.prev(1)).is_some_and(|(c, p)| c.is_bullish() && p.is_bullish())
+/// };
+///
+/// let mut registry = Registry::<_, 4>::new();
+/// assert!(registry.register("two_bulls", &two_bulls));
+///
+/// let prev = (100.0, 105.0, 99.0, 104.0, 0.0);
+/// let curr = (104.5, 110.0, 104.0, 109.0, 0.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+/// assert_eq!(registry.detect(&series).next(), Some("two_bulls"));
+/// ```
+pub struct Registry<'r, T, const N: usize> {
+    detectors: [Option<(&'static str, CustomDetector<'r, T>)>; N],
+    len: usize,
+}
+
+impl<'r, T, const N: usize> Registry<'r, T, N> {
+    /// Returns an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a detector under the given name, replacing any detector with the same name.
+    ///
+    /// Returns false if the registry is full.
+    pub fn register(&mut self, name: &'static str, detector: CustomDetector<'r, T>) -> bool {
+        let slot = match self.position(name) {
+            Some(idx) => idx,
+            None if self.len < N => {
+                self.len += 1;
+                self.len - 1
+            }
+            None => return false,
+        };
+
+        self.detectors[slot] = Some((name, detector));
+        true
+    }
+
+    /// Returns true if a detector is registered under the given name
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Returns the number of registered detectors
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no detector is registered
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Evaluates a single registered detector by name, returns false if unknown
+    pub fn is_pattern(&self, name: &str, stream: &CandleStream<'_, T>) -> bool {
+        self.entries()
+            .find(|(n, _)| *n == name)
+            .is_some_and(|(_, detector)| detector(stream))
+    }
+
+    /// Returns the names of the registered detectors matching the stream, in registration order
+    pub fn detect<'a>(
+        &'a self,
+        stream: &'a CandleStream<'_, T>,
+    ) -> impl Iterator<Item = &'static str> + 'a {
+        self.entries()
+            .filter(move |(_, detector)| detector(stream))
+            .map(|(name, _)| name)
+    }
+
+    // Iterates over the registered detectors
+    fn entries(&self) -> impl Iterator<Item = (&'static str, CustomDetector<'r, T>)> + '_ {
+        self.detectors[..self.len].iter().flatten().copied()
+    }
+
+    // Returns the slot of the detector registered under the given name
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries().position(|(n, _)| n == name)
+    }
+}
+
+impl<T, const N: usize> Default for Registry<'_, T, N> {
+    fn default() -> Self {
+        Self {
+            detectors: [const { None }; N],
+            len: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for Registry<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.entries().map(|(name, _)| name))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CandleStick;
+
+    type Candle = (f64, f64, f64, f64, f64);
+
+    #[test]
+    fn test_register() {
+        let always = |_: &CandleStream<'_, Candle>| true;
+        let never = |_: &CandleStream<'_, Candle>| false;
+
+        let mut registry = Registry::<Candle, 2>::new();
+        assert!(registry.is_empty());
+
+        assert!(registry.register("always", &always));
+        assert!(registry.register("never", &never));
+        assert!(registry.register("always", &never));
+        assert!(!registry.register("other", &always));
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains("always"));
+        assert!(!registry.contains("other"));
+    }
+
+    #[test]
+    fn test_detect() {
+        let bullish = |s: &CandleStream<'_, Candle>| s.get().is_some_and(|c| c.is_bullish());
+        let bearish = |s: &CandleStream<'_, Candle>| s.get().is_some_and(|c| c.is_bearish());
+
+        let mut registry = Registry::<Candle, 4>::new();
+        registry.register("bullish", &bullish);
+        registry.register("bearish", &bearish);
+
+        let candle = (100.0, 105.0, 99.0, 104.0, 0.0);
+        let mut series = CandleStream::new();
+        assert_eq!(registry.detect(&series).next(), None);
+
+        series.push(&candle);
+
+        let mut detected = registry.detect(&series);
+        assert_eq!(detected.next(), Some("bullish"));
+        assert_eq!(detected.next(), None);
+        assert!(registry.is_pattern("bullish", &series));
+        assert!(!registry.is_pattern("bearish", &series));
+        assert!(!registry.is_pattern("unknown", &series));
+    }
+}
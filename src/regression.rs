@@ -0,0 +1,139 @@
+use crate::{CandleStick, CandleStream, Trendline};
+
+/// A least squares fit of the closes over a window of candles, as returned by
+/// [`CandleStream::regression`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    /// The fitted line, its slope per candle and its intercept at the latest candle
+    pub line: Trendline,
+    /// Coefficient of determination, the share of the close variance explained by the line
+    pub r_squared: f64,
+    /// Variance of the closes around the line, the squared standard error of the fit
+    pub variance: f64,
+}
+
+impl Regression {
+    /// Returns the standard error of the fit, the typical distance of a close to the line
+    #[cfg(feature = "std")]
+    pub fn standard_error(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// Returns the upper and lower lines of the channel `k` standard errors around the fit
+    #[cfg(feature = "std")]
+    pub fn channel(&self, k: f64) -> (Trendline, Trendline) {
+        let offset = k * self.standard_error();
+        let shifted = |offset: f64| Trendline {
+            slope: self.line.slope,
+            level: self.line.level + offset,
+        };
+
+        (shifted(offset), shifted(-offset))
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the linear regression of the closes of the last `n` candles, including the
+    /// latest one.
+    ///
+    /// Returns `None` if `n` is lower than three or fewer than `n` candles are available. The
+    /// slope measures the trend and the R² how orderly it is, a quantitative filter for
+    /// reversal patterns: a bearish reversal carries more weight after a steep, clean advance.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (99.5, 100.5, 99.0, 100.0, 0.0),
+    ///     (100.0, 101.5, 99.5, 101.0, 0.0),
+    ///     (101.0, 102.5, 100.5, 102.0, 0.0),
+    ///     (102.0, 103.5, 101.5, 103.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let fit = series.regression(4).unwrap();
+    /// assert_eq!((fit.line.slope, fit.line.level), (1.0, 103.0));
+    /// assert_eq!((fit.r_squared, fit.variance), (1.0, 0.0));
+    /// ```
+    pub fn regression(&self, n: usize) -> Option<Regression> {
+        if n < 3 {
+            return None;
+        }
+        self.prev(n - 1)?;
+
+        let closes =
+            || (0..n).filter_map(|bars_ago| Some((bars_ago, self.prev(bars_ago)?.close())));
+        let line = Trendline::fit(closes())?;
+
+        let mean = closes().map(|(_, close)| close).sum::<f64>() / n as f64;
+        let (residual, total) =
+            closes().fold((0.0, 0.0), |(residual, total), (bars_ago, close)| {
+                let error = close - line.value_at(bars_ago);
+                (
+                    residual + error * error,
+                    total + (close - mean) * (close - mean),
+                )
+            });
+
+        Some(Regression {
+            line,
+            r_squared: match total {
+                0.0 => 1.0,
+                _ => 1.0 - residual / total,
+            },
+            variance: residual / (n - 2) as f64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANDLES: [(f64, f64, f64, f64, f64); 5] = [
+        (99.0, 101.0, 98.0, 100.0, 0.0),
+        (100.0, 103.0, 99.0, 102.0, 0.0),
+        (102.0, 102.5, 100.5, 101.0, 0.0),
+        (101.0, 104.0, 100.0, 103.0, 0.0),
+        (103.0, 104.5, 101.5, 104.0, 0.0),
+    ];
+
+    fn series() -> CandleStream<'static, (f64, f64, f64, f64, f64)> {
+        let mut series = CandleStream::new();
+        for candle in &CANDLES {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_regression() {
+        let fit = series().regression(5);
+
+        assert!(fit.is_some_and(|f| (f.line.slope - 0.9).abs() < 1e-9));
+        assert!(fit.is_some_and(|f| (f.line.level - 103.8).abs() < 1e-9));
+        assert!(fit.is_some_and(|f| (f.r_squared - 0.81).abs() < 1e-9));
+        assert!(fit.is_some_and(|f| (f.variance - 0.633_333_333).abs() < 1e-6));
+        assert_eq!(series().regression(2), None);
+        assert_eq!(series().regression(6), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_channel() {
+        let flat = [(100.0, 101.0, 99.0, 100.0, 0.0); 3];
+        let mut flat_series = CandleStream::new();
+        for candle in &flat {
+            flat_series.push(candle);
+        }
+
+        let fit = flat_series.regression(3);
+        assert!(fit.is_some_and(|f| f.r_squared == 1.0 && f.standard_error() == 0.0));
+
+        let channel = series().regression(5).map(|f| f.channel(2.0));
+        assert!(channel.is_some_and(|(upper, lower)| upper.level > 103.8 && lower.level < 103.8));
+        assert!(channel.is_some_and(|(upper, lower)| upper.slope == lower.slope));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{utils::midpoint, Candle, CandleStick, Timestamped};
+
+/// Record of a candle modified by [`repair_outliers`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Repair {
+    /// Position of the candle in the input series
+    pub index: usize,
+    /// The candle before repair
+    pub original: Candle,
+    /// Average true range of the preceding candles when the candle was repaired
+    pub atr: f64,
+}
+
+/// Iterator over a cleaned candle series, as returned by [`repair_outliers`]
+#[derive(Debug)]
+pub struct RepairOutliers<'a, T, const N: usize> {
+    candles: &'a [T],
+    multiple: f64,
+    idx: usize,
+    true_ranges: [f64; N],
+    prev_close: Option<f64>,
+}
+
+/// Cleans a candle series from bad prints, clamping the shadows of every candle whose range
+/// exceeds `multiple` times the average true range of the `N` preceding candles.
+///
+/// The clamped range is centered on the candle body and never cuts into it. Each cleaned candle
+/// is yielded along with a [`Repair`] record when it was modified. The average true range is
+/// computed from the cleaned candles, so a bad print doesn't widen the tolerance of the
+/// following ones, and no candle is repaired before `N` candles have been seen.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{repair_outliers, Candle};
+/// let candles = [
+///     Candle::new(0, 100.0, 101.0, 99.0, 100.5, 0.0),
+///     Candle::new(1, 100.5, 101.5, 99.5, 101.0, 0.0),
+///     Candle::new(2, 101.0, 150.0, 100.5, 101.5, 0.0), // bad print
+/// ];
+/// let cleaned: Vec<_> = repair_outliers::<_, 2>(&candles, 3.0).collect();
+/// assert!(cleaned[0].1.is_none() && cleaned[1].1.is_none());
+/// assert_eq!(cleaned[2].1.map(|r| r.index), Some(2));
+/// assert!(cleaned[2].0.high < 110.0);
+/// ```
+pub fn repair_outliers<T, const N: usize>(
+    candles: &[T],
+    multiple: f64,
+) -> RepairOutliers<'_, T, N> {
+    RepairOutliers {
+        candles,
+        multiple,
+        idx: 0,
+        true_ranges: [0.0; N],
+        prev_close: None,
+    }
+}
+
+impl<T: CandleStick + Timestamped, const N: usize> Iterator for RepairOutliers<'_, T, N> {
+    type Item = (Candle, Option<Repair>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let original = Candle::from(self.candles.get(self.idx)?);
+        let index = self.idx;
+        self.idx += 1;
+
+        let atr = self.true_ranges.iter().sum::<f64>() / N as f64;
+        let allowed = self.multiple * atr;
+
+        let (candle, repair) = match N > 0 && index >= N && original.high - original.low > allowed {
+            true => {
+                let mid = midpoint(original.open, original.close);
+                let candle = Candle {
+                    high: (mid + allowed / 2.0)
+                        .min(original.high)
+                        .max(original.open.max(original.close)),
+                    low: (mid - allowed / 2.0)
+                        .max(original.low)
+                        .min(original.open.min(original.close)),
+                    ..original
+                };
+                (
+                    candle,
+                    Some(Repair {
+                        index,
+                        original,
+                        atr,
+                    }),
+                )
+            }
+            false => (original, None),
+        };
+
+        if N > 0 {
+            let prev_close = self.prev_close.unwrap_or(candle.close);
+            self.true_ranges[index % N] = candle.high.max(prev_close) - candle.low.min(prev_close);
+        }
+        self.prev_close = Some(candle.close);
+
+        Some((candle, repair))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_outliers() {
+        let candles = [
+            Candle::new(0, 100.0, 101.0, 99.0, 100.5, 0.0),
+            Candle::new(1, 100.5, 101.5, 99.5, 101.0, 0.0),
+            Candle::new(2, 101.0, 150.0, 100.5, 101.5, 0.0),
+            Candle::new(3, 101.5, 102.0, 100.5, 101.0, 0.0),
+        ];
+
+        let mut cleaned = repair_outliers::<_, 2>(&candles, 3.0);
+
+        assert_eq!(cleaned.next(), Some((candles[0], None)));
+        assert_eq!(cleaned.next(), Some((candles[1], None)));
+
+        let (candle, repair) = cleaned.next().unwrap_or_default();
+        let atr = (2.0 + 2.0) / 2.0;
+        assert_eq!(
+            repair,
+            Some(Repair {
+                index: 2,
+                original: candles[2],
+                atr
+            })
+        );
+        assert_eq!(candle.high, 101.25 + 3.0);
+        assert_eq!(candle.low, 100.5);
+        assert_eq!((candle.open, candle.close), (101.0, 101.5));
+
+        assert_eq!(cleaned.next(), Some((candles[3], None)));
+        assert_eq!(cleaned.next(), None);
+    }
+
+    #[test]
+    fn test_repair_outliers_keeps_body() {
+        let candles = [
+            Candle::new(0, 100.0, 101.0, 99.0, 100.5, 0.0),
+            Candle::new(1, 100.5, 130.0, 99.5, 129.0, 0.0),
+        ];
+
+        let (candle, repair) = repair_outliers::<_, 1>(&candles, 2.0)
+            .nth(1)
+            .unwrap_or_default();
+
+        assert!(repair.is_some());
+        assert_eq!(candle.low, 100.5);
+        assert_eq!(candle.high, 129.0);
+    }
+}
@@ -0,0 +1,90 @@
+/// Monotonic deque tracking the maximum of the last `N` values pushed.
+///
+/// Values are tagged with the sequence number of the candle they belong to. Entries are kept
+/// in increasing sequence order and decreasing value order, so each push is amortized O(1)
+/// and the maximum of any suffix of the window is found with a binary search.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MonotonicDeque<const N: usize> {
+    entries: [(usize, f64); N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> MonotonicDeque<N> {
+    /// Returns an empty deque
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [(0, 0.0); N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Pushes the value of the candle with the given sequence number
+    pub(crate) fn push(&mut self, seq: usize, value: f64) {
+        while self.len > 0 && seq - self.entry(0).0 >= N {
+            self.head = (self.head + 1) % N;
+            self.len -= 1;
+        }
+
+        while self.len > 0 && self.entry(self.len - 1).1 <= value {
+            self.len -= 1;
+        }
+
+        self.entries[(self.head + self.len) % N] = (seq, value);
+        self.len += 1;
+    }
+
+    /// Returns the maximum of the values with a sequence number greater or equal to `since`
+    pub(crate) fn max_since(&self, since: usize) -> Option<f64> {
+        let (mut lo, mut hi) = (0, self.len);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            match self.entry(mid).0 < since {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+
+        match lo < self.len {
+            true => Some(self.entry(lo).1),
+            false => None,
+        }
+    }
+
+    // Returns the entry at the given logical position
+    fn entry(&self, pos: usize) -> (usize, f64) {
+        self.entries[(self.head + pos) % N]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_since() {
+        let mut deque = MonotonicDeque::<3>::new();
+        assert_eq!(deque.max_since(0), None);
+
+        deque.push(0, 5.0);
+        deque.push(1, 3.0);
+        deque.push(2, 4.0);
+
+        assert_eq!(deque.max_since(0), Some(5.0));
+        assert_eq!(deque.max_since(1), Some(4.0));
+        assert_eq!(deque.max_since(2), Some(4.0));
+        assert_eq!(deque.max_since(3), None);
+
+        deque.push(3, 1.0);
+
+        assert_eq!(deque.max_since(1), Some(4.0));
+        assert_eq!(deque.max_since(3), Some(1.0));
+
+        deque.push(4, 2.0);
+        deque.push(5, 0.5);
+
+        assert_eq!(deque.max_since(3), Some(2.0));
+        assert_eq!(deque.max_since(5), Some(0.5));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{CandleStick, CandleStream};
+
+/// A rounding bottom, or saucer, chart pattern, as returned by [`CandleStream::rounding_bottom`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundingBottom {
+    /// Position of the lowest point of the fitted curve, in candles back from the latest one
+    pub vertex: usize,
+    /// The lowest smoothed low
+    pub bottom: f64,
+    /// Second order coefficient of the curve fitted to the smoothed lows, always positive
+    pub curvature: f64,
+    /// Coefficient of determination of the fitted curve, `1.0` for a perfect saucer
+    pub r_squared: f64,
+    /// The highest high before the bottom, the left lip of the saucer
+    pub rim: f64,
+    /// True if the latest candle closed above the rim
+    pub broken_out: bool,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Rounding Bottom, or Saucer, a slow bullish reversal chart pattern over the
+    /// latest `bars` candles, meant for daily and weekly charts.
+    ///
+    /// The lows are smoothed with a moving average over `smoothing` candles, which requires
+    /// `bars + smoothing - 1` buffered candles, then fitted with a parabola by least squares.
+    /// The curve must open upward with its lowest point in the middle third of the window,
+    /// and the base must be rounded: at least a third of the smoothed lows sit in the lowest
+    /// quarter of the depth, which rules out sharp V-shaped bottoms. Noisy bases still fit a
+    /// curve, filter on [`RoundingBottom::r_squared`] to keep the clean ones.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a gradual shift from selling to buying pressure over a long period
+    /// - Volume usually dries up at the bottom and expands on the right side
+    /// - A close above the rim completes the pattern
+    pub fn rounding_bottom(&self, bars: usize, smoothing: usize) -> Option<RoundingBottom> {
+        if bars < 5 || smoothing == 0 {
+            return None;
+        }
+        self.prev(bars + smoothing - 2)?;
+
+        let smoothed = |bars_ago: usize| {
+            (bars_ago..bars_ago + smoothing)
+                .map(|n| self.prev(n).map(T::low))
+                .sum::<Option<f64>>()
+                .map(|sum| sum / smoothing as f64)
+        };
+        let center = (bars - 1) as f64 / 2.0;
+        let points = || (0..bars).filter_map(|n| Some((n as f64 - center, smoothed(n)?)));
+
+        let curve = Parabola::fit(points())?;
+        if curve.a <= 0.0 {
+            return None;
+        }
+
+        let vertex = -curve.b / (2.0 * curve.a);
+        if vertex.abs() > bars as f64 / 6.0 {
+            return None;
+        }
+
+        let bottom = points().map(|(_, low)| low).reduce(f64::min)?;
+        let edge = smoothed(0)?.min(smoothed(bars - 1)?);
+        let base = bottom + (edge - bottom) / 4.0;
+        if points().filter(|(_, low)| *low <= base).count() * 3 < bars {
+            return None;
+        }
+
+        let vertex = (vertex + center + 0.5) as usize;
+        let rim = (vertex + 1..bars)
+            .filter_map(|n| self.prev(n))
+            .map(T::high)
+            .reduce(f64::max)?;
+
+        Some(RoundingBottom {
+            vertex,
+            bottom,
+            curvature: curve.a,
+            r_squared: curve.r_squared(points()),
+            rim,
+            broken_out: self.get()?.close() > rim,
+        })
+    }
+}
+
+// y = a * x^2 + b * x + c, fitted on positions centered around zero
+struct Parabola {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Parabola {
+    // Least squares fit, the odd moments vanish on centered positions
+    fn fit(points: impl Iterator<Item = (f64, f64)>) -> Option<Self> {
+        let (n, s2, s4, sy, sxy, sx2y) = points.fold(
+            (0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+            |(n, s2, s4, sy, sxy, sx2y), (x, y)| {
+                let x2 = x * x;
+                (
+                    n + 1.0,
+                    s2 + x2,
+                    s4 + x2 * x2,
+                    sy + y,
+                    sxy + x * y,
+                    sx2y + x2 * y,
+                )
+            },
+        );
+
+        let determinant = n * s4 - s2 * s2;
+        if s2 == 0.0 || determinant == 0.0 {
+            return None;
+        }
+
+        let a = (n * sx2y - s2 * sy) / determinant;
+        Some(Self {
+            a,
+            b: sxy / s2,
+            c: (sy - a * s2) / n,
+        })
+    }
+
+    fn value(&self, x: f64) -> f64 {
+        (self.a * x + self.b) * x + self.c
+    }
+
+    fn r_squared(&self, points: impl Iterator<Item = (f64, f64)> + Clone) -> f64 {
+        let (n, sum) = points
+            .clone()
+            .fold((0.0, 0.0), |(n, sum), (_, y)| (n + 1.0, sum + y));
+        let mean = sum / n;
+        let (residual, total) = points.fold((0.0, 0.0), |(residual, total), (x, y)| {
+            let error = y - self.value(x);
+            (residual + error * error, total + (y - mean) * (y - mean))
+        });
+
+        match total {
+            0.0 => 1.0,
+            _ => 1.0 - residual / total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    // 23 candles, oldest first, with lows following `shape` around the middle one
+    fn build(shape: impl Fn(f64) -> f64) -> [Ohlcv; 23] {
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 23];
+        for (i, slot) in candles.iter_mut().enumerate() {
+            let low = shape(12.0 - i as f64);
+            *slot = (low + 0.5, low + 2.0, low, low + 1.5, 0.0);
+        }
+        candles
+    }
+
+    fn rounding_bottom(candles: &[Ohlcv]) -> Option<RoundingBottom> {
+        let mut series = CandleStream::<_, 30>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.rounding_bottom(21, 3)
+    }
+
+    #[test]
+    fn test_rounding_bottom() {
+        let mut candles = build(|x| 100.0 + 0.1 * x * x);
+
+        let pattern = rounding_bottom(&candles);
+        assert!(pattern.is_some_and(|p| p.vertex == 9 && p.curvature > 0.0));
+        assert!(pattern.is_some_and(|p| p.r_squared > 0.99 && p.rim == 112.0));
+        assert!(pattern.is_some_and(|p| !p.broken_out));
+
+        candles[22] = (110.5, 113.0, 110.0, 112.8, 0.0);
+
+        assert!(rounding_bottom(&candles).is_some_and(|p| p.broken_out));
+        assert_eq!(rounding_bottom(&candles[..22]), None);
+    }
+
+    #[test]
+    fn test_rounding_bottom_shape() {
+        let v_shape = build(|x| 100.0 + x.abs());
+        let rounding_top = build(|x| 120.0 - 0.1 * x * x);
+        let lopsided = build(|x| 100.0 + 0.1 * (x + 8.0) * (x + 8.0));
+
+        assert_eq!(rounding_bottom(&v_shape), None);
+        assert_eq!(rounding_bottom(&rounding_top), None);
+        assert_eq!(rounding_bottom(&lopsided), None);
+    }
+}
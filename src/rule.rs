@@ -0,0 +1,164 @@
+use crate::{candle_stream::SERIES_SIZE, CandleStick, CandleStream, Pattern, Regime, RegimeRules};
+
+/// The `Rule` trait is a condition on the latest candles of a stream, evaluated after every
+/// push, that composes with other rules into a simple strategy.
+///
+/// It is implemented by [`Pattern`], by closures taking the stream, and by the [`InRegime`]
+/// and [`VolumeAbove`] filters. Rules nest into [`And`], [`Or`] and [`Not`] combinators,
+/// themselves rules, without allocating.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{And, CandleStick, CandleStream, Or, Pattern, Rule, VolumeAbove};
+/// type Candle = (f64, f64, f64, f64, f64);
+///
+/// let above_100 = |s: &CandleStream<'_, Candle>| s.get().is_some_and(|c| c.close() > 100.0);
+/// let reversal = Or(Pattern::BullishEngulfing, Pattern::MorningStar);
+/// let surge = VolumeAbove { factor: 1.5, period: 1 };
+/// let entry = And(reversal, And(surge, above_100));
+///
+/// let prev = (101.0, 102.0, 99.5, 100.5, 1000.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 2000.0);
+/// let mut series = CandleStream::new();
+/// series.push(&prev).push(&curr);
+/// assert!(entry.holds(&series));
+///
+/// // the same engulfing candle on ordinary volume
+/// let quiet = (99.0, 103.0, 98.5, 102.5, 1200.0);
+/// series.push(&prev).push(&quiet);
+/// assert!(!entry.holds(&series));
+/// ```
+pub trait Rule<T, const N: usize = SERIES_SIZE> {
+    /// Returns true if the rule holds on the latest candles of the stream
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool;
+}
+
+/// A rule holding when both rules hold
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct And<A, B>(pub A, pub B);
+
+/// A rule holding when either rule holds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Or<A, B>(pub A, pub B);
+
+/// A rule holding when the inner rule doesn't
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Not<R>(pub R);
+
+impl<T, const N: usize, A: Rule<T, N>, B: Rule<T, N>> Rule<T, N> for And<A, B> {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        self.0.holds(stream) && self.1.holds(stream)
+    }
+}
+
+impl<T, const N: usize, A: Rule<T, N>, B: Rule<T, N>> Rule<T, N> for Or<A, B> {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        self.0.holds(stream) || self.1.holds(stream)
+    }
+}
+
+impl<T, const N: usize, R: Rule<T, N>> Rule<T, N> for Not<R> {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        !self.0.holds(stream)
+    }
+}
+
+impl<T: CandleStick, const N: usize> Rule<T, N> for Pattern {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        stream.is_pattern(*self)
+    }
+}
+
+impl<T, const N: usize, F> Rule<T, N> for F
+where
+    F: Fn(&CandleStream<'_, T, N>) -> bool,
+{
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        self(stream)
+    }
+}
+
+/// A rule holding when the market is in the given [`Regime`], see [`CandleStream::regime`]
+///
+/// The stream must hold at least `rules.period` candles, see [`CandleStream::with_capacity`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InRegime {
+    /// The required regime
+    pub regime: Regime,
+    /// The thresholds classifying the regime
+    pub rules: RegimeRules,
+}
+
+impl<T: CandleStick, const N: usize> Rule<T, N> for InRegime {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        stream.regime(self.rules) == Some(self.regime)
+    }
+}
+
+/// A rule holding when the volume of the latest candle is at least `factor` times the
+/// average volume of the `period` previous candles
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeAbove {
+    /// Minimum volume, relative to the average volume
+    pub factor: f64,
+    /// Number of previous candles averaged
+    pub period: usize,
+}
+
+impl<T: CandleStick, const N: usize> Rule<T, N> for VolumeAbove {
+    fn holds(&self, stream: &CandleStream<'_, T, N>) -> bool {
+        stream
+            .get()
+            .zip(stream.average_volume_range(1, self.period))
+            .is_some_and(|(c, (volume, _))| c.volume() >= self.factor * volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    #[test]
+    fn test_combinators() {
+        let candles = [
+            (100.0, 101.5, 99.5, 101.0, 100.0),
+            (101.1, 102.5, 100.9, 102.0, 100.0),
+            (102.1, 103.5, 101.9, 103.0, 100.0),
+            (103.1, 104.5, 102.9, 104.0, 100.0),
+            (104.1, 105.5, 103.9, 105.0, 250.0),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        let uptrend = InRegime {
+            regime: Regime::TrendingUp,
+            rules: RegimeRules {
+                period: 5,
+                ..RegimeRules::default()
+            },
+        };
+        let surge = VolumeAbove {
+            factor: 2.0,
+            period: 4,
+        };
+        let soldiers = Pattern::ThreeWhiteSoldiers;
+        let bearish = |s: &CandleStream<'_, Ohlcv>| s.get().is_some_and(|c| c.is_bearish());
+
+        assert!(And(uptrend, And(surge, soldiers)).holds(&series));
+        assert!(!And(uptrend, bearish).holds(&series));
+        assert!(Or(bearish, surge).holds(&series));
+        assert!(And(Not(bearish), Not(Not(soldiers))).holds(&series));
+        assert!(!VolumeAbove {
+            factor: 3.0,
+            ..surge
+        }
+        .holds(&series));
+        assert!(!VolumeAbove { period: 5, ..surge }.holds(&series));
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    candle_stream::SERIES_SIZE, CandleStick, CandleStream, PatternDetector, PatternMatch,
+    PatternSet, Timestamped,
+};
+
+/// The `Scanner` runs pattern detection over a slice of candles, bar by bar.
+///
+/// It feeds each candle into a [`CandleStream`] and yields the index of every bar that
+/// completes at least one pattern, together with the detected [`PatternSet`]. Only the
+/// patterns enabled in the mask are evaluated, which both speeds up large scans and silences
+/// patterns a strategy doesn't care about.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Pattern, PatternSet, Scanner};
+/// let candles = [
+///     (100.0, 101.0, 98.0, 99.0, 0.0),
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let mask = PatternSet::new().with(Pattern::BullishEngulfing);
+/// let mut scanner = Scanner::with_mask(&candles, mask);
+/// assert_eq!(scanner.next(), Some((2, mask)));
+/// assert_eq!(scanner.next(), None);
+/// ```
+#[derive(Debug)]
+pub struct Scanner<'s, T> {
+    candles: &'s [T],
+    stream: CandleStream<'s, T>,
+    mask: PatternSet,
+    idx: usize,
+}
+
+impl<'s, T> Scanner<'s, T> {
+    /// Returns a scanner evaluating every pattern
+    pub fn new(candles: &'s [T]) -> Self {
+        Self::with_mask(candles, PatternSet::all())
+    }
+
+    /// Returns a scanner evaluating only the patterns enabled in the mask
+    pub fn with_mask(candles: &'s [T], mask: PatternSet) -> Self {
+        Self {
+            candles,
+            stream: CandleStream::new(),
+            mask,
+            idx: 0,
+        }
+    }
+}
+
+impl<T: CandleStick> Iterator for Scanner<'_, T> {
+    type Item = (usize, PatternSet);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(candle) = self.candles.get(self.idx) {
+            let idx = self.idx;
+            self.idx += 1;
+
+            let detected = self.stream.push(candle).detect(self.mask);
+            if !detected.is_empty() {
+                return Some((idx, detected));
+            }
+        }
+
+        None
+    }
+}
+
+/// Window of the latest owned candles, shared by the scanners over candles that don't outlive
+/// the scan, like decoded or streamed ones. A [`CandleStream`] borrowing the window is rebuilt
+/// for every candle.
+#[derive(Debug, Clone)]
+pub(crate) struct WindowScan<T> {
+    window: [Option<T>; SERIES_SIZE],
+    idx: usize,
+}
+
+impl<T: CandleStick> WindowScan<T> {
+    /// Returns an empty window
+    pub(crate) fn new() -> Self {
+        Self {
+            window: [const { None }; SERIES_SIZE],
+            idx: 0,
+        }
+    }
+
+    /// Returns the number of candles scanned so far
+    pub(crate) fn scanned(&self) -> usize {
+        self.idx
+    }
+
+    /// Pulls candles until one completes at least one pattern of the mask, returning its index
+    /// together with the detected [`PatternSet`], or `None` once the candles are exhausted
+    pub(crate) fn next_match(
+        &mut self,
+        candles: impl Iterator<Item = T>,
+        mask: PatternSet,
+    ) -> Option<(usize, PatternSet)> {
+        for candle in candles {
+            let idx = self.idx;
+            self.idx += 1;
+
+            self.window.rotate_left(1);
+            if let Some(last) = self.window.last_mut() {
+                *last = Some(candle);
+            }
+
+            let mut stream = CandleStream::new();
+            for candle in self.window.iter().flatten() {
+                stream.push(candle);
+            }
+
+            let detected = stream.detect(mask);
+            if !detected.is_empty() {
+                return Some((idx, detected));
+            }
+        }
+
+        None
+    }
+}
+
+/// The `DetectorScanner` runs a set of [`PatternDetector`]s over a slice of candles, bar by bar.
+///
+/// It yields every match along with the index of the bar completing it, in bar order and then
+/// in detector order.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{DetectorScanner, Pattern, PatternDetector};
+/// type Candle = (f64, f64, f64, f64, f64);
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let detectors: [&dyn PatternDetector<Candle>; 2] =
+///     [&Pattern::BullishEngulfing, &Pattern::BearishEngulfing];
+/// let mut scanner = DetectorScanner::new(&candles, &detectors);
+/// let (idx, found) = scanner.next().unwrap();
+/// assert_eq!((idx, found.pattern, found.target), (1, Some(Pattern::BullishEngulfing), Some(106.0)));
+/// assert_eq!(scanner.next(), None);
+/// ```
+pub struct DetectorScanner<'s, 'd, T> {
+    candles: &'s [T],
+    detectors: &'d [&'d dyn PatternDetector<T>],
+    stream: CandleStream<'s, T>,
+    idx: usize,
+    detector_idx: usize,
+}
+
+impl<'s, 'd, T> DetectorScanner<'s, 'd, T> {
+    /// Returns a scanner evaluating the given detectors
+    pub fn new(candles: &'s [T], detectors: &'d [&'d dyn PatternDetector<T>]) -> Self {
+        Self {
+            candles,
+            detectors,
+            stream: CandleStream::new(),
+            idx: 0,
+            detector_idx: detectors.len(),
+        }
+    }
+}
+
+impl<'s, 'd, T: CandleStick + Timestamped> DetectorScanner<'s, 'd, T> {
+    /// Turns the scanner into an iterator of detection events, each match being stamped with
+    /// the timestamp of the candle completing it and an id increasing from zero.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Candle, DetectorScanner, Pattern, PatternDetector};
+    /// let candles = [
+    ///     Candle::new(60, 101.0, 102.0, 99.5, 100.5, 0.0),
+    ///     Candle::new(120, 99.0, 103.0, 98.5, 102.5, 0.0),
+    /// ];
+    /// let detectors: [&dyn PatternDetector<Candle>; 1] = [&Pattern::BullishEngulfing];
+    /// let found = DetectorScanner::new(&candles, &detectors).events().next().unwrap();
+    /// assert_eq!((found.timestamp, found.id), (Some(120), Some(0)));
+    /// ```
+    pub fn events(self) -> impl Iterator<Item = PatternMatch> + use<'s, 'd, T> {
+        let candles = self.candles;
+        self.zip(0..).map(move |((idx, found), id)| PatternMatch {
+            timestamp: candles.get(idx).map(T::timestamp),
+            id: Some(id),
+            ..found
+        })
+    }
+}
+
+impl<T: CandleStick> Iterator for DetectorScanner<'_, '_, T> {
+    type Item = (usize, PatternMatch);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(detector) = self.detectors.get(self.detector_idx) {
+                self.detector_idx += 1;
+
+                if let Some(found) = detector.detect(&self.stream) {
+                    return Some((self.idx - 1, found));
+                }
+            }
+
+            let candle = self.candles.get(self.idx)?;
+            self.stream.push(candle);
+            self.idx += 1;
+            self.detector_idx = 0;
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for DetectorScanner<'_, '_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DetectorScanner")
+            .field("idx", &self.idx)
+            .field("detectors", &self.detectors.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+
+    #[test]
+    fn test_scan() {
+        let candles = [
+            (100.0, 101.0, 98.0, 99.0, 0.0),
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (99.0, 103.0, 98.5, 102.5, 0.0),
+        ];
+
+        let mut scanner = Scanner::new(&candles);
+
+        assert_eq!(
+            scanner.next(),
+            Some((2, PatternSet::new().with(Pattern::BullishEngulfing)))
+        );
+        assert_eq!(scanner.next(), None);
+    }
+
+    #[test]
+    fn test_scan_with_mask() {
+        let candles = [
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (99.0, 103.0, 98.5, 102.5, 0.0),
+        ];
+
+        let mask = PatternSet::all().without(Pattern::BullishEngulfing);
+
+        assert_eq!(Scanner::with_mask(&candles, mask).next(), None);
+        assert_eq!(
+            Scanner::with_mask(&[] as &[(f64, f64, f64, f64, f64)], mask).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detector_scan() {
+        type Candle = (f64, f64, f64, f64, f64);
+
+        let candles = [
+            (100.0, 101.0, 98.0, 99.0, 0.0),
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (99.0, 103.0, 98.5, 102.5, 0.0),
+        ];
+        let bullish = ("Bullish", |s: &CandleStream<'_, Candle>| {
+            s.get().is_some_and(|c| c.is_bullish())
+        });
+        let detectors: [&dyn PatternDetector<Candle>; 2] = [&Pattern::BullishEngulfing, &bullish];
+
+        let mut scanner = DetectorScanner::new(&candles, &detectors);
+
+        let engulfing = PatternMatch::from(Pattern::BullishEngulfing).with_target(106.0);
+        assert_eq!(scanner.next(), Some((2, engulfing)));
+        assert_eq!(scanner.next(), Some((2, PatternMatch::custom("Bullish"))));
+        assert_eq!(scanner.next(), None);
+        assert_eq!(DetectorScanner::new(&candles, &[]).next(), None);
+    }
+
+    #[test]
+    fn test_detection_events() {
+        use crate::Candle;
+
+        let candles = [
+            Candle::new(60, 100.0, 101.0, 98.0, 99.0, 0.0),
+            Candle::new(120, 101.0, 102.0, 99.5, 100.5, 0.0),
+            Candle::new(180, 99.0, 103.0, 98.5, 102.5, 0.0),
+        ];
+        let bullish = ("Bullish", |s: &CandleStream<'_, Candle>| {
+            s.get().is_some_and(|c| c.is_bullish())
+        });
+        let detectors: [&dyn PatternDetector<Candle>; 2] = [&Pattern::BullishEngulfing, &bullish];
+
+        let events: [_; 2] = core::array::from_fn({
+            let mut events = DetectorScanner::new(&candles, &detectors).events();
+            move |_| events.next().map(|m| (m.name, m.timestamp, m.id))
+        });
+        assert_eq!(
+            events,
+            [
+                Some(("Bullish Engulfing", Some(180), Some(0))),
+                Some(("Bullish", Some(180), Some(1))),
+            ]
+        );
+    }
+}
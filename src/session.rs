@@ -0,0 +1,216 @@
+use crate::{
+    candle_stream::SERIES_SIZE, CandleStick, CandleStream, Pattern, PatternSet, Timestamped,
+};
+
+/// How patterns spanning a session boundary are evaluated, used by
+/// [`CandleStream::is_pattern_across_sessions`]
+///
+/// Consecutive candles more than one bar interval apart belong to different sessions, like the
+/// close of a day and the open of the next one. Opening gaps between sessions are often benign
+/// but easily satisfy the gap conditions of star and doji star patterns.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionGaps {
+    /// Session boundaries are ignored, opening gaps count like any other gap
+    #[default]
+    Normal,
+    /// Opening gaps are removed, as if each session opened at the previous close. Patterns
+    /// needing an open beyond the previous close, like engulfing, cannot match across sessions
+    Ignore,
+    /// Patterns spanning a session boundary never match
+    Refuse,
+}
+
+impl<T: CandleStick + Timestamped, const N: usize> CandleStream<'_, T, N> {
+    /// Returns true if a session boundary lies among the latest `n` candles, that is two
+    /// consecutive candles open more than `interval` apart.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Candle, CandleStream};
+    /// let close = Candle::new(57_600, 100.0, 101.0, 99.0, 100.5, 0.0);
+    /// let open = Candle::new(120_600, 102.0, 103.0, 101.5, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&close).push(&open);
+    /// assert!(series.spans_session(2, 3_600));
+    /// assert!(!series.spans_session(1, 3_600));
+    /// ```
+    pub fn spans_session(&self, n: usize, interval: i64) -> bool {
+        (0..n.saturating_sub(1)).any(|bars_ago| self.session_gap(bars_ago, interval).is_some())
+    }
+
+    /// Evaluates the given [`Pattern`] against the latest candles of the stream, handling the
+    /// session boundaries found among them according to `gaps`.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Candle, CandleStream, Pattern, SessionGaps};
+    /// let close = Candle::new(57_600, 110.0, 111.0, 100.0, 101.0, 0.0);
+    /// let open = Candle::new(120_600, 98.0, 99.0, 97.0, 98.05, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&close).push(&open);
+    /// let doji_star = Pattern::BullishDojiStar;
+    /// assert!(series.is_pattern_across_sessions(doji_star, 3_600, SessionGaps::Normal));
+    /// assert!(!series.is_pattern_across_sessions(doji_star, 3_600, SessionGaps::Ignore));
+    /// assert!(!series.is_pattern_across_sessions(doji_star, 3_600, SessionGaps::Refuse));
+    /// ```
+    pub fn is_pattern_across_sessions(
+        &self,
+        pattern: Pattern,
+        interval: i64,
+        gaps: SessionGaps,
+    ) -> bool {
+        let candles = pattern.candle_count();
+        match gaps {
+            SessionGaps::Normal => self.is_pattern(pattern),
+            SessionGaps::Refuse => {
+                !self.spans_session(candles, interval) && self.is_pattern(pattern)
+            }
+            SessionGaps::Ignore if !self.spans_session(candles, interval) => {
+                self.is_pattern(pattern)
+            }
+            SessionGaps::Ignore => {
+                // older candles are shifted by the gaps above them, so the latest keeps its prices
+                let mut offset = 0.0;
+                let mut rebased: [Option<Rebased<'_, T>>; SERIES_SIZE] =
+                    core::array::from_fn(|_| None);
+                for bars_ago in 0..candles {
+                    let Some(candle) = self.prev(bars_ago) else {
+                        return false;
+                    };
+                    rebased[candles - 1 - bars_ago] = Some(Rebased { candle, offset });
+                    offset += self.session_gap(bars_ago, interval).unwrap_or(0.0);
+                }
+
+                let mut stream = CandleStream::<_, SERIES_SIZE>::with_capacity();
+                for candle in rebased.iter().flatten() {
+                    stream.push(candle);
+                }
+                stream.is_pattern(pattern)
+            }
+        }
+    }
+
+    /// Returns the patterns of the mask currently formed by the latest candles, handling
+    /// session boundaries according to `gaps`.
+    ///
+    /// See [`CandleStream::is_pattern_across_sessions`].
+    pub fn detect_across_sessions(
+        &self,
+        mask: PatternSet,
+        interval: i64,
+        gaps: SessionGaps,
+    ) -> PatternSet {
+        mask.iter()
+            .filter(|p| self.is_pattern_across_sessions(*p, interval, gaps))
+            .collect()
+    }
+
+    // Returns the opening gap of the candle at the given position, if it starts a session
+    fn session_gap(&self, bars_ago: usize, interval: i64) -> Option<f64> {
+        let (c, p) = self.prev(bars_ago).zip(self.prev(bars_ago + 1))?;
+        (c.open_time(interval) - p.open_time(interval) > interval).then(|| c.open() - p.close())
+    }
+}
+
+// A candle with its prices shifted by an offset, keeping the thresholds of the wrapped candle
+struct Rebased<'a, T> {
+    candle: &'a T,
+    offset: f64,
+}
+
+impl<T: CandleStick> CandleStick for Rebased<'_, T> {
+    // the shape of a candle doesn't depend on its offset, so overrides like spread gating hold
+    forward_candlestick!(thresholds, log_scale, is_doji => |c| c.candle);
+
+    fn open(&self) -> f64 {
+        self.candle.open() + self.offset
+    }
+
+    fn high(&self) -> f64 {
+        self.candle.high() + self.offset
+    }
+
+    fn low(&self) -> f64 {
+        self.candle.low() + self.offset
+    }
+
+    fn close(&self) -> f64 {
+        self.candle.close() + self.offset
+    }
+
+    fn volume(&self) -> f64 {
+        self.candle.volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Candle;
+
+    const HOUR: i64 = 3_600;
+
+    #[test]
+    fn test_gap_within_session() {
+        let close = Candle::new(0, 110.0, 111.0, 100.0, 101.0, 0.0);
+        let open = Candle::new(HOUR, 98.0, 99.0, 97.0, 98.05, 0.0);
+        let mut series = CandleStream::new();
+        series.push(&close).push(&open);
+
+        assert!(!series.spans_session(2, HOUR));
+        for gaps in [
+            SessionGaps::Normal,
+            SessionGaps::Ignore,
+            SessionGaps::Refuse,
+        ] {
+            assert!(series.is_pattern_across_sessions(Pattern::BullishDojiStar, HOUR, gaps));
+        }
+    }
+
+    #[test]
+    fn test_ignored_gap_keeps_the_shape() {
+        // the latest candle opens the next session slightly above the previous close
+        let prev2 = Candle::new(0, 54.0, 54.5, 51.8, 52.0, 0.0);
+        let prev1 = Candle::new(HOUR, 52.2, 53.0, 52.0, 52.8, 0.0);
+        let curr = Candle::new(18 * HOUR, 52.9, 55.0, 52.7, 54.5, 0.0);
+        let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
+
+        let mask = PatternSet::new()
+            .with(Pattern::ThreeInsideUp)
+            .with(Pattern::BullishEngulfing);
+        let expected = PatternSet::new().with(Pattern::ThreeInsideUp);
+        assert!(series.spans_session(3, HOUR));
+        assert_eq!(
+            series.detect_across_sessions(mask, HOUR, SessionGaps::Normal),
+            expected
+        );
+        assert_eq!(
+            series.detect_across_sessions(mask, HOUR, SessionGaps::Ignore),
+            expected
+        );
+        assert!(series
+            .detect_across_sessions(mask, HOUR, SessionGaps::Refuse)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_ignored_gap_on_five_candle_pattern() {
+        // the last candle opens the next session with a gap, the pattern holds once removed
+        let candles = [
+            Candle::new(0, 100.0, 106.0, 99.5, 105.5, 0.0),
+            Candle::new(HOUR, 105.0, 105.5, 103.5, 104.0, 0.0),
+            Candle::new(2 * HOUR, 104.0, 104.5, 102.5, 103.0, 0.0),
+            Candle::new(3 * HOUR, 103.0, 103.5, 101.5, 102.0, 0.0),
+            Candle::new(20 * HOUR, 98.0, 104.5, 97.8, 103.5, 0.0),
+        ];
+        let mut series = CandleStream::new();
+        for candle in &candles {
+            series.push(candle);
+        }
+
+        let pattern = Pattern::RisingThreeMethods;
+        assert!(!series.is_pattern_across_sessions(pattern, HOUR, SessionGaps::Normal));
+        assert!(series.is_pattern_across_sessions(pattern, HOUR, SessionGaps::Ignore));
+    }
+}
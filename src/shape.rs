@@ -0,0 +1,93 @@
+use crate::CandleStick;
+
+/// The sub-predicates shared by the multi-candle patterns, evaluated once when a candle is
+/// pushed to a [`crate::CandleStream`] instead of by every pattern check.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Shape(u8);
+
+impl Shape {
+    const BULLISH: u8 = 1;
+    const BEARISH: u8 = 1 << 1;
+    const DOJI: u8 = 1 << 2;
+    const GAP_UP: u8 = 1 << 3;
+    const GAP_DOWN: u8 = 1 << 4;
+
+    /// Returns the shape of the candle, with its gap from the previous candle if any
+    pub(crate) fn of<T: CandleStick>(candle: &T, prev: Option<&T>) -> Self {
+        let flags = [
+            (candle.is_bullish(), Self::BULLISH),
+            (candle.is_bearish(), Self::BEARISH),
+            (candle.is_doji(), Self::DOJI),
+            (
+                prev.is_some_and(|p| candle.is_gap(p.high(), candle.low())),
+                Self::GAP_UP,
+            ),
+            (
+                prev.is_some_and(|p| candle.is_gap(candle.high(), p.low())),
+                Self::GAP_DOWN,
+            ),
+        ];
+
+        Self(
+            flags
+                .iter()
+                .filter(|(set, _)| *set)
+                .fold(0, |bits, (_, flag)| bits | flag),
+        )
+    }
+
+    /// Returns the flags of the shape, as persisted by [`crate::Snapshot`]
+    #[cfg(feature = "serde")]
+    pub(crate) fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the shape with the given flags
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_bits(bits: u8) -> Self {
+        let known = Self::BULLISH | Self::BEARISH | Self::DOJI | Self::GAP_UP | Self::GAP_DOWN;
+        Self(bits & known)
+    }
+
+    /// See [`CandleStick::is_bullish`]
+    pub(crate) fn is_bullish(self) -> bool {
+        self.0 & Self::BULLISH != 0
+    }
+
+    /// See [`CandleStick::is_bearish`]
+    pub(crate) fn is_bearish(self) -> bool {
+        self.0 & Self::BEARISH != 0
+    }
+
+    /// See [`CandleStick::is_doji`]
+    pub(crate) fn is_doji(self) -> bool {
+        self.0 & Self::DOJI != 0
+    }
+
+    /// Returns true if the candle's low is meaningfully above the previous high
+    pub(crate) fn is_gap_up(self) -> bool {
+        self.0 & Self::GAP_UP != 0
+    }
+
+    /// Returns true if the candle's high is meaningfully below the previous low
+    pub(crate) fn is_gap_down(self) -> bool {
+        self.0 & Self::GAP_DOWN != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_of_candle() {
+        let prev = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let doji = (47.0, 47.5, 46.8, 47.0, 0.0);
+        let shape = Shape::of(&doji, Some(&prev));
+
+        assert!(shape.is_doji() && shape.is_gap_down());
+        assert!(!shape.is_bullish() && !shape.is_bearish() && !shape.is_gap_up());
+        assert!(Shape::of(&prev, None).is_bearish());
+        assert!(!Shape::of(&doji, None).is_gap_down());
+    }
+}
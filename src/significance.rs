@@ -0,0 +1,197 @@
+use crate::{utils::Rng, CandleStick, Direction, Pattern, PatternSet, Scanner};
+
+/// How a [`SignificanceTest`] randomizes the forward returns of a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resampling {
+    /// Draws every forward return independently, with replacement
+    Shuffle,
+    /// Draws blocks of consecutive forward returns of the given length, with replacement,
+    /// preserving the autocorrelation of returns and of overlapping horizons
+    Block(usize),
+}
+
+/// The outcome of a [`SignificanceTest`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Significance {
+    /// Number of occurrences of the pattern followed by a complete horizon
+    pub occurrences: usize,
+    /// Average forward return after the pattern
+    pub mean_return: f64,
+    /// Average forward return after any bar of the series
+    pub baseline: f64,
+    /// Excess of the average return after the pattern over the baseline, in the direction
+    /// anticipated by the pattern: positive when bearish patterns are followed by falls
+    pub edge: f64,
+    /// Fraction of the randomized series on which the pattern shows an edge at least as
+    /// large, the lower the less likely the edge is noise
+    pub p_value: f64,
+}
+
+/// A permutation test telling whether the forward returns following a pattern differ from
+/// chance.
+///
+/// The pattern occurrences are kept in place while the forward returns of the series are
+/// resampled `trials` times, giving the distribution of the edge a random signal firing on
+/// the same bars would show. The p-value is the share of resampled edges at least as large
+/// as the real one.
+///
+/// Resampling is driven by a seeded generator, so that results are reproducible.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Pattern, SignificanceTest};
+/// // bullish engulfing candles, each followed by a rally
+/// let candles: Vec<_> = (0..200)
+///     .map(|i| match i % 4 {
+///         0 => (101.0, 102.0, 99.5, 100.5, 0.0),
+///         1 => (99.0, 103.0, 98.5, 102.5, 0.0),
+///         2 => (102.5, 106.0, 102.0, 105.5, 0.0),
+///         _ => (105.5, 106.0, 100.5, 101.0, 0.0),
+///     })
+///     .collect();
+/// let test = SignificanceTest { horizon: 1, ..Default::default() };
+///
+/// let significance = test.run(&candles, Pattern::BullishEngulfing).unwrap();
+/// assert_eq!(significance.occurrences, 50);
+/// assert!(significance.edge > 0.0 && significance.p_value < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignificanceTest {
+    /// Number of bars of the forward return, from close to close
+    pub horizon: usize,
+    /// How the forward returns are randomized
+    pub resampling: Resampling,
+    /// Number of randomized series
+    pub trials: usize,
+    /// Seed of the random generator
+    pub seed: u64,
+}
+
+impl Default for SignificanceTest {
+    fn default() -> Self {
+        Self {
+            horizon: 5,
+            resampling: Resampling::Block(10),
+            trials: 1000,
+            seed: 0,
+        }
+    }
+}
+
+impl SignificanceTest {
+    /// Runs the test on the occurrences of the pattern in the series, or returns `None` if
+    /// none of them is followed by a complete horizon
+    pub fn run<T: CandleStick>(&self, candles: &[T], pattern: Pattern) -> Option<Significance> {
+        let count = candles
+            .len()
+            .checked_sub(self.horizon)
+            .filter(|count| *count > 0)?;
+        let forward = |i: usize| {
+            candles
+                .get(i + self.horizon)
+                .zip(candles.get(i))
+                .map_or(0.0, |(future, candle)| future.return_from(candle))
+        };
+        let occurrences = || {
+            Scanner::with_mask(candles, PatternSet::new().with(pattern))
+                .map(|(idx, _)| idx)
+                .take_while(|idx| *idx < count)
+        };
+
+        let occurrences_count = occurrences().count();
+        if occurrences_count == 0 {
+            return None;
+        }
+
+        let baseline = (0..count).map(forward).sum::<f64>() / count as f64;
+        let mean_return = occurrences().map(forward).sum::<f64>() / occurrences_count as f64;
+        let sign = match pattern.direction() {
+            Direction::Bullish => 1.0,
+            Direction::Bearish => -1.0,
+        };
+        let edge = sign * (mean_return - baseline);
+
+        let block = match self.resampling {
+            Resampling::Shuffle => 1,
+            Resampling::Block(len) => len.clamp(1, count),
+        };
+        let mut rng = Rng::new(self.seed);
+        let exceeding = (0..self.trials)
+            .filter(|_| {
+                // occurrences within the same block read consecutive resampled returns
+                let mut current = None;
+                let mut start = 0;
+                let resampled = occurrences()
+                    .map(|idx| {
+                        if current != Some(idx / block) {
+                            current = Some(idx / block);
+                            start = rng.below(count - block + 1);
+                        }
+                        forward(start + idx % block)
+                    })
+                    .sum::<f64>();
+
+                sign * (resampled / occurrences_count as f64 - baseline) >= edge
+            })
+            .count();
+
+        Some(Significance {
+            occurrences: occurrences_count,
+            mean_return,
+            baseline,
+            edge,
+            p_value: (exceeding + 1) as f64 / (self.trials + 1) as f64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    // A noisy series repeating the engulfing fixture, optionally followed by a rally
+    fn series(rally: bool) -> [Ohlcv; 300] {
+        let mut noise = 0x2545_f491_4f6c_dd1d_u64;
+        core::array::from_fn(|i| {
+            noise ^= noise << 13;
+            noise ^= noise >> 7;
+            noise ^= noise << 17;
+            let close = 100.0 + (noise % 1000) as f64 / 500.0 - 1.0;
+            match i % 10 {
+                0 => fixtures::BULLISH_ENGULFING[0],
+                1 => fixtures::BULLISH_ENGULFING[1],
+                2 if rally => (103.0, 106.0, 102.5, 105.5, 0.0),
+                _ => (close, close + 1.0, close - 1.0, close + 0.2, 0.0),
+            }
+        })
+    }
+
+    #[test]
+    fn test_genuine_edge_is_significant() {
+        let test = SignificanceTest {
+            horizon: 1,
+            resampling: Resampling::Shuffle,
+            trials: 500,
+            seed: 7,
+        };
+
+        let genuine = test.run(&series(true), Pattern::BullishEngulfing);
+        assert_eq!(genuine.map(|s| s.occurrences), Some(30));
+        assert!(genuine.is_some_and(|s| s.edge > 0.0 && s.p_value < 0.01));
+
+        let blocks = SignificanceTest {
+            resampling: Resampling::Block(5),
+            ..test
+        };
+        assert!(blocks
+            .run(&series(true), Pattern::BullishEngulfing)
+            .is_some_and(|s| s.p_value < 0.01));
+
+        let noise = test.run(&series(false), Pattern::BullishEngulfing);
+        assert!(noise.is_some_and(|s| s.p_value > 0.01));
+        assert_eq!(test.run(&series(true), Pattern::ThreeBlackCrows), None);
+    }
+}
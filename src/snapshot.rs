@@ -0,0 +1,386 @@
+use crate::{shape::Shape, CandleStick, CandleStream};
+
+/// An owned copy of the state of a [`CandleStream`], as returned by [`CandleStream::snapshot`].
+///
+/// Besides the candles, the snapshot keeps the number of candles pushed to the stream and the
+/// gaps between the candles evaluated on push, so [`CandleStream::restore`] resumes pattern
+/// detection after a restart exactly where it stopped, without replaying history. With the
+/// `serde` feature, the snapshot can be persisted in any serde format.
+///
+/// Snapshots can also be built from candles in chronological order with `FromIterator`, for
+/// processes persisting the candles (see `iter`) with their own serialization. The gaps of the
+/// oldest candle are then unknown, and the pushed count starts from the number of candles.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStream, Snapshot};
+/// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+/// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+///
+/// let mut series = CandleStream::new();
+/// let snapshot = series.push(&prev).snapshot();
+/// let saved: Vec<_> = snapshot.iter().copied().collect();
+///
+/// let loaded: Snapshot<_> = saved.into_iter().collect();
+/// let mut restored = CandleStream::restore(&loaded);
+/// assert!(restored.push(&curr).is_bullish_engulfing());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot<T, const N: usize = 5> {
+    candles: [Option<T>; N],
+    shapes: Option<[Shape; N]>,
+    len: usize,
+    pushed: usize,
+}
+
+impl<T, const N: usize> Snapshot<T, N> {
+    /// Iterates over the candles, from the oldest to the latest
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.candles[..self.len].iter().flatten()
+    }
+
+    /// Returns the number of candles in the snapshot
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the snapshot holds no candle
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of candles pushed to the stream the snapshot was taken from, see
+    /// [`CandleStream::pushed`]
+    pub fn pushed(&self) -> usize {
+        self.pushed
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for Snapshot<T, N> {
+    /// Builds a snapshot from candles in chronological order, keeping the latest `N`
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut candles = [const { None }; N];
+        let mut count = 0;
+
+        for candle in iter {
+            if let Some(slot) = candles.get_mut(count % N) {
+                *slot = Some(candle);
+            }
+            count += 1;
+        }
+
+        let len = count.min(N);
+        if count > N {
+            candles.rotate_left(count % N);
+        }
+
+        Self {
+            candles,
+            shapes: None,
+            len,
+            pushed: count,
+        }
+    }
+}
+
+impl<'s, T: CandleStick, const N: usize> CandleStream<'s, T, N> {
+    /// Returns an owned copy of the candles currently held by the stream, along with the state
+    /// evaluated when they were pushed
+    pub fn snapshot(&self) -> Snapshot<T, N>
+    where
+        T: Clone,
+    {
+        let mut candles = [const { None }; N];
+        let mut shapes = [Shape::default(); N];
+        let len = self.len();
+
+        for (slot, bars_ago) in (0..len).rev().enumerate() {
+            if let Some((candle, shape)) = self.shaped(bars_ago) {
+                candles[slot] = Some(candle.clone());
+                shapes[slot] = shape;
+            }
+        }
+
+        Snapshot {
+            candles,
+            shapes: Some(shapes),
+            len,
+            pushed: self.pushed(),
+        }
+    }
+
+    /// Returns a stream restored from a snapshot.
+    ///
+    /// A stream restored from a snapshot it took is in the same state: same candles, gaps
+    /// between them, pushed count and rolling extremes. For snapshots collected from candles,
+    /// the gaps of the oldest candle are unknown.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+    /// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+    ///
+    /// let mut series = CandleStream::new();
+    /// let snapshot = series.push(&prev).push(&curr).snapshot();
+    /// let restored = CandleStream::restore(&snapshot);
+    /// assert_eq!(restored.pushed(), 2);
+    /// assert!(restored.is_bullish_engulfing());
+    /// ```
+    pub fn restore(snapshot: &'s Snapshot<T, N>) -> Self {
+        let mut stream = Self::resumed_after(snapshot.pushed.saturating_sub(snapshot.len));
+        let mut prev = None;
+
+        for (slot, candle) in snapshot.iter().enumerate() {
+            let shape = match &snapshot.shapes {
+                Some(shapes) => shapes[slot],
+                None => Shape::of(candle, prev),
+            };
+            stream.push_shaped(candle, shape);
+            prev = Some(candle);
+        }
+
+        stream
+    }
+}
+
+// Snapshots are serialized as the pushed count, the candles from the oldest to the latest and
+// their shape flags, as arrays of a generic length don't implement the serde traits
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Snapshot<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let shapes = self
+            .shapes
+            .as_ref()
+            .map(|shapes| Seq(shapes[..self.len].iter().map(|shape| shape.bits())));
+
+        let mut state = serializer.serialize_struct("Snapshot", 3)?;
+        state.serialize_field("pushed", &self.pushed)?;
+        state.serialize_field("candles", &Seq(self.candles[..self.len].iter().flatten()))?;
+        state.serialize_field("shapes", &shapes)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Snapshot<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Snapshot")]
+        struct Fields<T, const N: usize> {
+            pushed: usize,
+            candles: Window<T, N>,
+            shapes: Option<Window<u8, N>>,
+        }
+
+        let fields = Fields::<T, N>::deserialize(deserializer)?;
+        let Window(candles, len) = fields.candles;
+        let shapes = match fields.shapes {
+            Some(Window(bits, shapes)) if shapes == len => {
+                Some(bits.map(|bits| Shape::from_bits(bits.unwrap_or_default())))
+            }
+            Some(_) => return Err(D::Error::custom("expected one shape per candle")),
+            None => None,
+        };
+        if fields.pushed < len {
+            return Err(D::Error::custom("fewer candles pushed than held"));
+        }
+
+        Ok(Self {
+            candles,
+            shapes,
+            len,
+            pushed: fields.pushed,
+        })
+    }
+}
+
+// Serializes the items of a cloneable iterator as a sequence
+#[cfg(feature = "serde")]
+struct Seq<I>(I);
+
+#[cfg(feature = "serde")]
+impl<I: Iterator<Item: serde::Serialize> + Clone> serde::Serialize for Seq<I> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.clone())
+    }
+}
+
+// Deserializes a sequence of at most `N` items into an array, along with its length
+#[cfg(feature = "serde")]
+struct Window<T, const N: usize>([Option<T>; N], usize);
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Window<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Items<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de> for Items<T, N> {
+            type Value = Window<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of at most {N} items")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Window<T, N>, A::Error> {
+                let mut items = [const { None }; N];
+                let mut len = 0;
+                while let Some(item) = seq.next_element()? {
+                    let slot = items
+                        .get_mut(len)
+                        .ok_or_else(|| serde::de::Error::invalid_length(len + 1, &self))?;
+                    *slot = Some(item);
+                    len += 1;
+                }
+                Ok(Window(items, len))
+            }
+        }
+
+        deserializer.deserialize_seq(Items(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let candles = [
+            (100.0, 105.0, 99.0, 104.0, 0.0),
+            (104.0, 103.0, 97.0, 98.0, 0.0),
+            (98.0, 101.0, 96.0, 100.0, 0.0),
+            (100.0, 108.0, 99.5, 107.0, 0.0),
+            (107.0, 107.5, 101.0, 102.0, 0.0),
+            (102.0, 104.0, 100.0, 103.0, 0.0),
+        ];
+
+        let mut series = CandleStream::new();
+        for candle in candles.iter() {
+            series.push(candle);
+        }
+
+        let snapshot = series.snapshot();
+        assert_eq!(snapshot.len(), 5);
+        assert!(snapshot.iter().eq(candles[1..].iter()));
+
+        let restored = CandleStream::restore(&snapshot);
+
+        assert!(restored.iter().eq(series.iter()));
+        assert_eq!(restored.highest_high(5), series.highest_high(5));
+        assert_eq!(restored.lowest_low(3), series.lowest_low(3));
+        assert_eq!(restored.detect_all(), series.detect_all());
+    }
+
+    #[test]
+    fn test_restore_keeps_pushed_count_and_gaps() {
+        let candles = [
+            (106.0, 106.5, 103.0, 103.5, 0.0),
+            (101.0, 102.0, 100.0, 101.5, 0.0),
+            (103.5, 104.8, 103.2, 104.5, 0.0),
+        ];
+        let mut series = CandleStream::<_, 2>::with_capacity();
+        for candle in candles.iter() {
+            series.push(candle);
+        }
+        assert!(series.is_bullish_island_reversal());
+
+        let snapshot = series.snapshot();
+        let restored = CandleStream::restore(&snapshot);
+
+        assert_eq!((snapshot.pushed(), restored.pushed()), (3, 3));
+        assert!(restored.is_bullish_island_reversal());
+        assert_eq!(restored.highest_high(2), series.highest_high(2));
+
+        // the island's gap down from the first candle is lost without the pushed state
+        let collected: Snapshot<_, 2> = snapshot.iter().copied().collect();
+        let replayed = CandleStream::restore(&collected);
+        assert_eq!(replayed.pushed(), 2);
+        assert!(!replayed.is_bullish_island_reversal());
+    }
+
+    #[test]
+    fn test_restored_stream_keeps_going() {
+        let candles = [
+            (100.0, 105.0, 99.0, 104.0, 0.0),
+            (104.0, 103.0, 97.0, 98.0, 0.0),
+            (98.0, 101.0, 96.0, 100.0, 0.0),
+            (100.0, 108.0, 99.5, 107.0, 0.0),
+            (107.0, 107.5, 101.0, 102.0, 0.0),
+            (102.0, 104.0, 100.0, 103.0, 0.0),
+            (103.0, 109.0, 102.5, 108.5, 0.0),
+        ];
+
+        let mut series = CandleStream::new();
+        for candle in candles[..4].iter() {
+            series.push(candle);
+        }
+        let snapshot = series.snapshot();
+        let mut restored = CandleStream::restore(&snapshot);
+
+        for candle in candles[4..].iter() {
+            series.push(candle);
+            restored.push(candle);
+            assert_eq!(restored.pushed(), series.pushed());
+            assert_eq!(restored.highest_high(5), series.highest_high(5));
+            assert_eq!(restored.lowest_low(5), series.lowest_low(5));
+            assert_eq!(restored.detect_all(), series.detect_all());
+        }
+    }
+
+    #[test]
+    fn test_snapshot_from_iter() {
+        let empty: Snapshot<u8, 3> = core::iter::empty().collect();
+        assert!(empty.is_empty());
+
+        let partial: Snapshot<u8, 3> = [1, 2].into_iter().collect();
+        assert!(partial.iter().eq([1, 2].iter()));
+
+        let wrapped: Snapshot<u8, 3> = [1, 2, 3, 4, 5].into_iter().collect();
+        assert!(wrapped.iter().eq([3, 4, 5].iter()));
+        assert_eq!(wrapped.pushed(), 5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<(), serde_json::Error> {
+        let candles = [
+            (106.0, 106.5, 103.0, 103.5, 0.0),
+            (101.0, 102.0, 100.0, 101.5, 0.0),
+            (103.5, 104.8, 103.2, 104.5, 0.0),
+        ];
+        let mut series = CandleStream::<_, 2>::with_capacity();
+        for candle in candles.iter() {
+            series.push(candle);
+        }
+        let snapshot = series.snapshot();
+
+        let json = serde_json::to_string(&snapshot)?;
+        let loaded: Snapshot<(f64, f64, f64, f64, f64), 2> = serde_json::from_str(&json)?;
+        assert_eq!(loaded, snapshot);
+
+        let restored = CandleStream::restore(&loaded);
+        assert_eq!(restored.pushed(), 3);
+        assert!(restored.is_bullish_island_reversal());
+
+        let collected: Snapshot<u8, 3> = [1, 2].into_iter().collect();
+        let json = serde_json::to_string(&collected)?;
+        assert_eq!(json, r#"{"pushed":2,"candles":[1,2],"shapes":null}"#);
+        assert_eq!(serde_json::from_str::<Snapshot<u8, 3>>(&json)?, collected);
+
+        let too_long = r#"{"pushed":4,"candles":[1,2,3,4],"shapes":null}"#;
+        assert!(serde_json::from_str::<Snapshot<u8, 3>>(too_long).is_err());
+        let unpushed = r#"{"pushed":1,"candles":[1,2],"shapes":null}"#;
+        assert!(serde_json::from_str::<Snapshot<u8, 3>>(unpushed).is_err());
+        let unshaped = r#"{"pushed":2,"candles":[1,2],"shapes":[1]}"#;
+        assert!(serde_json::from_str::<Snapshot<u8, 3>>(unshaped).is_err());
+        Ok(())
+    }
+}
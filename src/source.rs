@@ -0,0 +1,539 @@
+use crate::{scanner::WindowScan, CandleStick, PatternSet};
+
+#[cfg(feature = "std")]
+use crate::Candle;
+#[cfg(feature = "std")]
+use std::{
+    format,
+    io::{self, BufRead},
+    string::String,
+    sync::mpsc::Receiver,
+};
+
+#[cfg(feature = "parquet")]
+use parquet::{
+    errors::ParquetError,
+    file::reader::{ChunkReader, FileReader, SerializedFileReader},
+    record::{reader::RowIter, Field, Row},
+    schema::types::Type,
+};
+#[cfg(feature = "parquet")]
+use std::boxed::Box;
+
+/// The `OhlcvSource` trait is a pull-based supply of candles, decoupling pattern detection from
+/// how the data arrives.
+///
+/// Reference implementations are provided for slice iterators, with the `std` feature for
+/// channel receivers and CSV readers, and with the `parquet` feature for Parquet files. Any
+/// source can be drained by a [`SourceScanner`].
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{OhlcvSource, Pattern, PatternSet};
+/// let candles = [
+///     (100.0, 101.0, 98.0, 99.0, 0.0),
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let mask = PatternSet::new().with(Pattern::BullishEngulfing);
+/// let mut scanner = candles.iter().scan_patterns(mask);
+/// assert_eq!(scanner.next(), Some((2, mask)));
+/// ```
+pub trait OhlcvSource {
+    /// The candles supplied by the source
+    type Candle: CandleStick;
+
+    /// Returns the next candle, or `None` once the source is exhausted
+    fn next_candle(&mut self) -> Option<Self::Candle>;
+
+    /// Returns a scanner draining the source and yielding the index of every bar completing at
+    /// least one pattern of the mask, together with the detected [`PatternSet`]
+    fn scan_patterns(self, mask: PatternSet) -> SourceScanner<Self>
+    where
+        Self: Sized,
+    {
+        SourceScanner {
+            source: self,
+            mask,
+            scan: WindowScan::new(),
+        }
+    }
+}
+
+impl<T: CandleStick + Clone> OhlcvSource for core::slice::Iter<'_, T> {
+    type Candle = T;
+
+    fn next_candle(&mut self) -> Option<T> {
+        self.next().cloned()
+    }
+}
+
+/// Receives candles until every sender is dropped, blocking while the channel is empty
+#[cfg(feature = "std")]
+impl<T: CandleStick> OhlcvSource for Receiver<T> {
+    type Candle = T;
+
+    fn next_candle(&mut self) -> Option<T> {
+        self.recv().ok()
+    }
+}
+
+/// The `SourceScanner` runs pattern detection over an [`OhlcvSource`], keeping only the last
+/// few candles in memory.
+///
+/// Returned by [`OhlcvSource::scan_patterns`].
+pub struct SourceScanner<S: OhlcvSource> {
+    source: S,
+    mask: PatternSet,
+    scan: WindowScan<S::Candle>,
+}
+
+impl<S: OhlcvSource> SourceScanner<S> {
+    /// Returns the drained source
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: OhlcvSource> Iterator for SourceScanner<S> {
+    type Item = (usize, PatternSet);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let source = &mut self.source;
+        self.scan
+            .next_match(core::iter::from_fn(|| source.next_candle()), self.mask)
+    }
+}
+
+impl<S: OhlcvSource> core::fmt::Debug for SourceScanner<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SourceScanner")
+            .field("mask", &self.mask)
+            .field("idx", &self.scan.scanned())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A source reading [`Candle`]s from CSV rows of `timestamp,open,high,low,close,volume`.
+///
+/// A first row that doesn't parse is skipped as a header. The source stops at the end of the
+/// input, on an I/O error or on a malformed row, the latter two being reported by
+/// [`CsvSource::error`].
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CsvSource, OhlcvSource};
+/// let csv = "time,open,high,low,close,volume\n\
+///            1700000000,101.0,102.0,99.5,100.5,1200\n\
+///            1700000060,99.0,103.0,98.5,102.5,1500\n";
+/// let mut source = CsvSource::new(csv.as_bytes());
+/// assert_eq!(source.next_candle().map(|c| c.close), Some(100.5));
+/// assert_eq!(source.next_candle().map(|c| c.timestamp), Some(1_700_000_060));
+/// assert!(source.next_candle().is_none() && source.error().is_none());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct CsvSource<R> {
+    reader: R,
+    line: String,
+    rows: usize,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> CsvSource<R> {
+    /// Returns a source reading the rows of the reader
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            rows: 0,
+            error: None,
+        }
+    }
+
+    /// Returns the error that stopped the source, if any
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> OhlcvSource for CsvSource<R> {
+    type Candle = Candle;
+
+    fn next_candle(&mut self) -> Option<Candle> {
+        while self.error.is_none() {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.rows += 1,
+                Err(error) => self.error = Some(error),
+            }
+
+            let row = self.line.trim();
+            if self.error.is_some() || row.is_empty() {
+                continue;
+            }
+            match parse_row(row) {
+                Some(candle) => return Some(candle),
+                None if self.rows == 1 => continue,
+                None => {
+                    self.error = Some(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("malformed candle on row {}", self.rows),
+                    ))
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// Parses a `timestamp,open,high,low,close,volume` row
+#[cfg(feature = "std")]
+fn parse_row(row: &str) -> Option<Candle> {
+    let mut fields = row.split(',').map(str::trim);
+    let timestamp = fields.next()?.parse().ok()?;
+    let mut price = || fields.next()?.parse::<f64>().ok();
+    let candle = Candle::new(timestamp, price()?, price()?, price()?, price()?, price()?);
+
+    fields.next().is_none().then_some(candle)
+}
+
+/// A source reading [`Candle`]s from the rows of a Parquet file, requiring the `parquet`
+/// feature.
+///
+/// Only the timestamp, open, high, low, close and volume columns are decoded, whatever their
+/// names. Prices and volumes may be stored as floating point or integer columns, timestamps
+/// as integers or Parquet timestamps, kept in their own unit. Files compressed with snappy or
+/// zstd are supported.
+///
+/// Missing columns are reported on creation. The source stops at the end of the file or on
+/// the first unreadable row, like a null or text price, the latter being reported by
+/// [`ParquetSource::error`].
+///
+/// # Example
+/// ```no_run
+/// use candlestick_rs::{OhlcvSource, ParquetSource, PatternSet};
+/// let file = std::fs::File::open("candles.parquet")?;
+/// let columns = ["ts", "o", "h", "l", "c", "v"];
+/// let source = ParquetSource::with_columns(file, columns)?;
+///
+/// let mut scanner = source.scan_patterns(PatternSet::all());
+/// for (idx, patterns) in scanner.by_ref() {
+///     println!("{idx}: {patterns:?}");
+/// }
+/// if let Some(error) = scanner.into_inner().error() {
+///     eprintln!("scan stopped early: {error}");
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "parquet")]
+pub struct ParquetSource {
+    rows: RowIter<'static>,
+    read: usize,
+    error: Option<ParquetError>,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetSource {
+    /// Returns a source reading the `timestamp`, `open`, `high`, `low`, `close` and `volume`
+    /// columns of the file
+    pub fn new<R: ChunkReader + 'static>(reader: R) -> Result<Self, ParquetError> {
+        Self::with_columns(
+            reader,
+            ["timestamp", "open", "high", "low", "close", "volume"],
+        )
+    }
+
+    /// Returns a source reading the given timestamp, open, high, low, close and volume columns
+    /// of the file
+    pub fn with_columns<R: ChunkReader + 'static>(
+        reader: R,
+        columns: [&str; 6],
+    ) -> Result<Self, ParquetError> {
+        let reader = SerializedFileReader::new(reader)?;
+        let schema = reader.metadata().file_metadata().schema_descr_ptr();
+        let root = schema.root_schema();
+
+        let fields = columns
+            .iter()
+            .map(|&name| {
+                root.get_fields()
+                    .iter()
+                    .find(|field| field.name() == name)
+                    .cloned()
+                    .ok_or_else(|| ParquetError::General(format!("missing column `{name}`")))
+            })
+            .collect::<Result<_, _>>()?;
+        let projection = Type::group_type_builder(root.name())
+            .with_fields(fields)
+            .build()?;
+        let rows = RowIter::from_file_into(Box::new(reader)).project(Some(projection))?;
+
+        Ok(Self {
+            rows,
+            read: 0,
+            error: None,
+        })
+    }
+
+    /// Returns the error that stopped the source, if any
+    pub fn error(&self) -> Option<&ParquetError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl OhlcvSource for ParquetSource {
+    type Candle = Candle;
+
+    fn next_candle(&mut self) -> Option<Candle> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        let row = match self.rows.next()? {
+            Ok(row) => row,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.read += 1;
+
+        match parse_record(&row) {
+            Some(candle) => Some(candle),
+            None => {
+                self.error = Some(ParquetError::General(format!(
+                    "malformed candle on row {}",
+                    self.read
+                )));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl core::fmt::Debug for ParquetSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParquetSource")
+            .field("read", &self.read)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+// Converts a projected `timestamp,open,high,low,close,volume` record
+#[cfg(feature = "parquet")]
+fn parse_record(row: &Row) -> Option<Candle> {
+    let mut fields = row.get_column_iter().map(|(_, field)| field);
+    let timestamp = match fields.next()? {
+        Field::Int(value) | Field::Date(value) => i64::from(*value),
+        Field::Long(value) | Field::TimestampMillis(value) | Field::TimestampMicros(value) => {
+            *value
+        }
+        _ => return None,
+    };
+    let mut price = || match fields.next()? {
+        Field::Float(value) => Some(f64::from(*value)),
+        Field::Double(value) => Some(*value),
+        Field::Int(value) => Some(f64::from(*value)),
+        Field::Long(value) => Some(*value as f64),
+        _ => None,
+    };
+
+    Some(Candle::new(
+        timestamp,
+        price()?,
+        price()?,
+        price()?,
+        price()?,
+        price()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Scanner};
+
+    #[test]
+    fn test_slice_source_matches_scanner() {
+        let candles = [
+            fixtures::BULLISH_ENGULFING[0],
+            fixtures::BULLISH_ENGULFING[1],
+            fixtures::MORNING_STAR[0],
+            fixtures::MORNING_STAR[1],
+            fixtures::MORNING_STAR[2],
+            fixtures::UPTHRUST[0],
+            fixtures::UPTHRUST[1],
+            fixtures::UPTHRUST[2],
+        ];
+
+        assert!(candles.iter().scan_patterns(PatternSet::all()).count() >= 3);
+        assert!(candles
+            .iter()
+            .scan_patterns(PatternSet::all())
+            .eq(Scanner::new(&candles)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_channel_source() {
+        use crate::Pattern;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        for candle in fixtures::BULLISH_ENGULFING {
+            assert!(sender.send(candle).is_ok());
+        }
+        drop(sender);
+
+        let mut scanner = receiver.scan_patterns(PatternSet::all());
+        let engulfing = scanner.next();
+        assert_eq!(engulfing.map(|(idx, _)| idx), Some(1));
+        assert!(engulfing.is_some_and(|(_, detected)| detected.contains(Pattern::BullishEngulfing)));
+        assert_eq!(scanner.next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_csv_source_stops_on_malformed_rows() {
+        let csv = "1,101.0,102.0,99.5,100.5,1200\n\n2,99.0,103.0,98.5,102.5\n3,1,1,1,1,1\n";
+        let mut source = CsvSource::new(csv.as_bytes());
+
+        assert_eq!(source.next_candle().map(|c| c.timestamp), Some(1));
+        assert!(source.next_candle().is_none());
+        assert_eq!(
+            source.error().map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+        assert!(source.next_candle().is_none());
+    }
+
+    #[cfg(feature = "parquet")]
+    fn parquet_file(
+        schema: &str,
+        mut write: impl FnMut(
+            usize,
+            &mut parquet::file::writer::SerializedColumnWriter<'_>,
+        ) -> Result<usize, ParquetError>,
+    ) -> Result<bytes::Bytes, ParquetError> {
+        use parquet::{file::writer::SerializedFileWriter, schema::parser::parse_message_type};
+        use std::{sync::Arc, vec::Vec};
+
+        let schema = Arc::new(parse_message_type(schema)?);
+        let mut writer = SerializedFileWriter::new(Vec::new(), schema, Default::default())?;
+        let mut group = writer.next_row_group()?;
+        let mut idx = 0;
+        while let Some(mut column) = group.next_column()? {
+            write(idx, &mut column)?;
+            column.close()?;
+            idx += 1;
+        }
+        group.close()?;
+
+        Ok(writer.into_inner()?.into())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_source_reads_configured_columns() -> Result<(), ParquetError> {
+        use crate::Pattern;
+        use parquet::data_type::{ByteArray, DoubleType, FloatType, Int64Type};
+
+        let [prev, curr] = fixtures::BULLISH_ENGULFING;
+        let schema = "message candles {
+            required binary symbol (UTF8);
+            required int64 time (TIMESTAMP(MILLIS, true));
+            required double c;
+            required double o;
+            required double h;
+            required double l;
+            required float v;
+        }";
+        let file = parquet_file(schema, |idx, column| match idx {
+            0 => column
+                .typed::<parquet::data_type::ByteArrayType>()
+                .write_batch(
+                    &[ByteArray::from("BTC"), ByteArray::from("BTC")],
+                    None,
+                    None,
+                ),
+            1 => column
+                .typed::<Int64Type>()
+                .write_batch(&[60_000, 120_000], None, None),
+            6 => column
+                .typed::<FloatType>()
+                .write_batch(&[1_200.0, 1_500.0], None, None),
+            _ => {
+                let field = |(o, h, l, c, _): fixtures::Ohlcv| [c, o, h, l][idx - 2];
+                column
+                    .typed::<DoubleType>()
+                    .write_batch(&[field(prev), field(curr)], None, None)
+            }
+        })?;
+
+        let columns = ["time", "o", "h", "l", "c", "v"];
+        let mut source = ParquetSource::with_columns(file.clone(), columns)?;
+        let first = source.next_candle();
+        assert_eq!(
+            first.map(|c| (c.timestamp, c.open, c.close)),
+            Some((60_000, prev.0, prev.3))
+        );
+        assert_eq!(first.map(|c| c.volume), Some(1_200.0));
+
+        let mut scanner =
+            ParquetSource::with_columns(file, columns)?.scan_patterns(PatternSet::all());
+        let engulfing = scanner.next();
+        assert_eq!(engulfing.map(|(idx, _)| idx), Some(1));
+        assert!(engulfing.is_some_and(|(_, detected)| detected.contains(Pattern::BullishEngulfing)));
+        assert_eq!(scanner.next(), None);
+        assert!(scanner.into_inner().error().is_none());
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_source_reports_missing_columns_and_malformed_rows() -> Result<(), ParquetError>
+    {
+        use parquet::data_type::{DoubleType, Int64Type};
+
+        let schema = "message candles {
+            required int64 timestamp;
+            required double open;
+            required double high;
+            required double low;
+            optional double close;
+            required double volume;
+        }";
+        let file = parquet_file(schema, |idx, column| match idx {
+            0 => column
+                .typed::<Int64Type>()
+                .write_batch(&[1, 2, 3], None, None),
+            4 => column
+                .typed::<DoubleType>()
+                .write_batch(&[100.5, 102.5], Some(&[1, 0, 1]), None),
+            _ => column
+                .typed::<DoubleType>()
+                .write_batch(&[100.0, 101.0, 102.0], None, None),
+        })?;
+
+        let missing =
+            ParquetSource::with_columns(file.clone(), ["timestamp", "o", "h", "l", "c", "v"]);
+        assert!(missing
+            .is_err_and(|e| matches!(e, ParquetError::General(m) if m == "missing column `o`")));
+
+        let mut source = ParquetSource::new(file)?;
+        assert_eq!(source.next_candle().map(|c| c.close), Some(100.5));
+        assert!(source.next_candle().is_none());
+        assert!(source.error().is_some_and(
+            |e| matches!(e, ParquetError::General(m) if m == "malformed candle on row 2")
+        ));
+        assert!(source.next_candle().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{CandleStick, CandleStream};
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Spinning Top at the end of an advance, a warning that buyers are losing
+    /// control.
+    ///
+    /// The latest candle must be a [`CandleStick::is_spinning_top`], and the `n` previous
+    /// candles must close higher overall. Returns false if `n` is lower than two or fewer than
+    /// `n + 1` candles are available.
+    ///
+    /// **Trading Significance**:
+    /// - The advance stalls as sellers match the buyers within the candle
+    /// - Often used to tighten stops on long positions rather than to sell outright
+    /// - A bearish candle closing below the spinning top confirms the loss of momentum
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 101.2, 99.8, 101.0, 0.0),
+    ///     (101.0, 102.2, 100.8, 102.0, 0.0),
+    ///     (102.0, 103.2, 101.8, 103.0, 0.0),
+    ///     (103.0, 104.5, 101.5, 103.3, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert!(series.is_spinning_top_after_advance(3));
+    /// assert!(!series.is_spinning_top_after_decline(3));
+    /// ```
+    pub fn is_spinning_top_after_advance(&self, n: usize) -> bool {
+        self.latest_with_move(n)
+            .is_some_and(|(c, _, net)| net > 0.0 && c.is_spinning_top())
+    }
+
+    /// Identifies a Spinning Top at the end of a decline, a warning that sellers are losing
+    /// control.
+    ///
+    /// Mirror of [`CandleStream::is_spinning_top_after_advance`].
+    ///
+    /// **Trading Significance**:
+    /// - The decline stalls as buyers match the sellers within the candle
+    /// - Often used to cover short positions or prepare a long entry
+    /// - A bullish candle closing above the spinning top confirms the loss of momentum
+    pub fn is_spinning_top_after_decline(&self, n: usize) -> bool {
+        self.latest_with_move(n)
+            .is_some_and(|(c, _, net)| net < 0.0 && c.is_spinning_top())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 10> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    const DECLINE: [Ohlcv; 4] = [
+        (103.0, 103.2, 101.8, 102.0, 0.0),
+        (102.0, 102.2, 100.8, 101.0, 0.0),
+        (101.0, 101.2, 99.8, 100.0, 0.0),
+        (100.0, 101.5, 98.5, 99.7, 0.0),
+    ];
+
+    #[test]
+    fn test_spinning_top_after_decline() {
+        assert!(series(&DECLINE).is_spinning_top_after_decline(3));
+        assert!(!series(&DECLINE).is_spinning_top_after_advance(3));
+        assert!(!series(&DECLINE).is_spinning_top_after_decline(1));
+        assert!(!series(&DECLINE).is_spinning_top_after_decline(4));
+
+        let mut marubozu = DECLINE;
+        marubozu[3] = (100.0, 100.0, 97.0, 97.0, 0.0);
+        assert!(!series(&marubozu).is_spinning_top_after_decline(3));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{CandleStick, CandleStream};
+
+/// Body and range statistics over a window of candles, as returned by [`CandleStream::stats`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    /// Average body size
+    pub mean_body: f64,
+    /// Largest body size
+    pub max_body: f64,
+    /// Average high to low range
+    pub mean_range: f64,
+    /// Largest high to low range
+    pub max_range: f64,
+    /// Average body to range ratio
+    pub mean_body_ratio: f64,
+}
+
+impl WindowStats {
+    /// Returns the body of the candle relative to the average body of the window
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let small = (100.0, 101.0, 99.0, 100.5, 0.0);
+    /// let large = (100.5, 103.0, 100.0, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let stats = series.push(&small).push(&small).push(&large).stats(3).unwrap();
+    /// assert_eq!(stats.relative_body(&large), 2.0);
+    /// ```
+    pub fn relative_body<T: CandleStick>(&self, candle: &T) -> f64 {
+        candle.body() / self.mean_body
+    }
+
+    /// Returns the range of the candle relative to the average range of the window
+    pub fn relative_range<T: CandleStick>(&self, candle: &T) -> f64 {
+        candle.range() / self.mean_range
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns body and range statistics of the last `n` candles, including the latest one.
+    ///
+    /// Returns `None` if `n` is zero or fewer than `n` candles are available. Useful for relative
+    /// judgments, like how large the latest body is compared to the recent average.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candle1 = (100.0, 102.0, 99.0, 101.0, 0.0);
+    /// let candle2 = (101.0, 104.0, 100.0, 104.0, 0.0);
+    /// let mut series = CandleStream::new();
+    /// let stats = series.push(&candle1).push(&candle2).stats(2).unwrap();
+    /// assert_eq!(stats.mean_body, 2.0);
+    /// assert_eq!(stats.max_range, 4.0);
+    /// ```
+    pub fn stats(&self, n: usize) -> Option<WindowStats> {
+        if n == 0 || n > self.len() {
+            return None;
+        }
+
+        let (sum_body, max_body, sum_range, max_range, sum_ratio) = self.iter().take(n).fold(
+            (0.0, f64::MIN, 0.0, f64::MIN, 0.0),
+            |(sum_body, max_body, sum_range, max_range, sum_ratio), c| {
+                (
+                    sum_body + c.body(),
+                    c.body().max(max_body),
+                    sum_range + c.range(),
+                    c.range().max(max_range),
+                    sum_ratio + c.body_range_ratio(),
+                )
+            },
+        );
+        let count = n as f64;
+
+        Some(WindowStats {
+            mean_body: sum_body / count,
+            max_body,
+            mean_range: sum_range / count,
+            max_range,
+            mean_body_ratio: sum_ratio / count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let candle1 = (100.0, 102.0, 99.0, 101.0, 0.0);
+        let candle2 = (101.0, 104.0, 100.0, 104.0, 0.0);
+        let candle3 = (104.0, 105.0, 99.0, 100.0, 0.0);
+
+        let mut series = CandleStream::new();
+        assert_eq!(series.stats(1), None);
+
+        series.push(&candle1).push(&candle2).push(&candle3);
+
+        assert_eq!(series.stats(0), None);
+        assert_eq!(series.stats(4), None);
+        assert_eq!(
+            series.stats(2),
+            Some(WindowStats {
+                mean_body: 3.5,
+                max_body: 4.0,
+                mean_range: 5.0,
+                max_range: 6.0,
+                mean_body_ratio: (3.0 / 4.0 + 4.0 / 6.0) / 2.0,
+            })
+        );
+        assert_eq!(series.stats(3).map(|s| s.mean_body), Some(8.0 / 3.0));
+    }
+}
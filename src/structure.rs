@@ -0,0 +1,183 @@
+use crate::{CandleStick, CandleStream, Direction, Swing, SwingKind};
+
+/// The label of a swing relative to the previous swing of the same side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StructureLabel {
+    /// A swing high above the previous swing high
+    HigherHigh,
+    /// A swing high below the previous swing high
+    LowerHigh,
+    /// A swing high level with the previous swing high, within one tick
+    EqualHigh,
+    /// A swing low above the previous swing low
+    HigherLow,
+    /// A swing low below the previous swing low
+    LowerLow,
+    /// A swing low level with the previous swing low, within one tick
+    EqualLow,
+}
+
+/// A labeled swing, as returned by [`CandleStream::market_structure`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StructurePoint {
+    /// The swing point
+    pub swing: Swing,
+    /// The swing compared to the previous swing of the same side
+    pub label: StructureLabel,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Iterates over the swing points of the given `strength`, labeled against the previous
+    /// swing of the same side, from the latest to the oldest.
+    ///
+    /// The oldest swing high and swing low have nothing to compare with and are skipped.
+    /// Prices within one tick of each other, see [`CandleStick::tick_size`], are equal.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, StructureLabel};
+    /// let candles = [
+    ///     (100.0, 102.0, 99.0, 101.0, 0.0),
+    ///     (101.0, 105.0, 101.0, 104.0, 0.0),
+    ///     (104.0, 104.0, 100.0, 101.0, 0.0),
+    ///     (102.5, 108.0, 102.0, 107.0, 0.0),
+    ///     (107.0, 107.5, 101.5, 102.0, 0.0),
+    ///     (104.0, 111.0, 103.5, 110.0, 0.0),
+    ///     (110.0, 110.5, 104.0, 105.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 10>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let labels = series.market_structure(1).map(|point| point.label);
+    /// assert!(labels.eq([
+    ///     StructureLabel::HigherHigh,
+    ///     StructureLabel::HigherLow,
+    ///     StructureLabel::HigherHigh,
+    /// ]));
+    /// ```
+    pub fn market_structure(&self, strength: usize) -> impl Iterator<Item = StructurePoint> + '_ {
+        let latest = self.get();
+
+        self.swings(strength).filter_map(move |swing| {
+            let previous = self
+                .swings(strength)
+                .skip_while(|s| s.bars_ago <= swing.bars_ago)
+                .find(|s| s.kind == swing.kind)?;
+            let equal = latest?.prices_equal(swing.price, previous.price);
+
+            let label = match (swing.kind, equal, swing.price > previous.price) {
+                (SwingKind::High, true, _) => StructureLabel::EqualHigh,
+                (SwingKind::High, _, true) => StructureLabel::HigherHigh,
+                (SwingKind::High, _, false) => StructureLabel::LowerHigh,
+                (SwingKind::Low, true, _) => StructureLabel::EqualLow,
+                (SwingKind::Low, _, true) => StructureLabel::HigherLow,
+                (SwingKind::Low, _, false) => StructureLabel::LowerLow,
+            };
+            Some(StructurePoint { swing, label })
+        })
+    }
+
+    /// Returns the structural bias from the latest labeled swing high and swing low: bullish
+    /// on a higher high with a higher low, bearish on a lower high with a lower low, and
+    /// `None` while the structure is mixed.
+    ///
+    /// **Trading Significance**:
+    /// - The primary trend filter of discretionary, price-action based strategies
+    /// - A first lower low in a bullish structure warns of a change of character
+    pub fn structure_bias(&self, strength: usize) -> Option<Direction> {
+        let latest = |kind| {
+            self.market_structure(strength)
+                .find(|point| point.swing.kind == kind)
+                .map(|point| point.label)
+        };
+        let (high, low) = (latest(SwingKind::High)?, latest(SwingKind::Low)?);
+
+        match (high, low) {
+            (StructureLabel::HigherHigh, StructureLabel::HigherLow) => Some(Direction::Bullish),
+            (StructureLabel::LowerHigh, StructureLabel::LowerLow) => Some(Direction::Bearish),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Thresholds;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const UPTREND: [(f64, f64); 9] = [
+        (102.0, 99.0),
+        (105.0, 101.0),
+        (103.5, 100.0),
+        (108.0, 102.0),
+        (106.0, 101.5),
+        (111.0, 103.5),
+        (109.0, 103.0),
+        (113.0, 105.0),
+        (112.0, 106.0),
+    ];
+
+    fn series<'s>(candles: &'s [Ohlcv]) -> CandleStream<'s, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_bullish_structure() {
+        let candles = UPTREND.map(candle);
+        let stream = series(&candles);
+
+        assert!(stream.market_structure(1).all(|p| matches!(
+            p.label,
+            StructureLabel::HigherHigh | StructureLabel::HigherLow
+        )));
+        assert_eq!(stream.market_structure(1).count(), 5);
+        assert_eq!(stream.structure_bias(1), Some(Direction::Bullish));
+    }
+
+    #[test]
+    fn test_bearish_and_mixed_structure() {
+        let downtrend = UPTREND.map(|(high, low)| candle((200.0 - low, 200.0 - high)));
+        assert_eq!(
+            series(&downtrend).structure_bias(1),
+            Some(Direction::Bearish)
+        );
+
+        let mut breakdown = UPTREND.map(candle);
+        breakdown[7] = candle((110.5, 103.5));
+        breakdown[8] = candle((109.0, 103.8));
+        assert_eq!(series(&breakdown).structure_bias(1), None);
+    }
+
+    #[test]
+    fn test_equal_highs_within_tick() {
+        let mut shape = UPTREND;
+        shape[7] = (111.04, 105.0);
+        shape[8] = (110.0, 106.0);
+        let thresholds = Thresholds {
+            tick_size: 0.05,
+            ..Thresholds::default()
+        };
+        let candles = shape.map(|bar| thresholds.apply(candle(bar)));
+
+        let mut stream = CandleStream::<_, 20>::with_capacity();
+        for candle in &candles {
+            stream.push(candle);
+        }
+
+        let latest = stream.market_structure(1).next();
+        assert!(latest.is_some_and(|p| p.label == StructureLabel::EqualHigh));
+        assert_eq!(stream.structure_bias(1), None);
+    }
+}
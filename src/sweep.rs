@@ -0,0 +1,153 @@
+use crate::{CandleStick, CandleStream, Direction, Swing, SwingKind};
+
+/// A liquidity sweep by the latest candle, as returned by [`CandleStream::liquidity_sweep`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquiditySweep {
+    /// Bullish when lows were swept and the candle closed back above them, bearish when highs
+    /// were swept and the candle closed back below them
+    pub direction: Direction,
+    /// The swept price level
+    pub level: f64,
+    /// Position of the candle that set the level, `0` being the latest candle
+    pub bars_ago: usize,
+    /// Distance the wick traded beyond the level
+    pub penetration: f64,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Liquidity Sweep, or stop hunt, where the latest candle wicks beyond a
+    /// swing high or low of the given `strength` and closes back inside.
+    ///
+    /// Only levels no candle traded beyond since they formed are considered, and the most
+    /// extreme swept level is reported. Swing highs are checked before swing lows.
+    ///
+    /// **Trading Significance**:
+    /// - Stops resting beyond obvious levels are triggered, then the move fails
+    /// - Shows larger participants filling orders against trapped breakout traders
+    /// - Pairs well with pin bar geometry, like a hammer sweeping a swing low
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction};
+    /// let candles = [
+    ///     (104.0, 105.0, 103.0, 103.5, 0.0),
+    ///     (103.5, 104.0, 100.0, 100.5, 0.0),
+    ///     (100.5, 102.5, 100.5, 102.0, 0.0),
+    ///     (102.0, 103.0, 101.0, 101.5, 0.0),
+    ///     (101.5, 102.0, 99.0, 101.8, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let sweep = series.liquidity_sweep(1).unwrap();
+    /// assert_eq!((sweep.direction, sweep.level, sweep.bars_ago), (Direction::Bullish, 100.0, 3));
+    /// assert_eq!(sweep.penetration, 1.0);
+    /// ```
+    pub fn liquidity_sweep(&self, strength: usize) -> Option<LiquiditySweep> {
+        self.swept(strength, SwingKind::High)
+            .or_else(|| self.swept(strength, SwingKind::Low))
+    }
+
+    // Returns the most extreme swing of the given kind swept by the latest candle
+    fn swept(&self, strength: usize, kind: SwingKind) -> Option<LiquiditySweep> {
+        let latest = self.get()?;
+        // prices are negated on the low side so that beyond always means above
+        let side = match kind {
+            SwingKind::High => 1.0,
+            SwingKind::Low => -1.0,
+        };
+        let extreme = |c: &T| match kind {
+            SwingKind::High => c.high(),
+            SwingKind::Low => -c.low(),
+        };
+
+        let untouched = |swing: &Swing| {
+            (1..swing.bars_ago)
+                .filter_map(|n| self.prev(n))
+                .all(|c| extreme(c) <= side * swing.price)
+        };
+        let swing = self
+            .swings(strength)
+            .filter(|swing| swing.kind == kind)
+            .filter(|swing| extreme(latest) > side * swing.price)
+            .filter(|swing| side * latest.close() < side * swing.price)
+            .filter(untouched)
+            .reduce(|a, b| match side * b.price > side * a.price {
+                true => b,
+                false => a,
+            })?;
+
+        Some(LiquiditySweep {
+            direction: match kind {
+                SwingKind::High => Direction::Bearish,
+                SwingKind::Low => Direction::Bullish,
+            },
+            level: swing.price,
+            bars_ago: swing.bars_ago,
+            penetration: extreme(latest) - side * swing.price,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn inverted(candle: &Ohlcv) -> Ohlcv {
+        let (o, h, l, c, v) = *candle;
+        (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v)
+    }
+
+    const RANGE: [Ohlcv; 6] = [
+        (104.0, 105.0, 103.0, 103.5, 0.0),
+        (103.5, 104.0, 100.0, 100.5, 0.0),
+        (100.5, 102.5, 100.5, 102.0, 0.0),
+        (102.0, 103.0, 101.0, 101.5, 0.0),
+        (101.5, 101.8, 99.8, 100.2, 0.0),
+        (100.2, 102.0, 100.1, 101.5, 0.0),
+    ];
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_sweep_of_the_lowest_untouched_level() {
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 7];
+        candles[..6].copy_from_slice(&RANGE);
+        candles[6] = (101.5, 102.0, 99.0, 101.0, 0.0);
+
+        let sweep = series(&candles).liquidity_sweep(1);
+        assert!(sweep.is_some_and(|s| (s.level, s.bars_ago) == (99.8, 2)));
+        assert!(sweep.is_some_and(|s| s.direction == Direction::Bullish));
+    }
+
+    #[test]
+    fn test_bearish_sweep() {
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 7];
+        candles[..6].copy_from_slice(&RANGE);
+        candles[6] = (101.5, 102.0, 99.0, 101.0, 0.0);
+        let candles = candles.map(|c| inverted(&c));
+
+        let sweep = series(&candles).liquidity_sweep(1);
+        assert!(sweep.is_some_and(|s| s.direction == Direction::Bearish));
+        assert!(sweep.is_some_and(|s| (s.level - 100.2).abs() < 1e-9));
+        assert!(sweep.is_some_and(|s| (s.penetration - 0.8).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_breakdown_is_not_a_sweep() {
+        let mut candles = [(0.0, 0.0, 0.0, 0.0, 0.0); 7];
+        candles[..6].copy_from_slice(&RANGE);
+        candles[6] = (101.5, 102.0, 99.0, 99.2, 0.0);
+
+        assert_eq!(series(&candles).liquidity_sweep(1), None);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{CandleStick, CandleStream};
+
+/// The side of a [`Swing`] point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwingKind {
+    /// Local maximum of the highs
+    High,
+    /// Local minimum of the lows
+    Low,
+}
+
+/// A swing point of the buffered history, as returned by [`CandleStream::swings`]
+///
+/// A swing high is a candle whose high is strictly above the highs of the `strength` candles
+/// on each side, and a swing low a candle whose low is strictly below their lows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Swing {
+    /// Whether the swing is a high or a low
+    pub kind: SwingKind,
+    /// Position of the swing candle, `0` being the latest candle
+    pub bars_ago: usize,
+    /// High of a swing high, or low of a swing low
+    pub price: f64,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Iterates over the swing points of the buffered history, from the latest to the oldest.
+    ///
+    /// A swing needs `strength` candles on each side to be confirmed, so the latest `strength`
+    /// candles never qualify. A candle that is both a swing high and a swing low yields the
+    /// high first. Use a capacity large enough to hold the formations of interest.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, SwingKind};
+    /// let candles = [
+    ///     (100.0, 102.0, 99.0, 101.0, 0.0),
+    ///     (101.0, 106.0, 100.0, 105.0, 0.0),
+    ///     (105.0, 105.5, 101.0, 102.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::new();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let swing = series.swings(1).next().unwrap();
+    /// assert_eq!((swing.kind, swing.bars_ago, swing.price), (SwingKind::High, 1, 106.0));
+    /// ```
+    pub fn swings(&self, strength: usize) -> impl Iterator<Item = Swing> + '_ {
+        (strength..self.len().saturating_sub(strength)).flat_map(move |bars_ago| {
+            [SwingKind::High, SwingKind::Low]
+                .into_iter()
+                .filter_map(move |kind| self.swing_at(bars_ago, strength, kind))
+        })
+    }
+
+    /// Iterates over the swing highs of the buffered history, from the latest to the oldest
+    pub fn swing_highs(&self, strength: usize) -> impl Iterator<Item = Swing> + '_ {
+        self.swings(strength)
+            .filter(|swing| swing.kind == SwingKind::High)
+    }
+
+    /// Iterates over the swing lows of the buffered history, from the latest to the oldest
+    pub fn swing_lows(&self, strength: usize) -> impl Iterator<Item = Swing> + '_ {
+        self.swings(strength)
+            .filter(|swing| swing.kind == SwingKind::Low)
+    }
+
+    // Returns the swing of the given kind at the given position, if any
+    fn swing_at(&self, bars_ago: usize, strength: usize, kind: SwingKind) -> Option<Swing> {
+        // lows are negated so that a swing is always a strict maximum
+        let extreme = |c: &T| match kind {
+            SwingKind::High => c.high(),
+            SwingKind::Low => -c.low(),
+        };
+        let pivot = extreme(self.prev(bars_ago)?);
+
+        (bars_ago.checked_sub(strength)?..=bars_ago + strength)
+            .filter(|&n| n != bars_ago)
+            .all(|n| self.prev(n).is_some_and(|c| extreme(c) < pivot))
+            .then_some(Swing {
+                kind,
+                bars_ago,
+                price: match kind {
+                    SwingKind::High => pivot,
+                    SwingKind::Low => -pivot,
+                },
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swings() {
+        let candles = [
+            (100.0, 102.0, 99.0, 101.0, 0.0),
+            (101.0, 105.0, 100.0, 104.0, 0.0),
+            (104.0, 110.0, 103.0, 108.0, 0.0),
+            (108.0, 108.5, 103.0, 104.0, 0.0),
+            (104.0, 105.0, 100.0, 101.0, 0.0),
+            (101.0, 106.0, 100.5, 105.0, 0.0),
+            (105.0, 106.0, 104.0, 105.5, 0.0),
+        ];
+
+        let mut series = CandleStream::<_, 10>::with_capacity();
+        for candle in candles.iter() {
+            series.push(candle);
+        }
+
+        assert!(series.swings(2).eq([
+            Swing {
+                kind: SwingKind::Low,
+                bars_ago: 2,
+                price: 100.0
+            },
+            Swing {
+                kind: SwingKind::High,
+                bars_ago: 4,
+                price: 110.0
+            },
+        ]));
+        assert_eq!(series.swing_highs(1).count(), 1);
+        assert_eq!(series.swings(4).count(), 0);
+    }
+}
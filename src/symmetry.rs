@@ -0,0 +1,196 @@
+use crate::{candle_stream::SERIES_SIZE, CandleStick, CandleStream, Inverted, PatternDetector};
+
+/// A bar where a detector and its mirror disagree, as returned by [`SymmetryCheck`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Asymmetry {
+    /// Index of the bar in the series
+    pub index: usize,
+    /// Whether the detector matched the original series on this bar
+    pub original: bool,
+    /// Whether the mirror detector matched the inverted series on this bar
+    pub mirrored: bool,
+}
+
+/// The `SymmetryCheck` runs a detector over a candle series and its mirror detector over the
+/// inverted series, and reports the bars where they disagree.
+///
+/// A bullish pattern reflected around a pivot price, see [`crate::invert_series`], must be
+/// found by its bearish counterpart on the same bar. Running the check over real or synthetic
+/// data catches definitions of a pair drifting apart as the pattern catalog grows.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Pattern, SymmetryCheck};
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+///     (102.5, 104.0, 102.0, 103.5, 0.0),
+/// ];
+/// let engulfing = SymmetryCheck::new(
+///     &candles,
+///     100.0,
+///     &Pattern::BullishEngulfing,
+///     &Pattern::BearishEngulfing,
+/// );
+/// assert_eq!(engulfing.count(), 0);
+///
+/// let mismatched = SymmetryCheck::new(
+///     &candles,
+///     100.0,
+///     &Pattern::BullishEngulfing,
+///     &Pattern::BearishHarami,
+/// );
+/// let asymmetry = mismatched.last().unwrap();
+/// assert_eq!((asymmetry.index, asymmetry.original, asymmetry.mirrored), (1, true, false));
+/// ```
+pub struct SymmetryCheck<'s, 'd, T> {
+    candles: &'s [T],
+    pivot: f64,
+    detector: &'d dyn PatternDetector<T>,
+    mirror: &'d dyn PatternDetector<Inverted<&'s T>>,
+    idx: usize,
+}
+
+impl<'s, 'd, T> SymmetryCheck<'s, 'd, T> {
+    /// Returns a check of the detector against its mirror, reflecting the candles around
+    /// `pivot`
+    pub fn new(
+        candles: &'s [T],
+        pivot: f64,
+        detector: &'d dyn PatternDetector<T>,
+        mirror: &'d dyn PatternDetector<Inverted<&'s T>>,
+    ) -> Self {
+        Self {
+            candles,
+            pivot,
+            detector,
+            mirror,
+            idx: 0,
+        }
+    }
+}
+
+impl<T: CandleStick> Iterator for SymmetryCheck<'_, '_, T> {
+    type Item = Asymmetry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(window) = self
+            .candles
+            .get((self.idx + 1).saturating_sub(SERIES_SIZE)..=self.idx)
+        {
+            let index = self.idx;
+            self.idx += 1;
+
+            let mut stream = CandleStream::new();
+            for candle in window {
+                stream.push(candle);
+            }
+            let inverted: [Option<Inverted<&T>>; SERIES_SIZE] =
+                core::array::from_fn(|i| window.get(i).map(|c| c.invert(self.pivot)));
+            let mut mirror = CandleStream::new();
+            for candle in inverted.iter().flatten() {
+                mirror.push(candle);
+            }
+
+            let original = self.detector.detect(&stream).is_some();
+            let mirrored = self.mirror.detect(&mirror).is_some();
+            if original != mirrored {
+                return Some(Asymmetry {
+                    index,
+                    original,
+                    mirrored,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl<T> core::fmt::Debug for SymmetryCheck<'_, '_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SymmetryCheck")
+            .field("len", &self.candles.len())
+            .field("pivot", &self.pivot)
+            .field("idx", &self.idx)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+
+    // a noisy series crossing the pivot, with gaps, doji and engulfing bodies
+    const CANDLES: [(f64, f64, f64, f64, f64); 16] = [
+        (100.0, 101.0, 99.0, 100.5, 0.0),
+        (100.5, 102.0, 100.0, 101.8, 0.0),
+        (101.8, 103.5, 101.5, 103.2, 0.0),
+        (104.0, 104.6, 103.8, 104.05, 0.0),
+        (103.5, 103.8, 101.0, 101.2, 0.0),
+        (101.0, 101.5, 98.0, 98.5, 0.0),
+        (98.6, 100.0, 98.2, 99.8, 0.0),
+        (97.0, 97.3, 96.8, 97.02, 0.0),
+        (97.5, 100.5, 97.2, 100.2, 0.0),
+        (100.4, 101.0, 99.8, 100.1, 0.0),
+        (100.2, 102.5, 99.5, 102.3, 0.0),
+        (102.5, 102.8, 100.0, 100.2, 0.0),
+        (100.0, 100.3, 98.5, 98.8, 0.0),
+        (98.9, 99.2, 97.0, 97.3, 0.0),
+        (97.2, 97.5, 95.5, 95.8, 0.0),
+        (95.0, 99.0, 94.8, 98.8, 0.0),
+    ];
+
+    #[test]
+    fn test_built_in_pairs_are_symmetric() {
+        let pairs = [
+            (Pattern::RisingThreeMethods, Pattern::FallingThreeMethods),
+            (Pattern::BullishTriStar, Pattern::BearishTriStar),
+            (Pattern::MorningStarDoji, Pattern::EveningStarDoji),
+            (Pattern::ThreeWhiteSoldiers, Pattern::ThreeBlackCrows),
+            (Pattern::ThreeInsideUp, Pattern::ThreeInsideDown),
+            (Pattern::UpsideTasukiGap, Pattern::DownsideTasukiGap),
+            (
+                Pattern::BullishIslandReversal,
+                Pattern::BearishIslandReversal,
+            ),
+            (Pattern::BullishEngulfing, Pattern::BearishEngulfing),
+            (Pattern::TweezerBottom, Pattern::TweezerTop),
+            (Pattern::BullishMeetingLines, Pattern::BearishMeetingLines),
+            (Pattern::BullishHarami, Pattern::BearishHarami),
+            (Pattern::BullishDojiStar, Pattern::BearishDojiStar),
+            (Pattern::BullishStar, Pattern::BearishStar),
+            (Pattern::BullishNearStar, Pattern::BearishNearStar),
+            (Pattern::NoSupply, Pattern::NoDemand),
+        ];
+
+        // left out: the morning and evening stars both accept a bullish star candle, only
+        // the upthrust requires a wide spread on high volume, unlike the spring, and the
+        // piercing line opens below the first low and closes within its body, while the dark
+        // cloud cover only opens above the first close, and both side-by-side white lines are
+        // made of bullish candles
+        for (bullish, bearish) in pairs {
+            let check = SymmetryCheck::new(&CANDLES, 100.0, &bullish, &bearish);
+            assert_eq!(check.count(), 0, "{bullish} and {bearish} are asymmetric");
+        }
+    }
+
+    #[test]
+    fn test_asymmetric_detector() {
+        let long_body = ("Long Body", |s: &CandleStream<'_, _>| {
+            s.get()
+                .is_some_and(|c: &(f64, f64, f64, f64, f64)| c.body() > 2.5)
+        });
+        let longer_body = ("Longer Body", |s: &CandleStream<'_, Inverted<&_>>| {
+            s.get().is_some_and(|c| c.body() > 3.5)
+        });
+
+        let check = SymmetryCheck::new(&CANDLES, 100.0, &long_body, &longer_body);
+        assert!(check.eq([Asymmetry {
+            index: 8,
+            original: true,
+            mirrored: false,
+        }]));
+    }
+}
@@ -0,0 +1,331 @@
+use crate::CandleStick;
+
+/// The price range a TA-Lib candle setting is averaged on
+#[derive(Debug, Clone, Copy)]
+enum RangeType {
+    RealBody,
+    HighLow,
+}
+
+/// A TA-Lib candle setting: the average of `range` over the previous `period` candles,
+/// multiplied by `factor`. A zero period uses the range of the candle itself.
+#[derive(Debug, Clone, Copy)]
+struct Setting {
+    range: RangeType,
+    period: usize,
+    factor: f64,
+}
+
+impl Setting {
+    const fn new(range: RangeType, period: usize, factor: f64) -> Self {
+        Self {
+            range,
+            period,
+            factor,
+        }
+    }
+}
+
+const BODY_LONG: Setting = Setting::new(RangeType::RealBody, 10, 1.0);
+const BODY_SHORT: Setting = Setting::new(RangeType::RealBody, 10, 1.0);
+const BODY_DOJI: Setting = Setting::new(RangeType::HighLow, 10, 0.1);
+const SHADOW_LONG: Setting = Setting::new(RangeType::RealBody, 0, 1.0);
+const SHADOW_VERY_SHORT: Setting = Setting::new(RangeType::HighLow, 10, 0.1);
+const NEAR: Setting = Setting::new(RangeType::HighLow, 5, 0.2);
+
+/// Returns the longest averaging period of the settings
+const fn lookback(settings: &[Setting]) -> usize {
+    let mut period = 0;
+    let mut i = 0;
+    while i < settings.len() {
+        if settings[i].period > period {
+            period = settings[i].period;
+        }
+        i += 1;
+    }
+    period
+}
+
+/// Reproduces the semantics of TA-Lib's `CDL*` functions, for users migrating from TA-Lib
+/// who want to validate parity before switching.
+///
+/// Unlike the ratio-based detectors of this crate, TA-Lib defines "long" and "short" bodies
+/// and shadows relative to the average of the previous candles, using its default candle
+/// settings (e.g. a doji body is at most 10% of the average range of the last 10 candles).
+///
+/// Every function yields one value per candle: `100` for a bullish signal, `-100` for a
+/// bearish one, `80`/`-80` where TA-Lib reports a weaker match, and `0` otherwise. The first
+/// candles, within the lookback of the function, always yield `0`, where TA-Lib would skip
+/// them instead.
+///
+/// Prices are compared as is, the thresholds of the candles are not used.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::TaLib;
+/// let candles = [
+///     (101.0, 102.0, 99.5, 100.5, 0.0),
+///     (99.0, 103.0, 98.5, 102.5, 0.0),
+/// ];
+/// let engulfing: Vec<i32> = TaLib::new(&candles).cdl_engulfing().collect();
+/// assert_eq!(engulfing, [0, 100]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TaLib<'s, T> {
+    candles: &'s [T],
+}
+
+impl<'s, T: CandleStick> TaLib<'s, T> {
+    /// Returns the TA-Lib compatible functions over the candles
+    pub fn new(candles: &'s [T]) -> Self {
+        Self { candles }
+    }
+
+    /// Equivalent of `CDLDOJI`
+    pub fn cdl_doji(&self) -> impl Iterator<Item = i32> + '_ {
+        self.scan(lookback(&[BODY_DOJI]), |i| {
+            if self.real_body(i) <= self.average(BODY_DOJI, i) {
+                100
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Equivalent of `CDLENGULFING`
+    pub fn cdl_engulfing(&self) -> impl Iterator<Item = i32> + '_ {
+        self.scan(1, |i| {
+            let (o, _, _, c) = self.candles[i].ohlc();
+            let (po, _, _, pc) = self.candles[i - 1].ohlc();
+            let color = self.color(i);
+
+            let engulfing = match (color, self.color(i - 1)) {
+                (1, -1) => (c >= po && o < pc) || (c > po && o <= pc),
+                (-1, 1) => (o >= pc && c < po) || (o > pc && c <= po),
+                _ => false,
+            };
+
+            match (engulfing, o != pc && c != po) {
+                (false, _) => 0,
+                (true, true) => color * 100,
+                (true, false) => color * 80,
+            }
+        })
+    }
+
+    /// Equivalent of `CDLHARAMI`
+    pub fn cdl_harami(&self) -> impl Iterator<Item = i32> + '_ {
+        self.scan(lookback(&[BODY_LONG, BODY_SHORT]) + 1, |i| {
+            if self.real_body(i - 1) <= self.average(BODY_LONG, i - 1)
+                || self.real_body(i) > self.average(BODY_SHORT, i)
+            {
+                return 0;
+            }
+
+            let (top, bottom) = self.body_bounds(i);
+            let (prev_top, prev_bottom) = self.body_bounds(i - 1);
+            if top < prev_top && bottom > prev_bottom {
+                -self.color(i - 1) * 100
+            } else if top <= prev_top && bottom >= prev_bottom {
+                -self.color(i - 1) * 80
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Equivalent of `CDLHAMMER`
+    pub fn cdl_hammer(&self) -> impl Iterator<Item = i32> + '_ {
+        let settings = [BODY_SHORT, SHADOW_LONG, SHADOW_VERY_SHORT, NEAR];
+
+        self.scan(lookback(&settings) + 1, |i| {
+            let (_, bottom) = self.body_bounds(i);
+            let hammer = self.real_body(i) < self.average(BODY_SHORT, i)
+                && self.lower_shadow(i) > self.average(SHADOW_LONG, i)
+                && self.upper_shadow(i) < self.average(SHADOW_VERY_SHORT, i)
+                && bottom <= self.candles[i - 1].low() + self.average(NEAR, i - 1);
+
+            if hammer {
+                100
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Equivalent of `CDLMORNINGSTAR`, with TA-Lib's default penetration of `0.3`
+    pub fn cdl_morning_star(&self) -> impl Iterator<Item = i32> + '_ {
+        self.star(-1)
+    }
+
+    /// Equivalent of `CDLEVENINGSTAR`, with TA-Lib's default penetration of `0.3`
+    pub fn cdl_evening_star(&self) -> impl Iterator<Item = i32> + '_ {
+        self.star(1)
+    }
+
+    /// Shared implementation of the morning and evening stars, `first` being the color of
+    /// the first candle
+    fn star(&self, first: i32) -> impl Iterator<Item = i32> + '_ {
+        const PENETRATION: f64 = 0.3;
+
+        self.scan(lookback(&[BODY_LONG, BODY_SHORT]) + 2, move |i| {
+            let (top, bottom) = self.body_bounds(i - 1);
+            let (first_top, first_bottom) = self.body_bounds(i - 2);
+            let gap = match first {
+                -1 => top < first_bottom,
+                _ => bottom > first_top,
+            };
+            let penetration = self.real_body(i - 2) * PENETRATION;
+            let penetrated = match first {
+                -1 => self.candles[i].close() > self.candles[i - 2].close() + penetration,
+                _ => self.candles[i].close() < self.candles[i - 2].close() - penetration,
+            };
+
+            let star = self.real_body(i - 2) > self.average(BODY_LONG, i - 2)
+                && self.color(i - 2) == first
+                && self.real_body(i - 1) <= self.average(BODY_SHORT, i - 1)
+                && gap
+                && self.real_body(i) > self.average(BODY_SHORT, i)
+                && self.color(i) == -first
+                && penetrated;
+
+            if star {
+                -first * 100
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Yields `0` for the first `lookback` candles and the signal of the others
+    fn scan(&self, lookback: usize, signal: impl Fn(usize) -> i32) -> impl Iterator<Item = i32> {
+        (0..self.candles.len()).map(move |i| if i < lookback { 0 } else { signal(i) })
+    }
+
+    /// TA-Lib candle color, white candles including those closing at their open
+    fn color(&self, i: usize) -> i32 {
+        if self.candles[i].close() >= self.candles[i].open() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn body_bounds(&self, i: usize) -> (f64, f64) {
+        let (o, _, _, c) = self.candles[i].ohlc();
+        (o.max(c), o.min(c))
+    }
+
+    fn real_body(&self, i: usize) -> f64 {
+        let (top, bottom) = self.body_bounds(i);
+        top - bottom
+    }
+
+    fn upper_shadow(&self, i: usize) -> f64 {
+        self.candles[i].high() - self.body_bounds(i).0
+    }
+
+    fn lower_shadow(&self, i: usize) -> f64 {
+        self.body_bounds(i).1 - self.candles[i].low()
+    }
+
+    fn range(&self, range: RangeType, i: usize) -> f64 {
+        match range {
+            RangeType::RealBody => self.real_body(i),
+            RangeType::HighLow => self.candles[i].high() - self.candles[i].low(),
+        }
+    }
+
+    /// Equivalent of TA-Lib's `TA_CANDLEAVERAGE` for the candle at index `i`
+    fn average(&self, setting: Setting, i: usize) -> f64 {
+        let total = match setting.period {
+            0 => self.range(setting.range, i),
+            period => {
+                (i - period..i)
+                    .map(|j| self.range(setting.range, j))
+                    .sum::<f64>()
+                    / period as f64
+            }
+        };
+
+        setting.factor * total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ten bars of range 2 and body 1, used to seed the TA-Lib averages
+    const WARMUP: (f64, f64, f64, f64, f64) = (100.0, 101.5, 99.5, 101.0, 0.0);
+
+    fn seeded<const N: usize>(
+        tail: [(f64, f64, f64, f64, f64); N],
+    ) -> [(f64, f64, f64, f64, f64); 16] {
+        let mut candles = [WARMUP; 16];
+        candles[16 - N..].copy_from_slice(&tail);
+        candles
+    }
+
+    fn last(signals: impl Iterator<Item = i32>) -> Option<i32> {
+        signals.last()
+    }
+
+    #[test]
+    fn test_doji_uses_average_range() {
+        // body of 0.15 is below 10% of the average range of 2, but above 10% of its own range
+        let candles = seeded([(100.0, 100.5, 99.5, 100.15, 0.0)]);
+        let talib = TaLib::new(&candles);
+
+        assert_eq!(last(talib.cdl_doji()), Some(100));
+        assert!(talib.cdl_doji().take(10).all(|s| s == 0));
+    }
+
+    #[test]
+    fn test_engulfing_weak_match() {
+        let candles = [
+            (101.0, 102.0, 99.5, 100.5, 0.0),
+            (100.5, 103.0, 98.5, 102.5, 0.0),
+        ];
+
+        assert!(TaLib::new(&candles).cdl_engulfing().eq([0, 80]));
+    }
+
+    #[test]
+    fn test_harami() {
+        let candles = seeded([
+            (104.0, 104.5, 99.5, 100.0, 0.0),
+            (101.0, 101.8, 100.9, 101.5, 0.0),
+        ]);
+
+        assert_eq!(last(TaLib::new(&candles).cdl_harami()), Some(100));
+    }
+
+    #[test]
+    fn test_hammer() {
+        let candles = seeded([
+            (100.0, 100.6, 99.4, 100.5, 0.0),
+            (99.6, 99.8, 98.0, 99.8, 0.0),
+        ]);
+
+        assert_eq!(last(TaLib::new(&candles).cdl_hammer()), Some(100));
+    }
+
+    #[test]
+    fn test_stars() {
+        let morning = seeded([
+            (104.0, 104.5, 99.5, 100.0, 0.0),
+            (99.0, 99.5, 98.5, 99.2, 0.0),
+            (99.5, 103.0, 99.4, 102.5, 0.0),
+        ]);
+        let evening = seeded([
+            (100.0, 104.5, 99.5, 104.0, 0.0),
+            (105.0, 105.5, 104.5, 104.8, 0.0),
+            (104.5, 104.6, 101.0, 101.5, 0.0),
+        ]);
+
+        assert_eq!(last(TaLib::new(&morning).cdl_morning_star()), Some(100));
+        assert_eq!(last(TaLib::new(&morning).cdl_evening_star()), Some(0));
+        assert_eq!(last(TaLib::new(&evening).cdl_evening_star()), Some(-100));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{CandleStick, CandleStream, Direction, Pattern};
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the measured move target of the given pattern, or `None` if the latest candles
+    /// do not form it.
+    ///
+    /// Engulfing patterns project the body of the engulfing candle from its close, the other
+    /// patterns project their height, from the highest high to the lowest low of their
+    /// candles. Targets lie above the latest close for bullish patterns and below it for
+    /// bearish ones. The same target is reported by [`crate::PatternMatch::target`].
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Pattern};
+    /// let prev = (101.0, 102.0, 99.5, 100.5, 0.0);
+    /// let curr = (99.0, 103.0, 98.5, 102.5, 0.0);
+    /// let mut series = CandleStream::new();
+    /// series.push(&prev).push(&curr);
+    /// assert_eq!(series.measured_target(Pattern::BullishEngulfing), Some(106.0));
+    /// assert_eq!(series.measured_target(Pattern::BearishEngulfing), None);
+    /// ```
+    pub fn measured_target(&self, pattern: Pattern) -> Option<f64> {
+        self.is_pattern(pattern)
+            .then(|| self.projection(pattern))
+            .flatten()
+    }
+
+    // Target of the pattern, assuming the latest candles form it
+    pub(crate) fn projection(&self, pattern: Pattern) -> Option<f64> {
+        let latest = self.get()?;
+        let distance = match pattern {
+            Pattern::BullishEngulfing | Pattern::BearishEngulfing => latest.body(),
+            _ => {
+                self.highest_high(pattern.candle_count())?
+                    - self.lowest_low(pattern.candle_count())?
+            }
+        };
+
+        Some(match pattern.direction() {
+            Direction::Bullish => latest.close() + distance,
+            Direction::Bearish => latest.close() - distance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PatternDetector;
+
+    #[test]
+    fn test_star_target() {
+        let prev2 = (52.0, 52.5, 48.0, 48.5, 0.0);
+        let prev1 = (48.3, 48.9, 47.5, 48.4, 0.0);
+        let curr = (48.7, 51.5, 48.5, 51.2, 0.0);
+        let mut series = CandleStream::new();
+        series.push(&prev2).push(&prev1).push(&curr);
+
+        assert_eq!(series.measured_target(Pattern::MorningStar), Some(56.2));
+        assert_eq!(series.measured_target(Pattern::EveningStar), None);
+        assert_eq!(
+            Pattern::MorningStar.detect(&series).and_then(|m| m.target),
+            Some(56.2)
+        );
+    }
+
+    #[test]
+    fn test_bearish_engulfing_target() {
+        let prev = (100.0, 102.0, 99.5, 101.5, 0.0);
+        let curr = (102.0, 102.5, 98.0, 99.0, 0.0);
+        let mut series = CandleStream::new();
+        series.push(&prev).push(&curr);
+
+        assert_eq!(
+            series.measured_target(Pattern::BearishEngulfing),
+            Some(96.0)
+        );
+    }
+}
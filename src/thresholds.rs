@@ -0,0 +1,390 @@
+use crate::CandleStick;
+
+/// A bundle of every ratio threshold and price tolerance used by the detectors of [`CandleStick`].
+///
+/// Thresholds are normally tuned by overriding the ratio methods of a `CandleStick`
+/// implementation. `Thresholds` makes them a value instead, that can be calibrated on
+/// historical data and applied to any candle through [`Thresholds::apply`].
+///
+/// The default values are the defaults of the `CandleStick` trait.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{CandleStick, Thresholds};
+/// let candle = (100.0, 105.0, 95.0, 101.5, 0.0);
+/// let loose = Thresholds { doji_body_ratio: 0.2, ..Thresholds::default() };
+/// assert!(!candle.is_doji());
+/// assert!(loose.apply(candle).is_doji());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Thresholds {
+    /// See [`CandleStick::hammer_body_ratio`]
+    pub hammer_body_ratio: f64,
+    /// See [`CandleStick::hammer_wick_ratio`]
+    pub hammer_wick_ratio: f64,
+    /// See [`CandleStick::hammer_tail_ratio`]
+    pub hammer_tail_ratio: f64,
+    /// See [`CandleStick::spinning_top_body_ratio`]
+    pub spinning_top_body_ratio: f64,
+    /// See [`CandleStick::spinning_top_shadow_ratio`]
+    pub spinning_top_shadow_ratio: f64,
+    /// See [`CandleStick::doji_body_ratio`]
+    pub doji_body_ratio: f64,
+    /// See [`CandleStick::doji_long_leg_ratio`]
+    pub doji_long_leg_ratio: f64,
+    /// See [`CandleStick::doji_tail_ratio`]
+    pub doji_tail_ratio: f64,
+    /// See [`CandleStick::doji_wick_ratio`]
+    pub doji_wick_ratio: f64,
+    /// See [`CandleStick::doji_min_ratio`]
+    pub doji_min_ratio: f64,
+    /// See [`CandleStick::marubozu_ratio`]
+    pub marubozu_ratio: f64,
+    /// See [`CandleStick::tick_size`]
+    pub tick_size: f64,
+    /// See [`CandleStick::min_gap_ticks`]
+    pub min_gap_ticks: u32,
+    /// See [`CandleStick::price_match_ratio`]
+    pub price_match_ratio: f64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            hammer_body_ratio: 0.3,
+            hammer_wick_ratio: 0.2,
+            hammer_tail_ratio: 0.6,
+            spinning_top_body_ratio: 0.2,
+            spinning_top_shadow_ratio: 0.3,
+            doji_body_ratio: 0.1,
+            doji_long_leg_ratio: 0.3,
+            doji_tail_ratio: 0.3,
+            doji_wick_ratio: 0.3,
+            doji_min_ratio: 0.05,
+            marubozu_ratio: 0.2,
+            tick_size: 0.0,
+            min_gap_ticks: 1,
+            price_match_ratio: 0.05,
+        }
+    }
+}
+
+/// Preset [`Thresholds`] for common asset classes, as sensible doji and body thresholds
+/// differ drastically across markets.
+///
+/// Tick sizes are instrument specific and left to zero, set them on the returned thresholds.
+///
+/// Presets are starting points tuned on typical market behavior, calibrating on the actual
+/// instrument with [`Thresholds::calibrate`] remains the most accurate option.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Preset, Thresholds};
+/// let preset = Preset::from_name("crypto-perp-1m").unwrap();
+/// assert_eq!(preset, Preset::CryptoPerp1m);
+/// assert!(preset.thresholds().doji_body_ratio < Thresholds::default().doji_body_ratio);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// Crypto perpetual futures on 1 minute bars: noisy, with many tiny bodies and long wicks
+    CryptoPerp1m,
+    /// Major FX pairs on intraday bars: smooth, with small shadows relative to bodies
+    FxMajors,
+    /// Large-cap equities on daily bars: the classic setting of the trait defaults
+    LargeCapEquitiesDaily,
+}
+
+impl Preset {
+    /// All presets
+    pub const ALL: [Preset; 3] = [
+        Preset::CryptoPerp1m,
+        Preset::FxMajors,
+        Preset::LargeCapEquitiesDaily,
+    ];
+
+    /// Returns the name of the preset
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Preset::CryptoPerp1m => "crypto-perp-1m",
+            Preset::FxMajors => "fx-majors",
+            Preset::LargeCapEquitiesDaily => "large-cap-equities-daily",
+        }
+    }
+
+    /// Returns the preset with the given name, if any
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| preset.name() == name)
+    }
+
+    /// Returns the thresholds of the preset
+    pub fn thresholds(&self) -> Thresholds {
+        let defaults = Thresholds::default();
+
+        match self {
+            Preset::CryptoPerp1m => Thresholds {
+                hammer_body_ratio: 0.25,
+                hammer_tail_ratio: 0.65,
+                spinning_top_body_ratio: 0.15,
+                spinning_top_shadow_ratio: 0.35,
+                doji_body_ratio: 0.05,
+                doji_long_leg_ratio: 0.35,
+                doji_min_ratio: 0.03,
+                marubozu_ratio: 0.1,
+                ..defaults
+            },
+            Preset::FxMajors => Thresholds {
+                spinning_top_body_ratio: 0.25,
+                doji_body_ratio: 0.08,
+                doji_long_leg_ratio: 0.25,
+                marubozu_ratio: 0.15,
+                ..defaults
+            },
+            Preset::LargeCapEquitiesDaily => defaults,
+        }
+    }
+}
+
+impl From<Preset> for Thresholds {
+    fn from(preset: Preset) -> Self {
+        preset.thresholds()
+    }
+}
+
+/// The thresholds that can be calibrated with [`Thresholds::calibrate`], each one driving the
+/// detection frequency of a single-candle pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Knob {
+    /// Calibrates [`Thresholds::doji_body_ratio`] against [`CandleStick::is_doji`]
+    DojiBody,
+    /// Calibrates [`Thresholds::marubozu_ratio`] against [`CandleStick::is_marubozu`]
+    Marubozu,
+    /// Calibrates [`Thresholds::hammer_body_ratio`] against [`CandleStick::is_hammer`]
+    HammerBody,
+    /// Calibrates [`Thresholds::spinning_top_body_ratio`] against [`CandleStick::is_spinning_top`]
+    SpinningTopBody,
+}
+
+impl Knob {
+    // Returns a mutable reference to the threshold driven by the knob
+    fn ratio(self, thresholds: &mut Thresholds) -> &mut f64 {
+        match self {
+            Knob::DojiBody => &mut thresholds.doji_body_ratio,
+            Knob::Marubozu => &mut thresholds.marubozu_ratio,
+            Knob::HammerBody => &mut thresholds.hammer_body_ratio,
+            Knob::SpinningTopBody => &mut thresholds.spinning_top_body_ratio,
+        }
+    }
+
+    // Returns true if the candle matches the pattern driven by the knob
+    fn matches<T: CandleStick>(self, candle: &Tuned<T>) -> bool {
+        match self {
+            Knob::DojiBody => candle.is_doji(),
+            Knob::Marubozu => candle.is_marubozu(),
+            Knob::HammerBody => candle.is_hammer(),
+            Knob::SpinningTopBody => candle.is_spinning_top(),
+        }
+    }
+}
+
+impl Thresholds {
+    /// Wraps a candle so that its pattern detection uses these thresholds
+    pub fn apply<T: CandleStick>(&self, candle: T) -> Tuned<T> {
+        Tuned {
+            candle,
+            thresholds: *self,
+        }
+    }
+
+    /// Returns the fraction of candles matching the pattern driven by the knob
+    pub fn frequency<T: CandleStick>(&self, candles: &[T], knob: Knob) -> f64 {
+        if candles.is_empty() {
+            return 0.0;
+        }
+
+        let matches = candles
+            .iter()
+            .filter(|c| knob.matches(&self.apply(*c)))
+            .count();
+
+        matches as f64 / candles.len() as f64
+    }
+
+    /// Solves for the threshold driven by the knob so that its pattern matches about the
+    /// `target` fraction of the candles, e.g. a doji on ~5% of the bars, leaving the other
+    /// thresholds untouched.
+    ///
+    /// The detection frequency grows with the threshold, so it is solved by bisection. The
+    /// result is as close to the target as the data allows, since candles with identical
+    /// ratios can only be included or excluded together.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{Knob, Thresholds};
+    /// let candles = [
+    ///     (100.0, 105.0, 95.0, 100.2, 0.0),
+    ///     (100.0, 105.0, 95.0, 100.8, 0.0),
+    ///     (100.0, 105.0, 95.0, 101.5, 0.0),
+    ///     (100.0, 105.0, 95.0, 104.0, 0.0),
+    /// ];
+    /// let thresholds = Thresholds::default().calibrate(&candles, Knob::DojiBody, 0.75);
+    /// assert_eq!(thresholds.frequency(&candles, Knob::DojiBody), 0.75);
+    /// ```
+    pub fn calibrate<T: CandleStick>(mut self, candles: &[T], knob: Knob, target: f64) -> Self {
+        let (mut lo, mut hi) = (0.0, 1.0);
+        *knob.ratio(&mut self) = hi;
+        while self.frequency(candles, knob) < target && hi < 1e6 {
+            lo = hi;
+            hi *= 2.0;
+            *knob.ratio(&mut self) = hi;
+        }
+
+        for _ in 0..64 {
+            let mid = (lo + hi) / 2.0;
+            *knob.ratio(&mut self) = mid;
+            match self.frequency(candles, knob) < target {
+                true => lo = mid,
+                false => hi = mid,
+            }
+        }
+
+        *knob.ratio(&mut self) = hi;
+        self
+    }
+}
+
+/// A candle whose pattern detection uses the given [`Thresholds`], as returned by
+/// [`Thresholds::apply`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tuned<T> {
+    /// The wrapped candle
+    pub candle: T,
+    /// The thresholds applied to the candle
+    pub thresholds: Thresholds,
+}
+
+impl<T: CandleStick> CandleStick for Tuned<T> {
+    fn hammer_body_ratio(&self) -> f64 {
+        self.thresholds.hammer_body_ratio
+    }
+
+    fn hammer_wick_ratio(&self) -> f64 {
+        self.thresholds.hammer_wick_ratio
+    }
+
+    fn hammer_tail_ratio(&self) -> f64 {
+        self.thresholds.hammer_tail_ratio
+    }
+
+    fn spinning_top_body_ratio(&self) -> f64 {
+        self.thresholds.spinning_top_body_ratio
+    }
+
+    fn spinning_top_shadow_ratio(&self) -> f64 {
+        self.thresholds.spinning_top_shadow_ratio
+    }
+
+    fn doji_body_ratio(&self) -> f64 {
+        self.thresholds.doji_body_ratio
+    }
+
+    fn doji_long_leg_ratio(&self) -> f64 {
+        self.thresholds.doji_long_leg_ratio
+    }
+
+    fn doji_tail_ratio(&self) -> f64 {
+        self.thresholds.doji_tail_ratio
+    }
+
+    fn doji_wick_ratio(&self) -> f64 {
+        self.thresholds.doji_wick_ratio
+    }
+
+    fn doji_min_ratio(&self) -> f64 {
+        self.thresholds.doji_min_ratio
+    }
+
+    fn marubozu_ratio(&self) -> f64 {
+        self.thresholds.marubozu_ratio
+    }
+
+    fn tick_size(&self) -> f64 {
+        self.thresholds.tick_size
+    }
+
+    fn min_gap_ticks(&self) -> u32 {
+        self.thresholds.min_gap_ticks
+    }
+
+    fn price_match_ratio(&self) -> f64 {
+        self.thresholds.price_match_ratio
+    }
+
+    forward_candlestick!(log_scale, prices => |c| c.candle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANDLES: [(f64, f64, f64, f64, f64); 5] = [
+        (100.0, 105.0, 95.0, 100.2, 0.0),
+        (100.0, 105.0, 95.0, 100.8, 0.0),
+        (100.0, 105.0, 95.0, 101.5, 0.0),
+        (100.0, 105.0, 95.0, 104.0, 0.0),
+        (100.0, 110.0, 99.5, 109.5, 0.0),
+    ];
+
+    #[test]
+    fn test_default_matches_trait_defaults() {
+        let candle = (100.0, 105.0, 95.0, 101.0, 0.0);
+        let tuned = Thresholds::default().apply(candle);
+
+        assert_eq!(tuned.hammer_body_ratio(), candle.hammer_body_ratio());
+        assert_eq!(tuned.doji_min_ratio(), candle.doji_min_ratio());
+        assert_eq!(tuned.marubozu_ratio(), candle.marubozu_ratio());
+        assert_eq!(tuned.price_match_ratio(), candle.price_match_ratio());
+        assert_eq!(tuned.classify(), candle.classify());
+    }
+
+    #[test]
+    fn test_frequency() {
+        let thresholds = Thresholds::default();
+
+        assert_eq!(thresholds.frequency(&CANDLES, Knob::DojiBody), 0.4);
+        assert_eq!(
+            thresholds.frequency(&[] as &[(f64, f64, f64, f64, f64)], Knob::DojiBody),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let thresholds = Thresholds::default();
+
+        let strict = thresholds.calibrate(&CANDLES, Knob::DojiBody, 0.2);
+        assert_eq!(strict.frequency(&CANDLES, Knob::DojiBody), 0.2);
+        assert!(strict.doji_body_ratio < thresholds.doji_body_ratio);
+        assert_eq!(strict.hammer_body_ratio, thresholds.hammer_body_ratio);
+
+        let loose = thresholds.calibrate(&CANDLES, Knob::DojiBody, 0.6);
+        assert_eq!(loose.frequency(&CANDLES, Knob::DojiBody), 0.6);
+
+        let marubozu = thresholds.calibrate(&CANDLES, Knob::Marubozu, 0.4);
+        assert_eq!(marubozu.frequency(&CANDLES, Knob::Marubozu), 0.4);
+    }
+
+    #[test]
+    fn test_presets() {
+        for preset in Preset::ALL {
+            assert_eq!(Preset::from_name(preset.name()), Some(preset));
+        }
+
+        assert_eq!(Preset::from_name("unknown"), None);
+        assert_eq!(
+            Thresholds::from(Preset::LargeCapEquitiesDaily),
+            Thresholds::default()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use core::cmp::Ordering;
+
+use crate::{CandleStick, CandleStream};
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Returns the average directional index of the buffered history, a trend strength from
+    /// `0.0` to `100.0` regardless of the trend direction.
+    ///
+    /// Follows Wilder's method: the directional movements are smoothed over `period` candles
+    /// into the directional indicators, whose spread is smoothed again over `period` values. Returns `None` if `period` is zero or fewer than `2 * period` candles
+    /// are buffered. The whole buffered history is used, so a larger capacity gives a value
+    /// closer to a charting package's.
+    ///
+    /// **Trading Significance**:
+    /// - Above 25 the market trends, favor continuation patterns
+    /// - Below 20 the market lacks direction, reversals at the range edges work best
+    /// - Reversal patterns against a strong, rising reading often fail
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let candles = [
+    ///     (100.0, 101.0, 99.5, 100.8, 0.0),
+    ///     (100.8, 102.0, 100.5, 101.8, 0.0),
+    ///     (101.8, 103.0, 101.5, 102.8, 0.0),
+    ///     (102.8, 104.0, 102.5, 103.8, 0.0),
+    ///     (103.8, 105.0, 103.5, 104.8, 0.0),
+    ///     (104.8, 106.0, 104.5, 105.8, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 10>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// assert_eq!(series.trend_strength(3), Some(100.0));
+    /// assert_eq!(series.trend_strength(4), None);
+    /// ```
+    pub fn trend_strength(&self, period: usize) -> Option<f64> {
+        if period == 0 || self.len() < 2 * period {
+            return None;
+        }
+        let weight = period as f64;
+
+        // plus and minus directional movements, from the oldest candle; the true range divides
+        // both directional indicators and cancels out of their spread, so it is left out
+        let moves = (0..self.len() - 1).rev().filter_map(|n| {
+            let (candle, prev) = (self.prev(n)?, self.prev(n + 1)?);
+            let (up, down) = (candle.high() - prev.high(), prev.low() - candle.low());
+            let plus = if up > down && up > 0.0 { up } else { 0.0 };
+            let minus = if down > up && down > 0.0 { down } else { 0.0 };
+            Some((plus, minus))
+        });
+
+        let (mut plus_dm, mut minus_dm, mut adx) = (0.0, 0.0, 0.0);
+        for (i, (plus, minus)) in moves.enumerate() {
+            let decay = match i < period {
+                true => 1.0,
+                false => 1.0 - 1.0 / weight,
+            };
+            plus_dm = plus_dm * decay + plus;
+            minus_dm = minus_dm * decay + minus;
+            if i + 1 < period {
+                continue;
+            }
+
+            let dx = match plus_dm + minus_dm {
+                0.0 => 0.0,
+                total => 100.0 * (plus_dm - minus_dm).abs() / total,
+            };
+            // the first value is the mean of the first `period` spreads
+            adx = match (i + 2).cmp(&(2 * period)) {
+                Ordering::Less => adx + dx,
+                Ordering::Equal => (adx + dx) / weight,
+                Ordering::Greater => (adx * (weight - 1.0) + dx) / weight,
+            };
+        }
+
+        Some(adx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 30> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_strong_trend() {
+        let down: [Ohlcv; 20] = core::array::from_fn(|i| {
+            let base = 100.0 - i as f64;
+            (base, base + 0.2, base - 1.2, base - 1.0, 0.0)
+        });
+
+        let strength = series(&down).trend_strength(5);
+        assert!(strength.is_some_and(|s| (s - 100.0).abs() < 1e-9));
+        assert_eq!(series(&down[..9]).trend_strength(5), None);
+        assert_eq!(series(&down).trend_strength(0), None);
+    }
+
+    #[test]
+    fn test_range_is_weak() {
+        let range: [Ohlcv; 20] = core::array::from_fn(|i| match i % 2 {
+            0 => (100.5, 102.0, 100.0, 101.5, 0.0),
+            _ => (101.5, 102.2, 100.2, 100.6, 0.0),
+        });
+
+        let strength = series(&range).trend_strength(5);
+        assert!(strength.is_some_and(|s| s < 20.0));
+    }
+}
@@ -0,0 +1,97 @@
+/// A straight line fitted to prices over the buffered history.
+///
+/// Positions are counted in candles back from the latest one, like [`crate::Swing::bars_ago`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trendline {
+    /// Price change per candle, forward in time
+    pub slope: f64,
+    /// Value of the line at the latest candle
+    pub level: f64,
+}
+
+impl Trendline {
+    /// Returns the value of the line at the given position, `0` being the latest candle
+    pub fn value_at(&self, bars_ago: usize) -> f64 {
+        self.level - self.slope * bars_ago as f64
+    }
+
+    /// Returns the number of candles ahead of the latest one where the two lines cross, or
+    /// `None` if they are parallel or already crossed
+    pub fn intersection(&self, other: &Trendline) -> Option<f64> {
+        let ahead = (other.level - self.level) / (self.slope - other.slope);
+        (ahead.is_finite() && ahead > 0.0).then_some(ahead)
+    }
+
+    /// Fits a line to `(bars_ago, price)` points by least squares, returning `None` without
+    /// at least two distinct positions
+    pub(crate) fn fit(points: impl Iterator<Item = (usize, f64)>) -> Option<Self> {
+        let (n, sx, sy, sxx, sxy) = points.fold(
+            (0.0, 0.0, 0.0, 0.0, 0.0),
+            |(n, sx, sy, sxx, sxy), (bars_ago, price)| {
+                let x = bars_ago as f64;
+                (n + 1.0, sx + x, sy + price, sxx + x * x, sxy + x * price)
+            },
+        );
+
+        let denominator = n * sxx - sx * sx;
+        if denominator == 0.0 {
+            return None;
+        }
+
+        // the fitted slope is measured backward in time, from the latest candle
+        let backward = (n * sxy - sx * sy) / denominator;
+        Some(Self {
+            slope: -backward,
+            level: (sy - backward * sx) / n,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() {
+        let line = Trendline::fit([(3, 97.0), (2, 98.0), (1, 99.0)].into_iter());
+
+        assert_eq!(
+            line,
+            Some(Trendline {
+                slope: 1.0,
+                level: 100.0
+            })
+        );
+        assert_eq!(line.map(|l| l.value_at(4)), Some(96.0));
+        assert_eq!(Trendline::fit([(1, 99.0)].into_iter()), None);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let upper = Trendline {
+            slope: -0.5,
+            level: 106.0,
+        };
+        let lower = Trendline {
+            slope: 0.5,
+            level: 105.0,
+        };
+
+        assert_eq!(upper.intersection(&lower), Some(1.0));
+        assert_eq!(lower.intersection(&upper), Some(1.0));
+        assert_eq!(
+            upper.intersection(&Trendline {
+                slope: -0.5,
+                level: 100.0
+            }),
+            None
+        );
+        assert_eq!(
+            upper.intersection(&Trendline {
+                slope: -1.0,
+                level: 105.0
+            }),
+            None
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{CandleStick, CandleStream, Direction, Trendline};
+
+/// The shape of a [`Triangle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriangleKind {
+    /// Flat resistance with rising support
+    Ascending,
+    /// Falling resistance with flat support
+    Descending,
+    /// Falling resistance with rising support
+    Symmetrical,
+}
+
+/// A triangle chart pattern, as returned by [`CandleStream::triangle`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    /// The shape of the triangle
+    pub kind: TriangleKind,
+    /// The resistance line fitted to the swing highs
+    pub upper: Trendline,
+    /// The support line fitted to the swing lows
+    pub lower: Trendline,
+    /// Number of candles ahead of the latest one where the lines meet
+    pub apex: f64,
+    /// Price where the lines meet
+    pub apex_price: f64,
+    /// Direction of the breakout if the latest candle closed outside of the lines
+    pub breakout: Option<Direction>,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Triangle, a consolidation chart pattern with converging resistance and
+    /// support lines, fitted to the latest `touches` swing highs and swing lows of the given
+    /// `strength`.
+    ///
+    /// A line is considered flat when its slope is within `flat_tolerance` of its level per
+    /// candle (e.g. `0.001` for 0.1%). Lines sloping the same way are wedges, not triangles.
+    ///
+    /// **Trading Significance**:
+    /// - Ascending triangles lean bullish, descending ones bearish
+    /// - Symmetrical triangles usually resolve in the direction of the prior trend
+    /// - Breakouts lose reliability as price gets close to the apex
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, TriangleKind};
+    /// let candles = [
+    ///     (105.0, 108.0, 104.0, 107.0, 0.0),
+    ///     (107.0, 112.0, 106.0, 110.0, 0.0),
+    ///     (109.0, 109.5, 103.0, 104.0, 0.0),
+    ///     (104.0, 106.0, 100.0, 101.0, 0.0),
+    ///     (101.0, 108.0, 100.5, 107.0, 0.0),
+    ///     (107.0, 110.0, 104.0, 109.0, 0.0),
+    ///     (109.0, 109.5, 103.0, 104.0, 0.0),
+    ///     (104.0, 105.0, 102.0, 103.0, 0.0),
+    ///     (103.0, 106.5, 102.8, 106.0, 0.0),
+    ///     (106.0, 108.0, 104.5, 107.0, 0.0),
+    ///     (107.0, 107.2, 104.5, 105.0, 0.0),
+    ///     (105.0, 105.5, 104.0, 104.5, 0.0),
+    ///     (104.7, 105.9, 104.6, 105.5, 0.0),
+    ///     (105.5, 106.2, 104.8, 105.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let triangle = series.triangle(1, 2, 0.001).unwrap();
+    /// assert_eq!(triangle.kind, TriangleKind::Symmetrical);
+    /// assert_eq!((triangle.upper.slope, triangle.lower.slope), (-0.5, 0.5));
+    /// assert_eq!(triangle.breakout, None);
+    /// ```
+    pub fn triangle(
+        &self,
+        strength: usize,
+        touches: usize,
+        flat_tolerance: f64,
+    ) -> Option<Triangle> {
+        let (upper, lower, apex) = self.converging_lines(strength, touches)?;
+
+        let flat = |line: &Trendline| line.slope.abs() <= flat_tolerance * line.level.abs();
+        let kind = match (upper.slope, lower.slope) {
+            _ if flat(&upper) && !flat(&lower) => TriangleKind::Ascending,
+            _ if flat(&lower) && !flat(&upper) => TriangleKind::Descending,
+            (upper, lower) if upper < 0.0 && lower > 0.0 => TriangleKind::Symmetrical,
+            _ => return None,
+        };
+
+        Some(Triangle {
+            kind,
+            upper,
+            lower,
+            apex,
+            apex_price: upper.level + upper.slope * apex,
+            breakout: self.line_breakout(&upper, &lower),
+        })
+    }
+
+    /// Fits resistance and support lines to the latest `touches` swing highs and lows,
+    /// returning them with their apex if they converge ahead of the latest candle
+    pub(crate) fn converging_lines(
+        &self,
+        strength: usize,
+        touches: usize,
+    ) -> Option<(Trendline, Trendline, f64)> {
+        if touches < 2 {
+            return None;
+        }
+
+        let highs = || {
+            self.swing_highs(strength)
+                .take(touches)
+                .map(|s| (s.bars_ago, s.price))
+        };
+        let lows = || {
+            self.swing_lows(strength)
+                .take(touches)
+                .map(|s| (s.bars_ago, s.price))
+        };
+        if highs().count() < touches || lows().count() < touches {
+            return None;
+        }
+
+        let upper = Trendline::fit(highs())?;
+        let lower = Trendline::fit(lows())?;
+        if upper.level <= lower.level {
+            return None;
+        }
+
+        let apex = upper.intersection(&lower)?;
+        Some((upper, lower, apex))
+    }
+
+    /// Returns the direction of the latest close outside of the lines, if any
+    pub(crate) fn line_breakout(&self, upper: &Trendline, lower: &Trendline) -> Option<Direction> {
+        let close = self.get()?.close();
+
+        match (close > upper.level, close < lower.level) {
+            (true, _) => Some(Direction::Bullish),
+            (_, true) => Some(Direction::Bearish),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const SYMMETRICAL: [(f64, f64); 13] = [
+        (108.0, 104.0),
+        (112.0, 106.0),
+        (109.0, 103.0),
+        (106.0, 100.0),
+        (108.0, 102.0),
+        (110.0, 104.0),
+        (107.0, 103.0),
+        (105.0, 102.0),
+        (106.5, 103.5),
+        (108.0, 104.5),
+        (106.5, 104.5),
+        (105.5, 104.0),
+        (106.0, 104.6),
+    ];
+
+    fn build(shape: [(f64, f64); 13], latest: Ohlcv) -> [Ohlcv; 14] {
+        let mut candles = [latest; 14];
+        for (slot, bar) in candles.iter_mut().zip(shape) {
+            *slot = candle(bar);
+        }
+        candles
+    }
+
+    fn triangle(candles: &[Ohlcv]) -> Option<Triangle> {
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.triangle(1, 2, 0.001)
+    }
+
+    #[test]
+    fn test_symmetrical_triangle() {
+        let inside = build(SYMMETRICAL, (105.2, 106.2, 104.8, 105.5, 0.0));
+        let breakout = build(SYMMETRICAL, (105.2, 106.2, 104.8, 106.1, 0.0));
+
+        let pattern = triangle(&inside);
+        assert!(pattern.is_some_and(|t| t.kind == TriangleKind::Symmetrical));
+        assert!(pattern.is_some_and(|t| (t.apex, t.apex_price) == (1.0, 105.5)));
+        assert!(pattern.is_some_and(|t| t.breakout.is_none()));
+
+        let pattern = triangle(&breakout);
+        assert!(pattern.is_some_and(|t| t.breakout == Some(Direction::Bullish)));
+    }
+
+    #[test]
+    fn test_ascending_triangle() {
+        let mut shape = SYMMETRICAL;
+        shape[9] = (110.0, 104.5);
+        let candles = build(shape, (105.2, 106.2, 104.8, 105.5, 0.0));
+
+        let pattern = triangle(&candles);
+        assert!(pattern.is_some_and(|t| t.kind == TriangleKind::Ascending));
+        assert!(pattern.is_some_and(|t| t.upper.level == 110.0));
+    }
+
+    #[test]
+    fn test_diverging_lines() {
+        let mut shape = SYMMETRICAL;
+        shape[9] = (113.0, 104.5);
+        let candles = build(shape, (105.2, 106.2, 104.8, 105.5, 0.0));
+
+        assert_eq!(triangle(&candles), None);
+        assert_eq!(triangle(&candles[..4]), None);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{CandleStick, CandleStream, Direction, Swing, SwingKind};
+
+/// A sharp V-shaped reversal, as returned by [`CandleStream::v_reversals`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VReversal {
+    /// Bullish for a V-bottom, bearish for a V-top
+    pub direction: Direction,
+    /// The pivot candle, the lowest low of a V-bottom or the highest high of a V-top
+    pub pivot: Swing,
+    /// Distance covered by the impulse into the pivot
+    pub impulse: f64,
+    /// Distance covered by the opposite move out of the pivot
+    pub recovery: f64,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Iterates over the V-bottoms and V-tops of the buffered history, from the latest to the
+    /// oldest.
+    ///
+    /// The pivot must be the most extreme price of the `bars` candles on each side of it, and
+    /// both the impulse into the pivot over the `bars` candles before it and the opposite move
+    /// over the up to `bars` candles after it must cover at least `min_move`, a fraction of the
+    /// pivot price (e.g. `0.05` for 5%). V-bottoms are checked before V-tops.
+    ///
+    /// **Trading Significance**:
+    /// - Capitulation or a blow-off met by an equally violent move the other way
+    /// - No basing or topping phase, so the reversal leaves little time to react
+    /// - The pivot is a clear invalidation level for positions taken on the reversal
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction};
+    /// let candles = [
+    ///     (110.5, 111.0, 109.0, 109.5, 0.0),
+    ///     (108.0, 109.0, 104.0, 105.0, 0.0),
+    ///     (104.0, 105.0, 100.0, 101.0, 0.0),
+    ///     (100.0, 101.0, 95.0, 96.0, 0.0),
+    ///     (97.5, 100.0, 97.0, 99.5, 0.0),
+    ///     (99.5, 104.0, 99.0, 103.5, 0.0),
+    ///     (103.5, 108.0, 103.0, 107.5, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 10>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let v = series.v_reversals(3, 0.05).next().unwrap();
+    /// assert_eq!((v.direction, v.pivot.bars_ago, v.pivot.price), (Direction::Bullish, 3, 95.0));
+    /// assert_eq!((v.impulse, v.recovery), (16.0, 13.0));
+    /// ```
+    pub fn v_reversals(&self, bars: usize, min_move: f64) -> impl Iterator<Item = VReversal> + '_ {
+        (1..self.len()).filter_map(move |bars_ago| {
+            self.v_at(bars_ago, bars, min_move, SwingKind::Low)
+                .or_else(|| self.v_at(bars_ago, bars, min_move, SwingKind::High))
+        })
+    }
+
+    // Returns the V-shaped reversal pivoting on the candle at the given position, if any
+    fn v_at(
+        &self,
+        bars_ago: usize,
+        bars: usize,
+        min_move: f64,
+        kind: SwingKind,
+    ) -> Option<VReversal> {
+        // prices are negated on the high side so that the pivot is always the lowest
+        let side = match kind {
+            SwingKind::Low => 1.0,
+            SwingKind::High => -1.0,
+        };
+        let extreme = |c: &T| match kind {
+            SwingKind::Low => c.low(),
+            SwingKind::High => -c.high(),
+        };
+        let opposite = |c: &T| match kind {
+            SwingKind::Low => c.high(),
+            SwingKind::High => -c.low(),
+        };
+
+        let pivot = extreme(self.prev(bars_ago)?);
+        let before = || (bars_ago + 1..=bars_ago + bars).filter_map(|n| self.prev(n));
+        let after = || (bars_ago.saturating_sub(bars)..bars_ago).filter_map(|n| self.prev(n));
+        if !before().chain(after()).all(|c| extreme(c) > pivot) {
+            return None;
+        }
+
+        let impulse = before().map(opposite).reduce(f64::max)? - pivot;
+        let recovery = after().map(opposite).reduce(f64::max)? - pivot;
+        let threshold = min_move * pivot.abs();
+        if impulse < threshold || recovery < threshold {
+            return None;
+        }
+
+        Some(VReversal {
+            direction: match kind {
+                SwingKind::Low => Direction::Bullish,
+                SwingKind::High => Direction::Bearish,
+            },
+            pivot: Swing {
+                kind,
+                bars_ago,
+                price: side * pivot,
+            },
+            impulse,
+            recovery,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const V_BOTTOM: [(f64, f64); 7] = [
+        (111.0, 109.0),
+        (109.0, 104.0),
+        (105.0, 100.0),
+        (101.0, 95.0),
+        (100.0, 97.0),
+        (104.0, 99.0),
+        (108.0, 103.0),
+    ];
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_v_top() {
+        let candles = V_BOTTOM.map(|(high, low)| candle((200.0 - low, 200.0 - high)));
+        let stream = series(&candles);
+
+        let v = stream.v_reversals(3, 0.05).next();
+        assert!(v.is_some_and(|v| v.direction == Direction::Bearish));
+        assert!(v.is_some_and(|v| v.pivot.kind == SwingKind::High && v.pivot.price == 105.0));
+        assert!(v.is_some_and(|v| (v.impulse, v.recovery) == (16.0, 13.0)));
+        assert_eq!(stream.v_reversals(3, 0.05).count(), 1);
+    }
+
+    #[test]
+    fn test_developing_recovery() {
+        let candles = V_BOTTOM.map(candle);
+
+        let v = series(&candles[..5]).v_reversals(3, 0.02).next();
+        assert!(v.is_some_and(|v| v.pivot.bars_ago == 1 && v.recovery == 5.0));
+        assert_eq!(series(&candles[..5]).v_reversals(3, 0.1).next(), None);
+    }
+
+    #[test]
+    fn test_gradual_recovery_is_not_a_v() {
+        let mut shape = V_BOTTOM;
+        shape[4] = (98.0, 96.0);
+        shape[5] = (99.0, 97.0);
+        shape[6] = (99.5, 97.5);
+        let candles = shape.map(candle);
+
+        assert_eq!(series(&candles).v_reversals(3, 0.05).next(), None);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{CandleStick, CandleStream};
+
+/// Number of previous candles the volume spread analysis bars are judged against
+pub(crate) const VSA_CONTEXT: usize = 2;
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a No Demand bar, a narrow up bar on volume lower than the two previous
+    /// candles, closing in the lower half of its range.
+    ///
+    /// The spread is narrow when the range is below the average range of the two previous
+    /// candles. This and the other volume spread analysis bars read the latest candle in the
+    /// context of the two previous ones.
+    ///
+    /// **Trading Significance**:
+    /// - Professionals are not interested in higher prices
+    /// - In an uptrend, warns that the advance is running out of buyers
+    /// - Confirmed when the next candle closes lower
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 102.0, 99.5, 101.5, 200.0);
+    /// let prev1 = (101.5, 103.0, 101.0, 102.5, 180.0);
+    /// let curr = (102.5, 103.4, 102.4, 102.8, 90.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_no_demand());
+    /// ```
+    pub fn is_no_demand(&self) -> bool {
+        self.vsa_bar(|c, p1, p2, range| {
+            c.close() > p1.close()
+                && c.range() < range
+                && close_position(c) <= 0.5
+                && c.volume() < p1.volume().min(p2.volume())
+        })
+    }
+
+    /// Identifies a No Supply bar, a narrow down bar on volume lower than the two previous
+    /// candles, closing in the upper half of its range.
+    ///
+    /// Mirror of [`CandleStream::is_no_demand`].
+    ///
+    /// **Trading Significance**:
+    /// - Professionals are not interested in lower prices
+    /// - In a downtrend or a pullback, warns that selling is drying up
+    /// - Confirmed when the next candle closes higher
+    pub fn is_no_supply(&self) -> bool {
+        self.vsa_bar(|c, p1, p2, range| {
+            c.close() < p1.close()
+                && c.range() < range
+                && close_position(c) >= 0.5
+                && c.volume() < p1.volume().min(p2.volume())
+        })
+    }
+
+    /// Identifies an Upthrust, a wide bar on above-average volume pushing above the two
+    /// previous highs and closing in the lower third of its range.
+    ///
+    /// **Trading Significance**:
+    /// - Buy stops above the highs are triggered while professionals sell
+    /// - A bull trap, especially after an advance or at the top of a range
+    /// - The candle-level counterpart of a liquidity sweep of the highs
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (100.0, 102.0, 99.5, 101.5, 100.0);
+    /// let prev1 = (101.5, 102.5, 100.5, 101.0, 100.0);
+    /// let curr = (101.0, 104.0, 100.2, 100.8, 250.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_upthrust());
+    /// ```
+    pub fn is_upthrust(&self) -> bool {
+        self.vsa_bar(|c, p1, p2, range| {
+            c.high() > p1.high().max(p2.high())
+                && c.range() > range
+                && close_position(c) <= 1.0 / 3.0
+                && 2.0 * c.volume() > p1.volume() + p2.volume()
+        })
+    }
+
+    /// Identifies a Spring, a bar dipping below the two previous lows and closing back above
+    /// them, in the upper third of its range.
+    ///
+    /// **Trading Significance**:
+    /// - Sell stops below the lows are triggered while professionals buy
+    /// - A bear trap, especially at the bottom of a range
+    /// - Most convincing on low volume, showing no real supply below the lows
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::CandleStream;
+    /// let prev2 = (101.0, 101.5, 99.0, 99.5, 100.0);
+    /// let prev1 = (99.5, 100.5, 99.2, 100.0, 100.0);
+    /// let curr = (100.0, 100.6, 98.0, 100.4, 80.0);
+    /// let mut series = CandleStream::new();
+    /// assert!(series.push(&prev2).push(&prev1).push(&curr).is_spring());
+    /// ```
+    pub fn is_spring(&self) -> bool {
+        self.vsa_bar(|c, p1, p2, _| {
+            let support = p1.low().min(p2.low());
+            c.low() < support && c.close() > support && close_position(c) >= 2.0 / 3.0
+        })
+    }
+
+    // Evaluates the latest candle against the two previous ones and their average range
+    fn vsa_bar(&self, bar: impl Fn(&T, &T, &T, f64) -> bool) -> bool {
+        self.get()
+            .zip(self.prev(1))
+            .zip(self.prev(2))
+            .zip(self.average_volume_range(1, VSA_CONTEXT))
+            .is_some_and(|(((c, p1), p2), (_, range))| c.range() > 0.0 && bar(c, p1, p2, range))
+    }
+}
+
+// Position of the close within the range, from `0.0` at the low to `1.0` at the high
+pub(crate) fn close_position<T: CandleStick>(candle: &T) -> f64 {
+    (candle.close() - candle.low()) / candle.range()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn series(candles: &[Ohlcv]) -> CandleStream<'_, Ohlcv, 5> {
+        let mut series = CandleStream::new();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_no_supply() {
+        let mut candles = [
+            (102.0, 102.5, 100.0, 100.5, 200.0),
+            (100.5, 101.0, 99.0, 99.5, 180.0),
+            (99.5, 99.6, 98.8, 99.3, 90.0),
+        ];
+        assert!(series(&candles).is_no_supply());
+        assert!(!series(&candles).is_no_demand());
+
+        candles[2].4 = 190.0;
+        assert!(!series(&candles).is_no_supply());
+    }
+
+    #[test]
+    fn test_upthrust_and_spring_are_mirrors() {
+        let upthrust = [
+            (100.0, 102.0, 99.5, 101.5, 100.0),
+            (101.5, 102.5, 100.5, 101.0, 100.0),
+            (101.0, 104.0, 100.2, 100.8, 250.0),
+        ];
+        let spring =
+            upthrust.map(|(o, h, l, c, v)| (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v));
+
+        assert!(series(&upthrust).is_upthrust());
+        assert!(!series(&upthrust).is_spring());
+        assert!(series(&spring).is_spring());
+        assert!(!series(&spring).is_upthrust());
+        assert!(!series(&upthrust[1..]).is_upthrust());
+        assert!(series(&spring).detect_all().contains(Pattern::Spring));
+    }
+}
@@ -0,0 +1,233 @@
+use core::ops::Range;
+
+use crate::{
+    candle_stream::SERIES_SIZE, CandleStick, CandleStream, Direction, Pattern, Thresholds,
+};
+
+/// A walk-forward optimization of the [`Thresholds`] of a pattern.
+///
+/// The series is split into rolling windows: each candidate is scored on the `in_sample`
+/// candles of a window, and the best one is evaluated on the `out_of_sample` candles that
+/// follow, before the window rolls forward by `out_of_sample` candles. Out-of-sample scores
+/// are what the chosen thresholds would actually have earned, free of the optimism of
+/// in-sample fitting.
+///
+/// A candidate is scored by the average return over `horizon` bars of the trades entered at
+/// the close of each occurrence of the pattern, in its anticipated direction. In-sample
+/// trades must exit within the in-sample candles.
+///
+/// # Example
+/// ```
+/// use candlestick_rs::{Pattern, Thresholds, WalkForward};
+/// let candles: Vec<_> = (0..120)
+///     .map(|i| match i % 4 {
+///         0 => (101.0, 102.0, 99.5, 100.5, 0.0),
+///         1 => (99.0, 103.0, 98.5, 102.5, 0.0),
+///         2 => (102.5, 106.0, 101.5, 105.5, 0.0),
+///         _ => (105.5, 106.0, 100.5, 101.0, 0.0),
+///     })
+///     .collect();
+/// let candidates = [Thresholds::default(), Thresholds { doji_body_ratio: 0.2, ..Default::default() }];
+/// let walk = WalkForward { in_sample: 40, out_of_sample: 20, horizon: 1 };
+///
+/// let windows: Vec<_> = walk.run(&candles, Pattern::BullishEngulfing, &candidates).collect();
+/// assert_eq!(windows.len(), 4);
+/// assert_eq!(windows[0].out_of_sample_range, 40..60);
+/// assert!(windows.iter().all(|w| w.out_of_sample.trades == 5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkForward {
+    /// Number of candles the candidates are scored on
+    pub in_sample: usize,
+    /// Number of candles the chosen candidate is evaluated on, and the step of the windows
+    pub out_of_sample: usize,
+    /// Number of bars each trade is held
+    pub horizon: usize,
+}
+
+/// The trades of a candidate over a range of candles
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TradeScore {
+    /// Number of trades
+    pub trades: usize,
+    /// Average return of the trades, `0.0` without trades
+    pub mean_return: f64,
+}
+
+/// A window of a [`WalkForward`] optimization
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalkForwardWindow {
+    /// Candles the candidates were scored on
+    pub in_sample_range: Range<usize>,
+    /// Candles the chosen candidate was evaluated on
+    pub out_of_sample_range: Range<usize>,
+    /// Index of the chosen candidate, the first one when no candidate traded in sample
+    pub chosen: usize,
+    /// Thresholds of the chosen candidate
+    pub thresholds: Thresholds,
+    /// TradeScore of the chosen candidate in sample
+    pub in_sample: TradeScore,
+    /// TradeScore of the chosen candidate out of sample
+    pub out_of_sample: TradeScore,
+}
+
+impl WalkForward {
+    /// Runs the optimization, yielding a window for every `out_of_sample` candles following
+    /// the first `in_sample` ones.
+    ///
+    /// The first candidate should be the baseline thresholds, as it is kept whenever no
+    /// candidate trades in sample.
+    pub fn run<'a, T: CandleStick>(
+        &'a self,
+        candles: &'a [T],
+        pattern: Pattern,
+        candidates: &'a [Thresholds],
+    ) -> impl Iterator<Item = WalkForwardWindow> + 'a {
+        let step = self.out_of_sample.max(1);
+
+        (0..)
+            .map(move |n| n * step)
+            .map_while(move |start| {
+                let split = start + self.in_sample;
+                let end = (split + step).min(candles.len());
+                (split < candles.len() && !candidates.is_empty()).then_some((start, split, end))
+            })
+            .map(move |(start, split, end)| {
+                let (chosen, thresholds, in_sample) = candidates
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, thresholds)| {
+                        let score = self.score(candles, pattern, thresholds, start..split, split);
+                        (idx, *thresholds, score)
+                    })
+                    .fold(
+                        None,
+                        |best: Option<(usize, Thresholds, TradeScore)>, candidate| match best {
+                            Some(best) if !beats(&candidate.2, &best.2) => Some(best),
+                            _ => Some(candidate),
+                        },
+                    )
+                    .unwrap_or((0, Thresholds::default(), TradeScore::default()));
+
+                WalkForwardWindow {
+                    in_sample_range: start..split,
+                    out_of_sample_range: split..end,
+                    chosen,
+                    thresholds,
+                    in_sample,
+                    out_of_sample: self.score(
+                        candles,
+                        pattern,
+                        &thresholds,
+                        split..end,
+                        candles.len(),
+                    ),
+                }
+            })
+    }
+
+    // Scores the trades entered on the given range and exited before the limit
+    fn score<T: CandleStick>(
+        &self,
+        candles: &[T],
+        pattern: Pattern,
+        thresholds: &Thresholds,
+        range: Range<usize>,
+        limit: usize,
+    ) -> TradeScore {
+        let sign = match pattern.direction() {
+            Direction::Bullish => 1.0,
+            Direction::Bearish => -1.0,
+        };
+
+        let (trades, total) = range
+            .filter(|idx| idx + self.horizon < limit)
+            .filter(|idx| formed_at(candles, *idx, pattern, thresholds))
+            .filter_map(|idx| {
+                let (entry, exit) = (candles.get(idx)?, candles.get(idx + self.horizon)?);
+                Some(sign * exit.return_from(entry))
+            })
+            .fold((0, 0.0), |(trades, total), ret| (trades + 1, total + ret));
+
+        TradeScore {
+            trades,
+            mean_return: match trades {
+                0 => 0.0,
+                _ => total / trades as f64,
+            },
+        }
+    }
+}
+
+// Returns true if the candidate has trades and a better average return
+fn beats(candidate: &TradeScore, best: &TradeScore) -> bool {
+    candidate.trades > 0 && (best.trades == 0 || candidate.mean_return > best.mean_return)
+}
+
+// Returns true if the pattern completes on the given bar under the thresholds
+fn formed_at<T: CandleStick>(
+    candles: &[T],
+    idx: usize,
+    pattern: Pattern,
+    thresholds: &Thresholds,
+) -> bool {
+    let start = (idx + 1).saturating_sub(SERIES_SIZE);
+    let tuned: [_; SERIES_SIZE] = core::array::from_fn(|i| {
+        candles
+            .get(start + i)
+            .filter(|_| start + i <= idx)
+            .map(|c| thresholds.apply(c))
+    });
+
+    let mut stream = CandleStream::new();
+    for candle in tuned.iter().flatten() {
+        stream.push(candle);
+    }
+    stream.is_pattern(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    #[test]
+    fn test_chosen_thresholds_follow_the_regime() {
+        // morning doji stars followed by a rally in the first half, by a fall in the second
+        let candles: [Ohlcv; 120] = core::array::from_fn(|i| match (i % 4, i >= 60) {
+            (0, _) => (106.0, 106.5, 100.5, 101.0, 0.0),
+            (1, _) => (100.0, 100.6, 99.4, 100.08, 0.0),
+            (2, _) => (101.0, 104.5, 100.5, 104.0, 0.0),
+            (_, false) => (104.0, 106.5, 103.5, 106.0, 0.0),
+            (_, true) => (104.0, 104.5, 98.5, 99.0, 0.0),
+        });
+        let strict = Thresholds {
+            doji_body_ratio: 0.05,
+            ..Thresholds::default()
+        };
+        let candidates = [strict, Thresholds::default()];
+        let walk = WalkForward {
+            in_sample: 40,
+            out_of_sample: 20,
+            horizon: 1,
+        };
+
+        let mut windows = walk.run(&candles, Pattern::MorningStarDoji, &candidates);
+        let first = windows.next();
+        assert_eq!(first.as_ref().map(|w| w.chosen), Some(1));
+        assert!(first
+            .as_ref()
+            .is_some_and(|w| w.in_sample.trades == 10 && w.out_of_sample.mean_return > 0.0));
+
+        // the stars failing after the last in-sample window, its choice loses out of sample
+        let last = windows.last();
+        assert_eq!(
+            last.as_ref().map(|w| w.out_of_sample_range.clone()),
+            Some(100..120)
+        );
+        assert!(last.is_some_and(|w| w.out_of_sample.mean_return < 0.0));
+
+        assert_eq!(walk.run(&candles, Pattern::MorningStarDoji, &[]).count(), 0);
+    }
+}
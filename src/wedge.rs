@@ -0,0 +1,167 @@
+use crate::{CandleStick, CandleStream, Direction, Trendline};
+
+/// The slope of a [`Wedge`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WedgeKind {
+    /// Both lines rising, support steeper than resistance
+    Rising,
+    /// Both lines falling, resistance steeper than support
+    Falling,
+}
+
+/// A wedge chart pattern, as returned by [`CandleStream::wedge`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wedge {
+    /// The slope of the wedge
+    pub kind: WedgeKind,
+    /// The resistance line fitted to the swing highs
+    pub upper: Trendline,
+    /// The support line fitted to the swing lows
+    pub lower: Trendline,
+    /// Number of candles ahead of the latest one where the lines meet
+    pub apex: f64,
+    /// The reversal bias of the wedge, against its slope
+    pub bias: Direction,
+    /// Direction of the breakout if the latest candle closed outside of the lines
+    pub breakout: Option<Direction>,
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Identifies a Wedge, a chart pattern with converging resistance and support lines
+    /// sloping in the same direction, fitted like [`CandleStream::triangle`] to the latest
+    /// `touches` swing highs and swing lows of the given `strength`.
+    ///
+    /// **Trading Significance**:
+    /// - Shows a trend losing momentum as each push covers less distance
+    /// - Rising wedges carry a bearish bias, falling wedges a bullish one
+    /// - A close through the line opposite to the slope confirms the reversal
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, Direction, WedgeKind};
+    /// let candles = [
+    ///     (103.0, 106.0, 102.0, 105.0, 0.0),
+    ///     (105.0, 108.0, 103.0, 104.0, 0.0),
+    ///     (104.0, 107.0, 101.0, 102.0, 0.0),
+    ///     (102.0, 106.0, 100.0, 105.0, 0.0),
+    ///     (105.0, 108.0, 102.0, 107.0, 0.0),
+    ///     (107.0, 110.0, 104.0, 108.0, 0.0),
+    ///     (108.0, 108.0, 105.0, 106.0, 0.0),
+    ///     (106.0, 106.0, 104.0, 105.0, 0.0),
+    ///     (105.0, 107.5, 104.5, 107.0, 0.0),
+    ///     (107.0, 111.0, 106.5, 110.0, 0.0),
+    ///     (109.0, 109.0, 107.0, 108.0, 0.0),
+    ///     (108.0, 108.0, 106.0, 107.0, 0.0),
+    ///     (107.0, 109.5, 106.8, 109.0, 0.0),
+    ///     (109.0, 110.0, 107.5, 109.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 20>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let wedge = series.wedge(1, 2).unwrap();
+    /// assert_eq!((wedge.kind, wedge.bias), (WedgeKind::Rising, Direction::Bearish));
+    /// assert_eq!(wedge.breakout, None);
+    /// ```
+    pub fn wedge(&self, strength: usize, touches: usize) -> Option<Wedge> {
+        let (upper, lower, apex) = self.converging_lines(strength, touches)?;
+
+        let (kind, bias) = match (upper.slope, lower.slope) {
+            (upper, lower) if upper > 0.0 && lower > 0.0 => (WedgeKind::Rising, Direction::Bearish),
+            (upper, lower) if upper < 0.0 && lower < 0.0 => {
+                (WedgeKind::Falling, Direction::Bullish)
+            }
+            _ => return None,
+        };
+
+        Some(Wedge {
+            kind,
+            upper,
+            lower,
+            apex,
+            bias,
+            breakout: self.line_breakout(&upper, &lower),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    fn inverted(candle: &Ohlcv) -> Ohlcv {
+        let (o, h, l, c, v) = *candle;
+        (200.0 - o, 200.0 - l, 200.0 - h, 200.0 - c, v)
+    }
+
+    const RISING: [(f64, f64); 13] = [
+        (106.0, 102.0),
+        (108.0, 103.0),
+        (107.0, 101.0),
+        (106.0, 100.0),
+        (108.0, 102.0),
+        (110.0, 104.0),
+        (108.0, 105.0),
+        (106.0, 104.0),
+        (107.5, 104.5),
+        (111.0, 106.5),
+        (109.0, 107.0),
+        (108.0, 106.0),
+        (109.5, 106.8),
+    ];
+
+    fn build(latest: Ohlcv) -> [Ohlcv; 14] {
+        let mut candles = [latest; 14];
+        for (slot, bar) in candles.iter_mut().zip(RISING) {
+            *slot = candle(bar);
+        }
+        candles
+    }
+
+    fn wedge(candles: &[Ohlcv]) -> Option<Wedge> {
+        let mut series = CandleStream::<_, 20>::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series.wedge(1, 2)
+    }
+
+    #[test]
+    fn test_rising_wedge_breakdown() {
+        let inside = build((108.0, 110.0, 107.5, 109.0, 0.0));
+        let breakdown = build((108.0, 110.0, 106.0, 106.5, 0.0));
+
+        let pattern = wedge(&inside);
+        assert!(pattern.is_some_and(|w| w.kind == WedgeKind::Rising && w.breakout.is_none()));
+        assert!(
+            pattern.is_some_and(|w| (w.upper.level, w.lower.level, w.apex) == (112.0, 107.0, 20.0))
+        );
+
+        let pattern = wedge(&breakdown);
+        assert!(pattern.is_some_and(|w| w.breakout == Some(w.bias)));
+    }
+
+    #[test]
+    fn test_falling_wedge() {
+        let candles = build((108.0, 110.0, 107.5, 109.0, 0.0)).map(|c| inverted(&c));
+
+        let pattern = wedge(&candles);
+        assert!(pattern.is_some_and(|w| w.kind == WedgeKind::Falling));
+        assert!(pattern.is_some_and(|w| w.bias == Direction::Bullish));
+    }
+
+    #[test]
+    fn test_triangle_is_not_a_wedge() {
+        let mut candles = build((108.0, 110.0, 107.5, 109.0, 0.0));
+        candles[9] = candle((110.0, 106.5));
+
+        assert_eq!(wedge(&candles), None);
+    }
+}
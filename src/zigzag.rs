@@ -0,0 +1,248 @@
+use crate::{CandleStick, CandleStream, Swing, SwingKind};
+
+/// The minimum move reversing a [`ZigZag`] leg
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZigZagThreshold {
+    /// A fraction of the leg extreme (e.g. `0.05` for 5%)
+    Percent(f64),
+    /// A multiple of the average true range of the `period` candles up to the current one
+    Atr {
+        /// Number of candles averaged
+        period: usize,
+        /// Multiple of the average true range
+        multiple: f64,
+    },
+}
+
+/// The buffered history reduced to alternating swing legs, as returned by
+/// [`CandleStream::zigzag`]
+///
+/// Confirmed pivots are those followed by a reversal of at least the threshold. The extreme
+/// of the ongoing leg is kept apart as the tentative pivot, since it may still extend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZigZag<const N: usize> {
+    pivots: [Option<Swing>; N],
+    len: usize,
+    tentative: Option<Swing>,
+}
+
+impl<const N: usize> ZigZag<N> {
+    /// Iterates over the confirmed pivots, alternating highs and lows, from the latest to the
+    /// oldest
+    pub fn iter(&self) -> impl Iterator<Item = Swing> + '_ {
+        self.pivots[..self.len].iter().rev().flatten().copied()
+    }
+
+    /// Iterates over the legs between consecutive confirmed pivots, as `(start, end)` pairs,
+    /// from the latest to the oldest
+    pub fn legs(&self) -> impl Iterator<Item = (Swing, Swing)> + '_ {
+        self.iter().skip(1).zip(self.iter())
+    }
+
+    /// Returns the extreme of the ongoing leg, not confirmed by a reversal yet
+    pub fn tentative(&self) -> Option<Swing> {
+        self.tentative
+    }
+
+    /// Returns the number of confirmed pivots
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no pivot was confirmed
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Confirms the tentative pivot and starts a new leg at the given extreme
+    fn reverse(&mut self, extreme: Swing) {
+        if let Some(slot) = self.pivots.get_mut(self.len) {
+            *slot = self.tentative;
+            self.len += 1;
+        }
+        self.tentative = Some(extreme);
+    }
+}
+
+impl<T: CandleStick, const N: usize> CandleStream<'_, T, N> {
+    /// Reduces the buffered history to a ZigZag of alternating swing legs, walking forward
+    /// from the oldest candle.
+    ///
+    /// A leg extends while candles make new extremes in its direction, and reverses once a
+    /// candle moves away from its extreme by at least the `threshold`. Unlike
+    /// [`CandleStream::swings`], pivots are filtered by the size of the move rather than by
+    /// the number of candles around them, which makes the legs suitable for measuring
+    /// retracements and trend structure.
+    ///
+    /// # Example
+    /// ```
+    /// use candlestick_rs::{CandleStream, SwingKind, ZigZagThreshold};
+    /// let candles = [
+    ///     (100.0, 101.0, 99.0, 100.5, 0.0),
+    ///     (100.5, 106.0, 100.0, 105.5, 0.0),
+    ///     (105.5, 110.0, 105.0, 109.0, 0.0),
+    ///     (109.0, 109.5, 104.0, 104.5, 0.0),
+    ///     (104.5, 106.5, 104.2, 106.0, 0.0),
+    /// ];
+    /// let mut series = CandleStream::<_, 10>::with_capacity();
+    /// for candle in &candles {
+    ///     series.push(candle);
+    /// }
+    /// let zigzag = series.zigzag(ZigZagThreshold::Percent(0.03));
+    /// let pivots = zigzag.iter().map(|s| (s.kind, s.bars_ago, s.price));
+    /// assert!(pivots.eq([(SwingKind::High, 2, 110.0), (SwingKind::Low, 4, 99.0)]));
+    /// assert_eq!(zigzag.tentative().map(|s| s.price), Some(104.0));
+    /// ```
+    pub fn zigzag(&self, threshold: ZigZagThreshold) -> ZigZag<N> {
+        let mut zigzag = ZigZag {
+            pivots: [None; N],
+            len: 0,
+            tentative: None,
+        };
+        let Some(oldest) = self.len().checked_sub(1) else {
+            return zigzag;
+        };
+
+        let swing = |kind, bars_ago, price| Swing {
+            kind,
+            bars_ago,
+            price,
+        };
+        // before the first reversal both extremes are candidates
+        let first = self.prev(oldest);
+        let mut high = first.map(|c| swing(SwingKind::High, oldest, c.high()));
+        let mut low = first.map(|c| swing(SwingKind::Low, oldest, c.low()));
+
+        for bars_ago in (0..oldest).rev() {
+            let Some(candle) = self.prev(bars_ago) else {
+                continue;
+            };
+            let reversal = |extreme: f64| match threshold {
+                ZigZagThreshold::Percent(fraction) => fraction * extreme.abs(),
+                ZigZagThreshold::Atr { period, multiple } => {
+                    multiple * self.average_true_range(bars_ago, period)
+                }
+            };
+            let new_high = swing(SwingKind::High, bars_ago, candle.high());
+            let new_low = swing(SwingKind::Low, bars_ago, candle.low());
+
+            match zigzag.tentative.map(|pivot| (pivot.kind, pivot.price)) {
+                Some((SwingKind::High, extreme)) if candle.high() > extreme => {
+                    zigzag.tentative = Some(new_high);
+                }
+                Some((SwingKind::High, extreme)) if extreme - candle.low() >= reversal(extreme) => {
+                    zigzag.reverse(new_low);
+                }
+                Some((SwingKind::Low, extreme)) if candle.low() < extreme => {
+                    zigzag.tentative = Some(new_low);
+                }
+                Some((SwingKind::Low, extreme)) if candle.high() - extreme >= reversal(extreme) => {
+                    zigzag.reverse(new_high);
+                }
+                Some(_) => {}
+                None => {
+                    high = high.filter(|h| h.price >= candle.high()).or(Some(new_high));
+                    low = low.filter(|l| l.price <= candle.low()).or(Some(new_low));
+                    let (Some(h), Some(l)) = (high, low) else {
+                        continue;
+                    };
+
+                    // the latest of the two extremes sets the direction of the first leg
+                    if h.price - l.price >= reversal(l.price) {
+                        let (start, end) = match h.bars_ago < l.bars_ago {
+                            true => (l, h),
+                            false => (h, l),
+                        };
+                        zigzag.tentative = Some(start);
+                        zigzag.reverse(end);
+                    }
+                }
+            }
+        }
+
+        zigzag
+    }
+
+    // Average true range of the `period` candles ending `bars_ago`, over the available ones
+    fn average_true_range(&self, bars_ago: usize, period: usize) -> f64 {
+        let (count, sum) = (bars_ago..bars_ago + period.max(1))
+            .filter_map(|n| Some((self.prev(n)?, self.prev(n + 1))))
+            .map(|(candle, prev)| {
+                let close = prev.map_or(candle.low(), |p| p.close());
+                candle.high().max(close) - candle.low().min(close)
+            })
+            .fold((0.0, 0.0), |(count, sum), range| (count + 1.0, sum + range));
+
+        sum / count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ohlcv = (f64, f64, f64, f64, f64);
+
+    fn candle((high, low): (f64, f64)) -> Ohlcv {
+        let quarter = (high - low) / 4.0;
+        (low + quarter, high, low, high - quarter, 0.0)
+    }
+
+    const SHAPE: [(f64, f64); 9] = [
+        (101.0, 99.0),
+        (106.0, 100.0),
+        (110.0, 105.0),
+        (109.5, 104.0),
+        (108.0, 102.0),
+        (107.0, 103.0),
+        (111.0, 106.0),
+        (114.0, 109.0),
+        (113.0, 111.0),
+    ];
+
+    fn series<'s>(candles: &'s [Ohlcv]) -> CandleStream<'s, Ohlcv, 20> {
+        let mut series = CandleStream::with_capacity();
+        for candle in candles {
+            series.push(candle);
+        }
+        series
+    }
+
+    #[test]
+    fn test_zigzag_percent() {
+        let candles = SHAPE.map(candle);
+        let zigzag = series(&candles).zigzag(ZigZagThreshold::Percent(0.05));
+
+        let pivots = zigzag.iter().map(|s| (s.kind, s.bars_ago, s.price));
+        assert!(pivots.eq([
+            (SwingKind::Low, 4, 102.0),
+            (SwingKind::High, 6, 110.0),
+            (SwingKind::Low, 8, 99.0),
+        ]));
+        assert_eq!(zigzag.tentative().map(|s| s.price), Some(114.0));
+        assert_eq!(zigzag.legs().count(), 2);
+        assert!(zigzag
+            .legs()
+            .all(|(start, end)| start.bars_ago > end.bars_ago && start.kind != end.kind));
+
+        let coarse = series(&candles).zigzag(ZigZagThreshold::Percent(0.2));
+        assert!(coarse.is_empty() && coarse.tentative().is_none());
+    }
+
+    #[test]
+    fn test_zigzag_atr() {
+        let candles = SHAPE.map(candle);
+        let fine = series(&candles).zigzag(ZigZagThreshold::Atr {
+            period: 3,
+            multiple: 1.0,
+        });
+        let coarse = series(&candles).zigzag(ZigZagThreshold::Atr {
+            period: 3,
+            multiple: 3.0,
+        });
+
+        assert_eq!(fine.len(), 3);
+        assert_eq!(coarse.len(), 1);
+        assert_eq!(series(&[]).zigzag(ZigZagThreshold::Percent(0.05)).len(), 0);
+    }
+}